@@ -1,21 +1,50 @@
-use super::data::{
-    AgentData, ApiResponse, ErrorResponse, Factions, LocationData, RegistrationData,
+use super::{
+    data::{
+        self, AgentData, ApiResponse, CargoData, CargoInfo, ChartData, Construction,
+        ConstructionSupplyData, ContractData, ContractOfferData, ContractUpdateData, DeliveryData,
+        ErrorResponse, ExtractionData, FactionData, FactionReputation, Factions, FlightMode,
+        JumpData, JumpGate, LocationData, MarketData, ModulesData, MountsData, NavInfo,
+        NavigationData, PageResponse, RefuelData, RegistrationData, RepairData, ScrapData,
+        ShipData, ShipModuleSymbol, ShipMountSymbol, ShipNavData, ShipPurchaseData, ShipScanData,
+        ShipServiceQuote, ShipServiceTransaction, ShipType, SiphonData, StatusData, Survey,
+        SurveyData, SystemScanData, TradeData, TradeSymbol, TransferData, WaypointScanData,
+    },
+    rate_limit::RateLimiter,
+    token_store::{FileTokenStore, StoredAgent, TokenStore, TokenStoreError},
+};
+use crate::{
+    history,
+    ledger::{self, EntryKind},
+    utils::{
+        self,
+        cache::CacheError,
+        config::{ConfigError, ProfileData},
+    },
 };
-use crate::utils::{self, config::ConfigData};
 
 use reqwest::{blocking::Client, header};
-use std::collections::HashMap;
+use serde::de::DeserializeOwned;
+use std::sync::Arc;
 
 // API Routes
 const ROOT_URL: &str = "https://api.spacetraders.io/v2";
 
+/// Largest page size accepted by list endpoints.
+const PAGE_LIMIT: u32 = 20;
+
 pub type ApiResult<T> = Result<T, ApiError>;
 
 #[derive(Debug)]
 pub enum ApiError {
     Network(reqwest::Error),
     MissingToken,
+    MissingAgentInfo,
     BadRequest(ErrorResponse),
+    Parse(serde_json::Error),
+    Config(ConfigError),
+    Cache(CacheError),
+    TokenStore(TokenStoreError),
+    Unreachable(String),
 }
 impl std::fmt::Display for ApiError {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
@@ -27,13 +56,37 @@ impl std::fmt::Display for ApiError {
             ApiError::MissingToken => {
                 write!(f, "Cannot access the SpaceTraders API: missing auth token.")
             }
+            ApiError::MissingAgentInfo => write!(
+                f,
+                "Cannot re-register agent: the config file has no record of its symbol or faction."
+            ),
             ApiError::BadRequest(e) => {
                 write!(f, "The SpaceTraders API rejected the request: {e:#?}")
             }
+            ApiError::Parse(e) => write!(
+                f,
+                "The SpaceTraders API sent a response that doesn't match the expected schema: {e}"
+            ),
+            ApiError::Config(e) => write!(f, "Error accessing the config file: {e:?}"),
+            ApiError::Cache(e) => write!(f, "Error accessing the cache file: {e:?}"),
+            ApiError::TokenStore(e) => write!(f, "Error storing the agent's token: {e:?}"),
+            ApiError::Unreachable(destination) => write!(
+                f,
+                "Cannot reach {destination}: no known route leads there from the ship's location."
+            ),
         }
     }
 }
 
+/// Gets the system a waypoint belongs to.
+///
+/// * `waypoint` - waypoint symbol in the format of "XX-YYYY-ZZZZZZ".
+///
+/// Returns the system symbol in the format of "XX-YYYY".
+pub fn system_of(waypoint: &str) -> String {
+    waypoint.split('-').take(2).collect::<Vec<&str>>().join("-")
+}
+
 /// Encapsulates basic HTTP methods used by the API client under the hood.
 trait HttpClient {
     /// Convenience method for HTTP GET with some SpaceTraders-specific defaults prefilled.
@@ -54,11 +107,32 @@ trait HttpClient {
         request_body: impl Into<reqwest::blocking::Body>,
         url: &str,
     ) -> reqwest::Result<reqwest::blocking::Response>;
+
+    /// Convenience method for HTTP PATCH with some SpaceTraders-specific defaults prefilled.
+    ///
+    /// * `request_body` - [`Body`](`reqwest::blocking::Body`) content of request.
+    /// * `url` - URL for the given HTTP endpoint.
+    ///
+    /// Returns the [`Response`](reqwest::blocking::Response) for a successful request, or the [`Error`](reqwest::Error).
+    fn patch(
+        &self,
+        request_body: impl Into<reqwest::blocking::Body>,
+        url: &str,
+    ) -> reqwest::Result<reqwest::blocking::Response>;
 }
 
 /// All of the relevant methods for high-level interactions with the SpaceTrader API.
-pub trait TraderApis {
-    /// Register a new SpaceTraders agent and save its config data.
+///
+/// Implementations are shared between the threads agents are run on, so they must be thread-safe.
+pub trait TraderApis: Sync {
+    /// Gets the status of the game server, including when the universe was last reset, clearing the local cache
+    /// if it was filled before that reset.
+    ///
+    /// Returns [`StatusData`] for the server, or the [`ApiError`] reason for failure, which is [`ApiError::Cache`]
+    /// if the outdated cache couldn't be cleared.
+    fn get_status(&self) -> ApiResult<StatusData>;
+
+    /// Register a new SpaceTraders agent. Nothing is stored locally - see [`ApiClient::register`] for that.
     ///
     /// * `agent_name` - desired name of new agent.
     /// * `faction_name` - [`Faction`](`Factions`) of new agent.
@@ -75,13 +149,382 @@ pub trait TraderApis {
     /// Returns [`AgentData`] for the agent, or the [`ApiError`] reason for failure.
     fn get_agent_data(&self) -> ApiResult<AgentData>;
 
-    /// Gets location data for a given waypoint.
+    /// Gets the agent's reputation with every faction it has dealt with.
+    ///
+    /// Returns a [`FactionReputation`] for each faction, or the [`ApiError`] reason for failure.
+    fn list_reputations(&self) -> ApiResult<Vec<FactionReputation>>;
+
+    /// Gets every faction in the game, so one can be chosen before registering an agent.
+    ///
+    /// Returns [`FactionData`] for each faction, or the [`ApiError`] reason for failure.
+    fn list_factions(&self) -> ApiResult<Vec<FactionData>>;
+
+    /// Gets the description, headquarters and traits of a faction.
+    ///
+    /// * `faction` - [`Faction`](`Factions`) to look up.
+    ///
+    /// Returns [`FactionData`] for the faction, or the [`ApiError`] reason for failure.
+    fn get_faction(&self, faction: &Factions) -> ApiResult<FactionData>;
+
+    /// Gets location data for a given waypoint, preferring the local cache over the network.
     ///
     /// * `waypoint` - string representation of the given waypoint. This is expected
-    /// to be in the format of "XX-YYYY-ZZZZZZ" where Xs constitute the sector and 'XX-YYYY' is the system.
+    ///   to be in the format of "XX-YYYY-ZZZZZZ" where Xs constitute the sector and 'XX-YYYY' is the system.
     ///
     /// Returns [`LocationData`] for the waypoint, or the [`ApiError`] reason for failure.
     fn get_waypoint_location_data(&self, waypoint: &str) -> ApiResult<LocationData>;
+
+    /// Gets location data for several waypoints, reading the local cache once and fetching only what it lacks.
+    ///
+    /// * `waypoints` - symbols of the waypoints.
+    ///
+    /// Returns [`LocationData`] for each waypoint in the given order, or the [`ApiError`] reason for failure.
+    fn get_waypoints_location_data(&self, waypoints: &[&str]) -> ApiResult<Vec<LocationData>>;
+
+    /// Gets the imports, exports and - if one of your ships is present - current prices of a market,
+    /// recording those prices into the local price history.
+    ///
+    /// * `waypoint` - symbol of the marketplace waypoint.
+    ///
+    /// Returns [`MarketData`] for the market, or the [`ApiError`] reason for failure.
+    fn get_market(&self, waypoint: &str) -> ApiResult<MarketData>;
+
+    /// Gets all ships owned by the agent.
+    ///
+    /// Returns the [`ShipData`] of every ship in the fleet, or the [`ApiError`] reason for failure.
+    fn list_ships(&self) -> ApiResult<Vec<ShipData>>;
+
+    /// Gets a single ship owned by the agent.
+    ///
+    /// * `ship` - symbol of the ship.
+    ///
+    /// Returns [`ShipData`] for the ship, or the [`ApiError`] reason for failure.
+    fn get_ship(&self, ship: &str) -> ApiResult<ShipData>;
+
+    /// Gets every waypoint in a system, from the cache if the system has been listed recently.
+    ///
+    /// * `system` - symbol of the system, in the format of "XX-YYYY".
+    ///
+    /// Returns the [`LocationData`] of every waypoint, or the [`ApiError`] reason for failure.
+    fn list_waypoints(&self, system: &str) -> ApiResult<Vec<LocationData>>;
+
+    /// Gets the materials needed to finish building a waypoint and how many have been supplied so far.
+    ///
+    /// * `waypoint` - symbol of the construction site's waypoint.
+    ///
+    /// Returns the [`Construction`] progress, or the [`ApiError`] reason for failure.
+    fn get_construction(&self, waypoint: &str) -> ApiResult<Construction>;
+
+    /// Supplies goods from a docked ship's cargo hold to the construction site at its waypoint.
+    ///
+    /// * `ship` - symbol of the ship.
+    /// * `trade_symbol` - symbol of the material.
+    /// * `units` - amount to supply.
+    ///
+    /// Returns the updated construction progress and cargo in [`ConstructionSupplyData`], or the [`ApiError`]
+    /// reason for failure.
+    fn supply_construction(
+        &self,
+        ship: &str,
+        trade_symbol: &TradeSymbol,
+        units: u32,
+    ) -> ApiResult<ConstructionSupplyData>;
+
+    /// Gets the connections of a jump gate, preferring the local cache over the network.
+    ///
+    /// * `waypoint` - symbol of the jump gate's waypoint.
+    ///
+    /// Returns the [`JumpGate`], or the [`ApiError`] reason for failure.
+    fn get_jump_gate(&self, waypoint: &str) -> ApiResult<JumpGate>;
+
+    /// Moves a docked ship into orbit, which is required before it can travel.
+    ///
+    /// * `ship` - symbol of the ship.
+    ///
+    /// Returns the ship's updated [`NavInfo`], or the [`ApiError`] reason for failure.
+    fn orbit_ship(&self, ship: &str) -> ApiResult<NavInfo>;
+
+    /// Docks an orbiting ship, which is required before it can trade or refuel.
+    ///
+    /// * `ship` - symbol of the ship.
+    ///
+    /// Returns the ship's updated [`NavInfo`], or the [`ApiError`] reason for failure.
+    fn dock_ship(&self, ship: &str) -> ApiResult<NavInfo>;
+
+    /// Sends an orbiting ship to another waypoint in its current system.
+    ///
+    /// * `ship` - symbol of the ship.
+    /// * `waypoint` - symbol of the destination waypoint.
+    ///
+    /// Returns the ship's updated fuel and navigation in [`NavigationData`], or the [`ApiError`] reason for failure.
+    fn navigate_ship(&self, ship: &str, waypoint: &str) -> ApiResult<NavigationData>;
+
+    /// Jumps an orbiting ship at a jump gate to the connected gate in another system.
+    ///
+    /// * `ship` - symbol of the ship.
+    /// * `system` - symbol of the destination system, which must be connected to the ship's jump gate.
+    ///
+    /// Returns the ship's updated navigation and cooldown in [`JumpData`], or the [`ApiError`] reason for failure.
+    fn jump_ship(&self, ship: &str, system: &str) -> ApiResult<JumpData>;
+
+    /// Warps an orbiting ship with a warp drive to a waypoint in another system, without using a jump gate.
+    ///
+    /// * `ship` - symbol of the ship.
+    /// * `waypoint` - symbol of the destination waypoint.
+    ///
+    /// Returns the ship's updated fuel and navigation in [`NavigationData`], or the [`ApiError`] reason for failure.
+    fn warp_ship(&self, ship: &str, waypoint: &str) -> ApiResult<NavigationData>;
+
+    /// Changes the [`FlightMode`] a ship uses for its following trips.
+    ///
+    /// * `ship` - symbol of the ship.
+    /// * `flight_mode` - the new [`FlightMode`].
+    ///
+    /// Returns the ship's updated [`NavInfo`], or the [`ApiError`] reason for failure.
+    fn set_flight_mode(&self, ship: &str, flight_mode: FlightMode) -> ApiResult<NavInfo>;
+
+    /// Charts the uncharted waypoint an orbiting ship is at, revealing its traits to everyone and updating
+    /// the cached waypoint.
+    ///
+    /// * `ship` - symbol of the ship.
+    ///
+    /// Returns the new chart and charted waypoint in [`ChartData`], or the [`ApiError`] reason for failure.
+    fn create_chart(&self, ship: &str) -> ApiResult<ChartData>;
+
+    /// Surveys the ship's current waypoint for resource deposits. Requires a surveyor mount.
+    ///
+    /// * `ship` - symbol of the ship.
+    ///
+    /// Returns the [`Survey`]s found and the resulting cooldown in [`SurveyData`], or the [`ApiError`] reason for failure.
+    fn create_survey(&self, ship: &str) -> ApiResult<SurveyData>;
+
+    /// Scans for the systems within range of a ship's sensors. Requires a sensor array mount.
+    ///
+    /// * `ship` - symbol of the ship.
+    ///
+    /// Returns the systems found and the resulting cooldown in [`SystemScanData`], or the [`ApiError`] reason for
+    /// failure.
+    fn scan_systems(&self, ship: &str) -> ApiResult<SystemScanData>;
+
+    /// Scans for the waypoints within range of a ship's sensors and caches them. Requires a sensor array mount.
+    ///
+    /// * `ship` - symbol of the ship.
+    ///
+    /// Returns the waypoints found and the resulting cooldown in [`WaypointScanData`], or the [`ApiError`] reason
+    /// for failure.
+    fn scan_waypoints(&self, ship: &str) -> ApiResult<WaypointScanData>;
+
+    /// Scans for the ships within range of a ship's sensors. Requires a sensor array mount.
+    ///
+    /// * `ship` - symbol of the ship.
+    ///
+    /// Returns the ships found and the resulting cooldown in [`ShipScanData`], or the [`ApiError`] reason for
+    /// failure.
+    fn scan_ships(&self, ship: &str) -> ApiResult<ShipScanData>;
+
+    /// Siphons gas at the ship's current waypoint, which must be a gas giant. Requires a gas siphon mount.
+    ///
+    /// * `ship` - symbol of the ship.
+    ///
+    /// Returns the yield, cooldown and updated cargo in [`SiphonData`], or the [`ApiError`] reason for failure.
+    fn siphon_resources(&self, ship: &str) -> ApiResult<SiphonData>;
+
+    /// Extracts resources at the ship's current waypoint. Requires a mining mount.
+    ///
+    /// * `ship` - symbol of the ship.
+    /// * `survey` - optional [`Survey`] of the waypoint, used to target its deposits.
+    ///
+    /// Returns the yield, cooldown and updated cargo in [`ExtractionData`], or the [`ApiError`] reason for failure.
+    fn extract_resources(&self, ship: &str, survey: Option<&Survey>) -> ApiResult<ExtractionData>;
+
+    /// Throws goods out of the ship's cargo hold.
+    ///
+    /// * `ship` - symbol of the ship.
+    /// * `trade_symbol` - symbol of the good.
+    /// * `units` - amount to throw out.
+    ///
+    /// Returns the ship's updated [`CargoInfo`], or the [`ApiError`] reason for failure.
+    fn jettison_cargo(
+        &self,
+        ship: &str,
+        trade_symbol: &TradeSymbol,
+        units: u32,
+    ) -> ApiResult<CargoInfo>;
+
+    /// Moves goods from one ship's cargo hold to another's. Both ships must be at the same waypoint and either both
+    /// docked or both in orbit.
+    ///
+    /// * `from_ship` - symbol of the sending ship.
+    /// * `to_ship` - symbol of the receiving ship.
+    /// * `trade_symbol` - symbol of the good.
+    /// * `units` - amount to move.
+    ///
+    /// Returns the ships' updated cargo in [`TransferData`], or the [`ApiError`] reason for failure.
+    fn transfer_cargo(
+        &self,
+        from_ship: &str,
+        to_ship: &str,
+        trade_symbol: &TradeSymbol,
+        units: u32,
+    ) -> ApiResult<TransferData>;
+
+    /// Sells goods from a docked ship's cargo hold to the market at its waypoint.
+    ///
+    /// * `ship` - symbol of the ship.
+    /// * `trade_symbol` - symbol of the good.
+    /// * `units` - amount to sell.
+    ///
+    /// Returns the updated agent and cargo along with the transaction in [`TradeData`], or the [`ApiError`]
+    /// reason for failure.
+    fn sell_cargo(
+        &self,
+        ship: &str,
+        trade_symbol: &TradeSymbol,
+        units: u32,
+    ) -> ApiResult<TradeData>;
+
+    /// Refuels a docked ship from the market at its waypoint, or from the fuel in its cargo hold.
+    ///
+    /// * `ship` - symbol of the ship.
+    /// * `units` - amount of fuel to add, or [`Option::None`] to fill the tank.
+    /// * `from_cargo` - whether to use the fuel in the cargo hold instead of buying it.
+    ///
+    /// Returns the updated agent, fuel and cargo along with the transaction in [`RefuelData`], or the [`ApiError`]
+    /// reason for failure.
+    fn refuel_ship(
+        &self,
+        ship: &str,
+        units: Option<u32>,
+        from_cargo: bool,
+    ) -> ApiResult<RefuelData>;
+
+    /// Gets what the shipyard at a docked ship's waypoint would charge to repair it.
+    ///
+    /// * `ship` - symbol of the ship.
+    ///
+    /// Returns the quoted [`ShipServiceTransaction`], or the [`ApiError`] reason for failure.
+    fn get_repair_ship(&self, ship: &str) -> ApiResult<ShipServiceTransaction>;
+
+    /// Repairs a docked ship at the shipyard at its waypoint, restoring the condition of its components.
+    ///
+    /// * `ship` - symbol of the ship.
+    ///
+    /// Returns the updated agent and ship along with the transaction in [`RepairData`], or the [`ApiError`]
+    /// reason for failure.
+    fn repair_ship(&self, ship: &str) -> ApiResult<RepairData>;
+
+    /// Gets what the shipyard at a docked ship's waypoint would pay to scrap it.
+    ///
+    /// * `ship` - symbol of the ship.
+    ///
+    /// Returns the quoted [`ShipServiceTransaction`], or the [`ApiError`] reason for failure.
+    fn get_scrap_ship(&self, ship: &str) -> ApiResult<ShipServiceTransaction>;
+
+    /// Scraps a docked ship at the shipyard at its waypoint, permanently removing it from the fleet.
+    ///
+    /// * `ship` - symbol of the ship.
+    ///
+    /// Returns the updated agent along with the transaction in [`ScrapData`], or the [`ApiError`] reason for
+    /// failure.
+    fn scrap_ship(&self, ship: &str) -> ApiResult<ScrapData>;
+
+    /// Buys a new ship at a shipyard where one of the agent's ships is present.
+    ///
+    /// * `ship_type` - [`ShipType`] of the ship to buy.
+    /// * `waypoint` - symbol of the shipyard's waypoint.
+    ///
+    /// Returns the updated agent and the new ship along with the transaction in [`ShipPurchaseData`], or the
+    /// [`ApiError`] reason for failure.
+    fn purchase_ship(&self, ship_type: &ShipType, waypoint: &str) -> ApiResult<ShipPurchaseData>;
+
+    /// Installs a mount from a docked ship's cargo hold at the shipyard at its waypoint.
+    ///
+    /// * `ship` - symbol of the ship.
+    /// * `mount` - symbol of the mount.
+    ///
+    /// Returns the ship's updated mounts and cargo in [`MountsData`], or the [`ApiError`] reason for failure.
+    fn install_mount(&self, ship: &str, mount: &ShipMountSymbol) -> ApiResult<MountsData>;
+
+    /// Removes a mount from a docked ship into its cargo hold at the shipyard at its waypoint.
+    ///
+    /// * `ship` - symbol of the ship.
+    /// * `mount` - symbol of the mount.
+    ///
+    /// Returns the ship's updated mounts and cargo in [`MountsData`], or the [`ApiError`] reason for failure.
+    fn remove_mount(&self, ship: &str, mount: &ShipMountSymbol) -> ApiResult<MountsData>;
+
+    /// Installs a module from a docked ship's cargo hold at the shipyard at its waypoint.
+    ///
+    /// * `ship` - symbol of the ship.
+    /// * `module` - symbol of the module.
+    ///
+    /// Returns the ship's updated modules and cargo in [`ModulesData`], or the [`ApiError`] reason for failure.
+    fn install_module(&self, ship: &str, module: &ShipModuleSymbol) -> ApiResult<ModulesData>;
+
+    /// Removes a module from a docked ship into its cargo hold at the shipyard at its waypoint.
+    ///
+    /// * `ship` - symbol of the ship.
+    /// * `module` - symbol of the module.
+    ///
+    /// Returns the ship's updated modules and cargo in [`ModulesData`], or the [`ApiError`] reason for failure.
+    fn remove_module(&self, ship: &str, module: &ShipModuleSymbol) -> ApiResult<ModulesData>;
+
+    /// Buys goods from the market at a docked ship's waypoint into its cargo hold.
+    ///
+    /// * `ship` - symbol of the ship.
+    /// * `trade_symbol` - symbol of the good.
+    /// * `units` - amount to buy.
+    ///
+    /// Returns the updated agent and cargo along with the transaction in [`TradeData`], or the [`ApiError`]
+    /// reason for failure.
+    fn purchase_cargo(
+        &self,
+        ship: &str,
+        trade_symbol: &TradeSymbol,
+        units: u32,
+    ) -> ApiResult<TradeData>;
+
+    /// Gets all contracts offered to or accepted by the agent.
+    ///
+    /// Returns the [`ContractData`] of every contract, or the [`ApiError`] reason for failure.
+    fn list_contracts(&self) -> ApiResult<Vec<ContractData>>;
+
+    /// Accepts a contract, paying out its `on_accepted` payment.
+    ///
+    /// * `contract_id` - ID of the contract.
+    ///
+    /// Returns the updated agent and contract in [`ContractUpdateData`], or the [`ApiError`] reason for failure.
+    fn accept_contract(&self, contract_id: &str) -> ApiResult<ContractUpdateData>;
+
+    /// Delivers goods from a docked ship's cargo hold towards a contract.
+    ///
+    /// * `contract_id` - ID of the contract.
+    /// * `ship` - symbol of the ship, which must be at the delivery destination.
+    /// * `trade_symbol` - symbol of the good.
+    /// * `units` - amount to deliver.
+    ///
+    /// Returns the updated contract and cargo in [`DeliveryData`], or the [`ApiError`] reason for failure.
+    fn deliver_contract(
+        &self,
+        contract_id: &str,
+        ship: &str,
+        trade_symbol: &TradeSymbol,
+        units: u32,
+    ) -> ApiResult<DeliveryData>;
+
+    /// Fulfills a contract whose deliveries are complete, paying out its `on_fulfilled` payment.
+    ///
+    /// * `contract_id` - ID of the contract.
+    ///
+    /// Returns the updated agent and contract in [`ContractUpdateData`], or the [`ApiError`] reason for failure.
+    fn fulfill_contract(&self, contract_id: &str) -> ApiResult<ContractUpdateData>;
+
+    /// Asks the faction at a docked ship's waypoint for a new contract.
+    ///
+    /// * `ship` - symbol of the ship.
+    ///
+    /// Returns the offered [`ContractData`], or the [`ApiError`] reason for failure.
+    fn negotiate_contract(&self, ship: &str) -> ApiResult<ContractData>;
 }
 
 /// Client interface for the SpaceTraders API. Uses HTTP requests under the hood to make these transactions.
@@ -91,19 +534,54 @@ pub struct ApiClient {
     http_client: Client,
     /// API token for the user's player agent necessary for authenticating API requests.
     token: String,
+    /// Whether to warn about response fields and values that aren't modelled in [`data`].
+    strict_mode: bool,
+    /// Limiter every request waits on, shared between clones since they act for the same agent.
+    rate_limiter: Arc<RateLimiter>,
+    /// Where the token of a newly registered agent is kept.
+    token_store: Arc<dyn TokenStore>,
 }
 
 impl ApiClient {
-    /// Initializes an [`ApiClient`] based on existing config data.
+    /// Initializes an [`ApiClient`] for the agent in the config file, keeping any agent registered later there too.
     ///
     /// Returns an [`ApiClient`] for your agent, or the [`ApiError`] reason for failure.
     pub fn init() -> ApiResult<Self> {
-        let api_config_data =
-            utils::config::read_default_config_file().ok_or(ApiError::MissingToken)?;
-        Ok(Self {
+        Self::init_with_token_store(Arc::new(FileTokenStore::default()))
+    }
+
+    /// Initializes an [`ApiClient`] for one of the profiles in the config file, keeping any agent registered later
+    /// in that profile.
+    ///
+    /// * `profile_name` - name of the profile.
+    ///
+    /// Returns an [`ApiClient`] for the profile's agent, or the [`ApiError`] reason for failure.
+    pub fn init_profile(profile_name: &str) -> ApiResult<Self> {
+        Self::init_with_token_store(Arc::new(FileTokenStore::for_profile(profile_name)))
+    }
+
+    /// Creates an [`ApiClient`] for a profile's agent, with a rate limiter and token store of its own.
+    ///
+    /// * `profile` - [`ProfileData`] of the agent.
+    pub fn for_profile(profile: &ProfileData) -> Self {
+        Self {
+            token: profile.token.clone(),
+            token_store: Arc::new(FileTokenStore::for_profile(&profile.name)),
+            ..Self::unregistered()
+        }
+    }
+
+    /// Creates an [`ApiClient`] with no agent, for browsing public data such as the factions before registering.
+    ///
+    /// Endpoints that act on behalf of an agent will fail with this client.
+    pub fn unregistered() -> Self {
+        Self {
             http_client: Default::default(),
-            token: api_config_data.token,
-        })
+            token: Default::default(),
+            strict_mode: false,
+            rate_limiter: Default::default(),
+            token_store: Arc::new(FileTokenStore::default()),
+        }
     }
 
     /// Creates a new [`ApiClient`] along with registering a new agent.
@@ -116,22 +594,287 @@ impl ApiClient {
     ///
     /// Returns an [`ApiClient`] registered to your new agent, or the [`ApiError`] reason for failure.
     pub fn new(agent_name: &str, faction: Factions) -> ApiResult<Self> {
-        let mut api_client = Self {
-            http_client: Default::default(),
-            token: Default::default(),
-        };
+        Self::unregistered().register(agent_name, faction)
+    }
+
+    /// Creates an [`ApiClient`] with no agent that authenticates as an account, which newer versions of the API
+    /// require for registering agents.
+    ///
+    /// * `account_token` - token of the SpaceTraders account.
+    pub fn with_account_token(account_token: &str) -> Self {
+        Self {
+            token: account_token.to_string(),
+            ..Self::unregistered()
+        }
+    }
+
+    /// Initializes an [`ApiClient`] with the agent token kept in a [`TokenStore`].
+    ///
+    /// * `token_store` - the [`TokenStore`], which also keeps the token of any agent registered later.
+    ///
+    /// Returns an [`ApiClient`] for the stored agent, or [`ApiError::MissingToken`] if no agent is stored.
+    pub fn from_token_store(token_store: Arc<dyn TokenStore>) -> ApiResult<Self> {
+        let token = token_store.token().ok_or(ApiError::MissingToken)?;
+        Ok(Self {
+            token,
+            token_store,
+            ..Self::unregistered()
+        })
+    }
 
-        let registration_data = api_client.register_new_agent(agent_name, faction)?;
-        api_client.token = registration_data.token;
+    /// Initializes an [`ApiClient`] with the agent kept in a [`TokenStore`], using the settings in the config file.
+    ///
+    /// * `token_store` - the [`TokenStore`], which also keeps the token of any agent registered later.
+    ///
+    /// Returns an [`ApiClient`] for the stored agent, or [`ApiError::MissingToken`] if no agent is stored.
+    pub fn init_with_token_store(token_store: Arc<dyn TokenStore>) -> ApiResult<Self> {
+        let mut api_client = Self::from_token_store(token_store)?;
+        api_client.set_strict_mode(
+            utils::config::read_default_config_file()
+                .is_some_and(|config_data| config_data.strict_mode),
+        );
         Ok(api_client)
     }
+
+    /// Changes where the token of a newly registered agent is kept, which is the config file by default.
+    ///
+    /// * `token_store` - the [`TokenStore`].
+    pub fn set_token_store(&mut self, token_store: Arc<dyn TokenStore>) {
+        self.token_store = token_store;
+    }
+
+    /// Registers a new agent, keeps its token in the client's [`TokenStore`] and switches the client over to it.
+    ///
+    /// * `agent_name` - name of the agent you want to create.
+    /// * `faction` - [`Faction`](`Factions`) you want your new agent to be in.
+    ///
+    /// Returns the [`ApiClient`] registered to your new agent, or the [`ApiError`] reason for failure.
+    pub fn register(mut self, agent_name: &str, faction: Factions) -> ApiResult<Self> {
+        let registered_agent = self.register_agent(agent_name, faction)?;
+        self.token_store
+            .save(&registered_agent)
+            .map_err(ApiError::TokenStore)?;
+        self.token = registered_agent.token;
+        Ok(self)
+    }
+
+    /// Registers a new agent without keeping it or switching the client over to it, e.g. to archive the agent it
+    /// replaces first.
+    ///
+    /// * `agent_name` - name of the agent you want to create.
+    /// * `faction` - [`Faction`](`Factions`) you want your new agent to be in.
+    ///
+    /// Returns the [`StoredAgent`] to keep for the new agent, or the [`ApiError`] reason for failure.
+    pub fn register_agent(&self, agent_name: &str, faction: Factions) -> ApiResult<StoredAgent> {
+        let registration_data = self.register_new_agent(agent_name, faction)?;
+        let reset_date = self
+            .get_status()
+            .ok()
+            .map(|status_data| status_data.reset_date);
+        Ok(StoredAgent::from_registration(
+            &registration_data,
+            reset_date,
+        ))
+    }
+
+    /// Initializes an [`ApiClient`] based on existing config data, automatically re-registering the agent
+    /// if the universe has been reset since it was created.
+    ///
+    /// Returns an [`ApiClient`] for your (possibly new) agent, or the [`ApiError`] reason for failure.
+    pub fn init_with_reset_recovery() -> ApiResult<Self> {
+        let api_client = Self::init()?;
+        if api_client.has_server_reset()? {
+            api_client.recover_from_reset()
+        } else {
+            Ok(api_client)
+        }
+    }
+
+    /// Checks whether the universe has been reset since the stored agent was registered.
+    ///
+    /// Agents stored before reset dates were tracked get the server's current reset date recorded,
+    /// so detection only works from then on.
+    ///
+    /// Returns whether a reset happened, or the [`ApiError`] reason for failure.
+    pub fn has_server_reset(&self) -> ApiResult<bool> {
+        let status_data = self.get_status()?;
+        let stored_agent = self.token_store.load().ok_or(ApiError::MissingToken)?;
+
+        match stored_agent.reset_date {
+            Some(ref reset_date) => Ok(*reset_date != status_data.reset_date),
+            None => {
+                self.token_store
+                    .save(&StoredAgent {
+                        reset_date: Some(status_data.reset_date),
+                        ..stored_agent
+                    })
+                    .map_err(ApiError::TokenStore)?;
+                Ok(false)
+            }
+        }
+    }
+
+    /// Archives the stored agent and registers a new agent with the same symbol and faction in its place.
+    ///
+    /// This should only be used after [`has_server_reset`](Self::has_server_reset) confirms the stored token is dead.
+    ///
+    /// Returns an [`ApiClient`] registered to the new agent, or the [`ApiError`] reason for failure.
+    pub fn recover_from_reset(&self) -> ApiResult<Self> {
+        let stored_agent = self.token_store.load().ok_or(ApiError::MissingToken)?;
+        let (Some(symbol), Some(faction)) = (stored_agent.symbol, stored_agent.faction) else {
+            return Err(ApiError::MissingAgentInfo);
+        };
+
+        let archive_label = stored_agent
+            .reset_date
+            .unwrap_or_else(|| String::from("archived"));
+        self.token_store
+            .archive(&archive_label)
+            .map_err(ApiError::TokenStore)?;
+
+        // The dead token mustn't be sent along, and the new agent's token is stored by the registration itself.
+        Self {
+            token: String::new(),
+            ..self.clone()
+        }
+        .register(&symbol, faction)
+    }
+
+    /// Turns strict mode on or off.
+    ///
+    /// Responses that don't fully match the schema in [`data`] are always parsed as far as possible; in strict
+    /// mode, every unknown field or value they contain is also reported as a warning.
+    ///
+    /// * `strict_mode` - whether to report schema drift.
+    pub fn set_strict_mode(&mut self, strict_mode: bool) {
+        self.strict_mode = strict_mode;
+    }
+
+    /// Adds the client's token to a request, if it has one.
+    ///
+    /// Public data such as the factions can be browsed without a token, and registration only needs an account
+    /// token on newer servers, so an empty `Authorization` header must not be sent in its place.
+    ///
+    /// * `request` - the [`RequestBuilder`](reqwest::blocking::RequestBuilder) to authenticate.
+    fn authenticate(
+        &self,
+        request: reqwest::blocking::RequestBuilder,
+    ) -> reqwest::blocking::RequestBuilder {
+        if self.token.is_empty() {
+            request
+        } else {
+            request.bearer_auth(&self.token)
+        }
+    }
+
+    /// Parses JSON from the SpaceTraders API, reporting any schema drift in strict mode.
+    ///
+    /// * `json` - JSON content of the response.
+    /// * `url` - URL the response came from.
+    ///
+    /// Returns the parsed data, or [`ApiError::Parse`] if the JSON can't be parsed into it.
+    fn parse_json<T: DeserializeOwned>(&self, json: serde_json::Value, url: &str) -> ApiResult<T> {
+        // Drift noted by any other parsing on this thread isn't about this response.
+        data::take_schema_drift();
+        let parsed = serde_json::from_value(json).map_err(ApiError::Parse);
+
+        let schema_drift = data::take_schema_drift();
+        if self.strict_mode && !schema_drift.is_empty() {
+            println!(
+                "Warning: response from {url} doesn't match the known schema: {}",
+                schema_drift.join(", ")
+            );
+        }
+        parsed
+    }
+
+    /// Reads the JSON body of a response from the SpaceTraders API and parses it.
+    ///
+    /// * `response` - the [`Response`](reqwest::blocking::Response).
+    ///
+    /// Returns the parsed data, or the [`ApiError`] reason for failure.
+    fn parse_response<T: DeserializeOwned>(
+        &self,
+        response: reqwest::blocking::Response,
+    ) -> ApiResult<T> {
+        let url = response.url().to_string();
+        let body = response.text().map_err(ApiError::Network)?;
+        let json = serde_json::from_str(&body).map_err(ApiError::Parse)?;
+        self.parse_json(json, &url)
+    }
+
+    /// Sends a GET request and unwraps the data from the SpaceTraders response.
+    ///
+    /// * `url` - URL for the given API endpoint.
+    ///
+    /// Returns the response data, or the [`ApiError`] reason for failure.
+    fn get_data<T: DeserializeOwned>(&self, url: &str) -> ApiResult<T> {
+        let api_response: ApiResponse<T> =
+            self.parse_response(self.get(url).map_err(ApiError::Network)?)?;
+
+        match api_response {
+            ApiResponse::Data(data) => Ok(data),
+            ApiResponse::Error(api_error) => Err(ApiError::BadRequest(api_error)),
+        }
+    }
+
+    /// Sends a POST request and unwraps the data from the SpaceTraders response.
+    ///
+    /// * `request_body` - JSON content of the request.
+    /// * `url` - URL for the given API endpoint.
+    ///
+    /// Returns the response data, or the [`ApiError`] reason for failure.
+    fn post_data<T: DeserializeOwned>(
+        &self,
+        request_body: serde_json::Value,
+        url: &str,
+    ) -> ApiResult<T> {
+        let api_response: ApiResponse<T> = self.parse_response(
+            self.post(request_body.to_string(), url)
+                .map_err(ApiError::Network)?,
+        )?;
+
+        match api_response {
+            ApiResponse::Data(data) => Ok(data),
+            ApiResponse::Error(api_error) => Err(ApiError::BadRequest(api_error)),
+        }
+    }
+
+    /// Sends GET requests for every page of a list endpoint and collects the data from the responses.
+    ///
+    /// * `url` - URL for the given API endpoint, without pagination parameters.
+    ///
+    /// Returns the data from all pages, or the [`ApiError`] reason for failure.
+    fn get_all_pages<T: DeserializeOwned>(&self, url: &str) -> ApiResult<Vec<T>> {
+        let mut all_data = Vec::new();
+        let mut page = 1;
+        loop {
+            let page_url = format!("{url}?limit={PAGE_LIMIT}&page={page}");
+            let page_response: serde_json::Value =
+                self.parse_response(self.get(&page_url).map_err(ApiError::Network)?)?;
+
+            // Pages carry a `meta` field next to `data`, so they can't be parsed as an `ApiResponse`.
+            if let Some(api_error) = page_response.get("error") {
+                return Err(ApiError::BadRequest(
+                    self.parse_json(api_error.clone(), &page_url)?,
+                ));
+            }
+            let page_data: PageResponse<T> = self.parse_json(page_response, &page_url)?;
+
+            let is_last_page = page_data.meta.page * page_data.meta.limit >= page_data.meta.total;
+            all_data.extend(page_data.data);
+            if is_last_page {
+                return Ok(all_data);
+            }
+            page += 1;
+        }
+    }
 }
 
 impl HttpClient for ApiClient {
     fn get(&self, url: &str) -> reqwest::Result<reqwest::blocking::Response> {
-        self.http_client
-            .get(url)
-            .bearer_auth(&self.token)
+        self.rate_limiter.acquire();
+        self.authenticate(self.http_client.get(url))
             .header(header::CONTENT_TYPE, "application/json")
             .send()
     }
@@ -141,8 +884,20 @@ impl HttpClient for ApiClient {
         request_body: impl Into<reqwest::blocking::Body>,
         url: &str,
     ) -> reqwest::Result<reqwest::blocking::Response> {
-        self.http_client
-            .post(url)
+        self.rate_limiter.acquire();
+        self.authenticate(self.http_client.post(url))
+            .header(header::CONTENT_TYPE, "application/json")
+            .body(request_body)
+            .send()
+    }
+
+    fn patch(
+        &self,
+        request_body: impl Into<reqwest::blocking::Body>,
+        url: &str,
+    ) -> reqwest::Result<reqwest::blocking::Response> {
+        self.rate_limiter.acquire();
+        self.authenticate(self.http_client.patch(url))
             .header(header::CONTENT_TYPE, "application/json")
             .body(request_body)
             .send()
@@ -150,70 +905,520 @@ impl HttpClient for ApiClient {
 }
 
 impl TraderApis for ApiClient {
+    fn get_status(&self) -> ApiResult<StatusData> {
+        // Unlike other endpoints, the server status isn't wrapped in a `data` field.
+        let response = self.get(ROOT_URL).map_err(ApiError::Network)?;
+        if !response.status().is_success() {
+            let api_response: ApiResponse<StatusData> = self.parse_response(response)?;
+            return match api_response {
+                ApiResponse::Data(status_data) => Ok(status_data),
+                ApiResponse::Error(api_error) => Err(ApiError::BadRequest(api_error)),
+            };
+        }
+
+        let status_data: StatusData = self.parse_response(response)?;
+        // Anything cached before the latest reset describes a universe that no longer exists, so a cache that
+        // can't be cleared mustn't go unnoticed.
+        utils::cache::invalidate_cache_on_reset(&status_data.reset_date)
+            .map_err(ApiError::Cache)?;
+        Ok(status_data)
+    }
+
     fn register_new_agent(
         &self,
         agent_name: &str,
         faction_name: Factions,
     ) -> ApiResult<RegistrationData> {
-        let url = format!("{ROOT_URL}/register");
-        let request_body = format!(
-            "{:?}",
-            HashMap::from([
-                ("symbol", agent_name),
-                ("faction", &faction_name.to_string().to_uppercase())
-            ])
-        );
+        self.post_data(
+            serde_json::json!({ "symbol": agent_name, "faction": faction_name }),
+            &format!("{ROOT_URL}/register"),
+        )
+    }
 
-        let api_response: ApiResponse<RegistrationData> = self
-            .post(request_body, &url)
-            .map_err(|reqwest_err| ApiError::Network(reqwest_err))?
-            .json::<ApiResponse<RegistrationData>>()
-            .expect("Error parsing API JSON response");
+    fn get_agent_data(&self) -> ApiResult<AgentData> {
+        self.get_data(&format!("{ROOT_URL}/my/agent"))
+    }
 
-        match api_response {
-            ApiResponse::Data(registrion_data) => {
-                utils::config::write_default_config_file(ConfigData {
-                    token: registrion_data.token.clone(),
-                })
-                .expect("Error writing to config file!");
-                Ok(registrion_data)
-            }
-            ApiResponse::Error(api_error) => Err(ApiError::BadRequest(api_error)),
+    fn list_reputations(&self) -> ApiResult<Vec<FactionReputation>> {
+        self.get_all_pages(&format!("{ROOT_URL}/my/factions"))
+    }
+
+    fn list_factions(&self) -> ApiResult<Vec<FactionData>> {
+        self.get_all_pages(&format!("{ROOT_URL}/factions"))
+    }
+
+    fn get_faction(&self, faction: &Factions) -> ApiResult<FactionData> {
+        self.get_data(&format!("{ROOT_URL}/factions/{faction}"))
+    }
+
+    fn get_waypoint_location_data(&self, waypoint: &str) -> ApiResult<LocationData> {
+        if let Some(location_data) = utils::cache::read_cached_waypoint(waypoint) {
+            return Ok(location_data);
         }
+
+        let system = system_of(waypoint);
+        let location_data: LocationData =
+            self.get_data(&format!("{ROOT_URL}/systems/{system}/waypoints/{waypoint}"))?;
+
+        // A failed cache write only costs another request next time, so don't fail the call over it.
+        let _ = utils::cache::write_cached_waypoint(&location_data);
+        Ok(location_data)
     }
 
-    fn get_agent_data(&self) -> ApiResult<AgentData> {
-        let url = format!("{ROOT_URL}/my/agent");
+    fn get_waypoints_location_data(&self, waypoints: &[&str]) -> ApiResult<Vec<LocationData>> {
+        let mut cached_waypoints = utils::cache::read_cached_waypoints(waypoints);
+        let mut fetched_waypoints = Vec::new();
+        let location_data = waypoints
+            .iter()
+            .map(|waypoint| {
+                if let Some(location_data) = cached_waypoints.get(*waypoint) {
+                    return Ok(location_data.clone());
+                }
+                let system = system_of(waypoint);
+                let location_data: LocationData =
+                    self.get_data(&format!("{ROOT_URL}/systems/{system}/waypoints/{waypoint}"))?;
+                cached_waypoints.insert(waypoint.to_string(), location_data.clone());
+                fetched_waypoints.push(location_data.clone());
+                Ok(location_data)
+            })
+            .collect();
+
+        // Whatever was fetched before a failure is still worth caching.
+        if !fetched_waypoints.is_empty() {
+            let _ = utils::cache::write_cached_waypoints(&fetched_waypoints);
+        }
+        location_data
+    }
 
-        let api_response: ApiResponse<AgentData> = self
-            .get(&url)
-            .map_err(|reqwest_err| ApiError::Network(reqwest_err))?
-            .json()
-            .expect("Error parsing API response JSON!");
+    fn get_market(&self, waypoint: &str) -> ApiResult<MarketData> {
+        let system = system_of(waypoint);
+        let market_data: MarketData = self.get_data(&format!(
+            "{ROOT_URL}/systems/{system}/waypoints/{waypoint}/market"
+        ))?;
 
-        match api_response {
-            ApiResponse::Data(agent_data) => Ok(agent_data),
-            ApiResponse::Error(api_error) => Err(ApiError::BadRequest(api_error)),
+        // Losing a price observation isn't worth failing the request over.
+        let _ = history::record_market(&market_data);
+        Ok(market_data)
+    }
+
+    fn list_ships(&self) -> ApiResult<Vec<ShipData>> {
+        self.get_all_pages(&format!("{ROOT_URL}/my/ships"))
+    }
+
+    fn get_ship(&self, ship: &str) -> ApiResult<ShipData> {
+        self.get_data(&format!("{ROOT_URL}/my/ships/{ship}"))
+    }
+
+    fn list_waypoints(&self, system: &str) -> ApiResult<Vec<LocationData>> {
+        if let Some(waypoints) = utils::cache::read_cached_system_waypoints(system) {
+            return Ok(waypoints);
         }
+
+        let waypoints: Vec<LocationData> =
+            self.get_all_pages(&format!("{ROOT_URL}/systems/{system}/waypoints"))?;
+
+        let _ = utils::cache::write_cached_system_waypoints(system, &waypoints);
+        Ok(waypoints)
     }
 
-    fn get_waypoint_location_data(&self, waypoint: &str) -> ApiResult<LocationData> {
-        let system = waypoint
-            .split("-")
-            .map(String::from)
-            .collect::<Vec<String>>()[0..=1]
-            .join("-");
-        let url = format!("{ROOT_URL}/systems/{system}/waypoints/{waypoint}");
-
-        let api_response: ApiResponse<LocationData> = self
-            .get(&url)
-            .map_err(|reqwest_err| ApiError::Network(reqwest_err))?
-            .json()
-            .expect("Error parsing API response JSON!");
+    fn get_construction(&self, waypoint: &str) -> ApiResult<Construction> {
+        let system = system_of(waypoint);
+        self.get_data(&format!(
+            "{ROOT_URL}/systems/{system}/waypoints/{waypoint}/construction"
+        ))
+    }
+
+    fn supply_construction(
+        &self,
+        ship: &str,
+        trade_symbol: &TradeSymbol,
+        units: u32,
+    ) -> ApiResult<ConstructionSupplyData> {
+        let waypoint = self.get_ship(ship)?.nav.waypoint_symbol;
+        let system = system_of(&waypoint);
+        self.post_data(
+            serde_json::json!({ "shipSymbol": ship, "tradeSymbol": trade_symbol, "units": units }),
+            &format!("{ROOT_URL}/systems/{system}/waypoints/{waypoint}/construction/supply"),
+        )
+    }
+
+    fn get_jump_gate(&self, waypoint: &str) -> ApiResult<JumpGate> {
+        if let Some(jump_gate) = utils::cache::read_cached_jump_gate(waypoint) {
+            return Ok(jump_gate);
+        }
+
+        let system = system_of(waypoint);
+        let jump_gate: JumpGate = self.get_data(&format!(
+            "{ROOT_URL}/systems/{system}/waypoints/{waypoint}/jump-gate"
+        ))?;
+
+        // A failed cache write only costs a refetch later, so don't fail the request over it.
+        let _ = utils::cache::write_cached_jump_gate(&jump_gate);
+        Ok(jump_gate)
+    }
+
+    fn orbit_ship(&self, ship: &str) -> ApiResult<NavInfo> {
+        let ship_nav_data: ShipNavData = self.post_data(
+            serde_json::json!({}),
+            &format!("{ROOT_URL}/my/ships/{ship}/orbit"),
+        )?;
+        Ok(ship_nav_data.nav)
+    }
+
+    fn dock_ship(&self, ship: &str) -> ApiResult<NavInfo> {
+        let ship_nav_data: ShipNavData = self.post_data(
+            serde_json::json!({}),
+            &format!("{ROOT_URL}/my/ships/{ship}/dock"),
+        )?;
+        Ok(ship_nav_data.nav)
+    }
+
+    fn navigate_ship(&self, ship: &str, waypoint: &str) -> ApiResult<NavigationData> {
+        self.post_data(
+            serde_json::json!({ "waypointSymbol": waypoint }),
+            &format!("{ROOT_URL}/my/ships/{ship}/navigate"),
+        )
+    }
+
+    fn jump_ship(&self, ship: &str, system: &str) -> ApiResult<JumpData> {
+        let ship_data = self.get_ship(ship)?;
+        let jump_gate = self.get_jump_gate(&ship_data.nav.waypoint_symbol)?;
+        let destination = jump_gate
+            .connection_to(system)
+            .ok_or_else(|| ApiError::Unreachable(system.to_string()))?;
+
+        let jump_data: JumpData = self.post_data(
+            serde_json::json!({ "waypointSymbol": destination }),
+            &format!("{ROOT_URL}/my/ships/{ship}/jump"),
+        )?;
+
+        // Jumps are paid for with antimatter bought on the spot.
+        let _ = ledger::record_market_transaction(EntryKind::Purchase, &jump_data.transaction);
+        Ok(jump_data)
+    }
+
+    fn warp_ship(&self, ship: &str, waypoint: &str) -> ApiResult<NavigationData> {
+        self.post_data(
+            serde_json::json!({ "waypointSymbol": waypoint }),
+            &format!("{ROOT_URL}/my/ships/{ship}/warp"),
+        )
+    }
+
+    fn set_flight_mode(&self, ship: &str, flight_mode: FlightMode) -> ApiResult<NavInfo> {
+        let api_response: ApiResponse<NavInfo> = self.parse_response(
+            self.patch(
+                serde_json::json!({ "flightMode": flight_mode }).to_string(),
+                &format!("{ROOT_URL}/my/ships/{ship}/nav"),
+            )
+            .map_err(ApiError::Network)?,
+        )?;
 
         match api_response {
-            ApiResponse::Data(location_data) => Ok(location_data),
+            ApiResponse::Data(nav_info) => Ok(nav_info),
             ApiResponse::Error(api_error) => Err(ApiError::BadRequest(api_error)),
         }
     }
+
+    fn create_chart(&self, ship: &str) -> ApiResult<ChartData> {
+        let chart_data: ChartData = self.post_data(
+            serde_json::json!({}),
+            &format!("{ROOT_URL}/my/ships/{ship}/chart"),
+        )?;
+
+        // The cached copy of the waypoint predates the chart and is missing its traits.
+        let _ = utils::cache::write_cached_waypoint(&chart_data.waypoint);
+        Ok(chart_data)
+    }
+
+    fn create_survey(&self, ship: &str) -> ApiResult<SurveyData> {
+        self.post_data(
+            serde_json::json!({}),
+            &format!("{ROOT_URL}/my/ships/{ship}/survey"),
+        )
+    }
+
+    fn scan_systems(&self, ship: &str) -> ApiResult<SystemScanData> {
+        self.post_data(
+            serde_json::json!({}),
+            &format!("{ROOT_URL}/my/ships/{ship}/scan/systems"),
+        )
+    }
+
+    fn scan_waypoints(&self, ship: &str) -> ApiResult<WaypointScanData> {
+        let waypoint_scan_data: WaypointScanData = self.post_data(
+            serde_json::json!({}),
+            &format!("{ROOT_URL}/my/ships/{ship}/scan/waypoints"),
+        )?;
+
+        // Scans reveal as much as fetching each waypoint would. Caching them saves those requests, and keeps the
+        // waypoints of an already listed system fresh, so listing it again doesn't page through the API.
+        let scanned_waypoints: Vec<LocationData> = waypoint_scan_data
+            .waypoints
+            .iter()
+            .cloned()
+            .map(LocationData::from)
+            .collect();
+        let _ = utils::cache::write_cached_waypoints(&scanned_waypoints);
+        Ok(waypoint_scan_data)
+    }
+
+    fn scan_ships(&self, ship: &str) -> ApiResult<ShipScanData> {
+        self.post_data(
+            serde_json::json!({}),
+            &format!("{ROOT_URL}/my/ships/{ship}/scan/ships"),
+        )
+    }
+
+    fn siphon_resources(&self, ship: &str) -> ApiResult<SiphonData> {
+        self.post_data(
+            serde_json::json!({}),
+            &format!("{ROOT_URL}/my/ships/{ship}/siphon"),
+        )
+    }
+
+    fn extract_resources(&self, ship: &str, survey: Option<&Survey>) -> ApiResult<ExtractionData> {
+        let request_body = match survey {
+            Some(survey) => serde_json::json!({ "survey": survey }),
+            None => serde_json::json!({}),
+        };
+        self.post_data(request_body, &format!("{ROOT_URL}/my/ships/{ship}/extract"))
+    }
+
+    fn jettison_cargo(
+        &self,
+        ship: &str,
+        trade_symbol: &TradeSymbol,
+        units: u32,
+    ) -> ApiResult<CargoInfo> {
+        let cargo_data: CargoData = self.post_data(
+            serde_json::json!({ "symbol": trade_symbol, "units": units }),
+            &format!("{ROOT_URL}/my/ships/{ship}/jettison"),
+        )?;
+        Ok(cargo_data.cargo)
+    }
+
+    fn transfer_cargo(
+        &self,
+        from_ship: &str,
+        to_ship: &str,
+        trade_symbol: &TradeSymbol,
+        units: u32,
+    ) -> ApiResult<TransferData> {
+        self.post_data(
+            serde_json::json!({ "tradeSymbol": trade_symbol, "units": units, "shipSymbol": to_ship }),
+            &format!("{ROOT_URL}/my/ships/{from_ship}/transfer"),
+        )
+    }
+
+    fn sell_cargo(
+        &self,
+        ship: &str,
+        trade_symbol: &TradeSymbol,
+        units: u32,
+    ) -> ApiResult<TradeData> {
+        let trade_data: TradeData = self.post_data(
+            serde_json::json!({ "symbol": trade_symbol, "units": units }),
+            &format!("{ROOT_URL}/my/ships/{ship}/sell"),
+        )?;
+
+        // A missing ledger entry isn't worth failing the request over.
+        let _ = ledger::record_market_transaction(EntryKind::Sale, &trade_data.transaction);
+        Ok(trade_data)
+    }
+
+    fn refuel_ship(
+        &self,
+        ship: &str,
+        units: Option<u32>,
+        from_cargo: bool,
+    ) -> ApiResult<RefuelData> {
+        let mut request_body = serde_json::json!({ "fromCargo": from_cargo });
+        if let Some(units) = units {
+            request_body["units"] = units.into();
+        }
+        let refuel_data: RefuelData =
+            self.post_data(request_body, &format!("{ROOT_URL}/my/ships/{ship}/refuel"))?;
+
+        let _ = ledger::record_market_transaction(EntryKind::Refuel, &refuel_data.transaction);
+        Ok(refuel_data)
+    }
+
+    fn get_repair_ship(&self, ship: &str) -> ApiResult<ShipServiceTransaction> {
+        let quote: ShipServiceQuote =
+            self.get_data(&format!("{ROOT_URL}/my/ships/{ship}/repair"))?;
+        Ok(quote.transaction)
+    }
+
+    fn repair_ship(&self, ship: &str) -> ApiResult<RepairData> {
+        let repair_data: RepairData = self.post_data(
+            serde_json::json!({}),
+            &format!("{ROOT_URL}/my/ships/{ship}/repair"),
+        )?;
+
+        let _ = ledger::record_ship_service(EntryKind::Repair, &repair_data.transaction);
+        Ok(repair_data)
+    }
+
+    fn get_scrap_ship(&self, ship: &str) -> ApiResult<ShipServiceTransaction> {
+        let quote: ShipServiceQuote =
+            self.get_data(&format!("{ROOT_URL}/my/ships/{ship}/scrap"))?;
+        Ok(quote.transaction)
+    }
+
+    fn scrap_ship(&self, ship: &str) -> ApiResult<ScrapData> {
+        let scrap_data: ScrapData = self.post_data(
+            serde_json::json!({}),
+            &format!("{ROOT_URL}/my/ships/{ship}/scrap"),
+        )?;
+
+        let _ = ledger::record_ship_service(EntryKind::Scrap, &scrap_data.transaction);
+        Ok(scrap_data)
+    }
+
+    fn purchase_ship(&self, ship_type: &ShipType, waypoint: &str) -> ApiResult<ShipPurchaseData> {
+        let ship_purchase_data: ShipPurchaseData = self.post_data(
+            serde_json::json!({ "shipType": ship_type, "waypointSymbol": waypoint }),
+            &format!("{ROOT_URL}/my/ships"),
+        )?;
+
+        let _ = ledger::record_ship_purchase(&ship_purchase_data.transaction);
+        Ok(ship_purchase_data)
+    }
+
+    fn install_mount(&self, ship: &str, mount: &ShipMountSymbol) -> ApiResult<MountsData> {
+        let mounts_data: MountsData = self.post_data(
+            serde_json::json!({ "symbol": mount }),
+            &format!("{ROOT_URL}/my/ships/{ship}/mounts/install"),
+        )?;
+
+        let _ = ledger::record_ship_modification(&mounts_data.transaction);
+        Ok(mounts_data)
+    }
+
+    fn remove_mount(&self, ship: &str, mount: &ShipMountSymbol) -> ApiResult<MountsData> {
+        let mounts_data: MountsData = self.post_data(
+            serde_json::json!({ "symbol": mount }),
+            &format!("{ROOT_URL}/my/ships/{ship}/mounts/remove"),
+        )?;
+
+        let _ = ledger::record_ship_modification(&mounts_data.transaction);
+        Ok(mounts_data)
+    }
+
+    fn install_module(&self, ship: &str, module: &ShipModuleSymbol) -> ApiResult<ModulesData> {
+        let modules_data: ModulesData = self.post_data(
+            serde_json::json!({ "symbol": module }),
+            &format!("{ROOT_URL}/my/ships/{ship}/modules/install"),
+        )?;
+
+        let _ = ledger::record_ship_modification(&modules_data.transaction);
+        Ok(modules_data)
+    }
+
+    fn remove_module(&self, ship: &str, module: &ShipModuleSymbol) -> ApiResult<ModulesData> {
+        let modules_data: ModulesData = self.post_data(
+            serde_json::json!({ "symbol": module }),
+            &format!("{ROOT_URL}/my/ships/{ship}/modules/remove"),
+        )?;
+
+        let _ = ledger::record_ship_modification(&modules_data.transaction);
+        Ok(modules_data)
+    }
+
+    fn purchase_cargo(
+        &self,
+        ship: &str,
+        trade_symbol: &TradeSymbol,
+        units: u32,
+    ) -> ApiResult<TradeData> {
+        let trade_data: TradeData = self.post_data(
+            serde_json::json!({ "symbol": trade_symbol, "units": units }),
+            &format!("{ROOT_URL}/my/ships/{ship}/purchase"),
+        )?;
+
+        let _ = ledger::record_market_transaction(EntryKind::Purchase, &trade_data.transaction);
+        Ok(trade_data)
+    }
+
+    fn list_contracts(&self) -> ApiResult<Vec<ContractData>> {
+        self.get_all_pages(&format!("{ROOT_URL}/my/contracts"))
+    }
+
+    fn accept_contract(&self, contract_id: &str) -> ApiResult<ContractUpdateData> {
+        let contract_update_data: ContractUpdateData = self.post_data(
+            serde_json::json!({}),
+            &format!("{ROOT_URL}/my/contracts/{contract_id}/accept"),
+        )?;
+
+        let contract = &contract_update_data.contract;
+        let _ = ledger::record_contract_payment(contract, contract.terms.payment.on_accepted);
+        Ok(contract_update_data)
+    }
+
+    fn deliver_contract(
+        &self,
+        contract_id: &str,
+        ship: &str,
+        trade_symbol: &TradeSymbol,
+        units: u32,
+    ) -> ApiResult<DeliveryData> {
+        self.post_data(
+            serde_json::json!({ "shipSymbol": ship, "tradeSymbol": trade_symbol, "units": units }),
+            &format!("{ROOT_URL}/my/contracts/{contract_id}/deliver"),
+        )
+    }
+
+    fn fulfill_contract(&self, contract_id: &str) -> ApiResult<ContractUpdateData> {
+        let contract_update_data: ContractUpdateData = self.post_data(
+            serde_json::json!({}),
+            &format!("{ROOT_URL}/my/contracts/{contract_id}/fulfill"),
+        )?;
+
+        let contract = &contract_update_data.contract;
+        let _ = ledger::record_contract_payment(contract, contract.terms.payment.on_fulfilled);
+        Ok(contract_update_data)
+    }
+
+    fn negotiate_contract(&self, ship: &str) -> ApiResult<ContractData> {
+        let contract_offer_data: ContractOfferData = self.post_data(
+            serde_json::json!({}),
+            &format!("{ROOT_URL}/my/ships/{ship}/negotiate/contract"),
+        )?;
+        Ok(contract_offer_data.contract)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{ApiClient, ROOT_URL};
+
+    use reqwest::header;
+
+    #[test]
+    fn verify_auth_header_needs_token() {
+        // Setup
+        let unregistered_client = ApiClient::unregistered();
+        let account_client = ApiClient::with_account_token("TEST_ACCOUNT_TOKEN");
+        let http_client = reqwest::blocking::Client::new();
+
+        // Test
+        let unregistered_request = unregistered_client
+            .authenticate(http_client.get(ROOT_URL))
+            .build()
+            .unwrap();
+        let account_request = account_client
+            .authenticate(http_client.get(ROOT_URL))
+            .build()
+            .unwrap();
+
+        // Verify
+        assert!(unregistered_request
+            .headers()
+            .get(header::AUTHORIZATION)
+            .is_none());
+        assert_eq!(
+            account_request.headers()[header::AUTHORIZATION],
+            "Bearer TEST_ACCOUNT_TOKEN"
+        );
+    }
 }
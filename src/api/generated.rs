@@ -0,0 +1,3071 @@
+//! Models and endpoint paths generated from the SpaceTraders OpenAPI document.
+//!
+//! Regenerate with `cargo run --bin codegen` instead of editing by hand.
+
+use super::data::UnknownFields;
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+/// The activity level of a trade good. If the good is an import, this represents how strong consumption is. If the good is an export, this represents how strong the production is for the good. When activity is strong, consumption or production is near maximum capacity. When activity is weak, consumption or production is near minimum capacity.
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+pub enum ActivityLevel {
+    Weak,
+    Growing,
+    Strong,
+    Restricted,
+    /// A symbol this client doesn't know about yet.
+    Unknown(String),
+}
+impl ActivityLevel {
+    /// Symbol the API uses for this value.
+    pub fn as_str(&self) -> &str {
+        match self {
+            ActivityLevel::Weak => "WEAK",
+            ActivityLevel::Growing => "GROWING",
+            ActivityLevel::Strong => "STRONG",
+            ActivityLevel::Restricted => "RESTRICTED",
+            ActivityLevel::Unknown(symbol) => symbol,
+        }
+    }
+}
+impl From<&str> for ActivityLevel {
+    fn from(symbol: &str) -> Self {
+        match symbol {
+            "WEAK" => ActivityLevel::Weak,
+            "GROWING" => ActivityLevel::Growing,
+            "STRONG" => ActivityLevel::Strong,
+            "RESTRICTED" => ActivityLevel::Restricted,
+            _ => ActivityLevel::Unknown(symbol.to_string()),
+        }
+    }
+}
+impl std::fmt::Display for ActivityLevel {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+impl Serialize for ActivityLevel {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+impl<'de> Deserialize<'de> for ActivityLevel {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let symbol = Self::from(String::deserialize(deserializer)?.as_str());
+        if let ActivityLevel::Unknown(ref unknown_symbol) = symbol {
+            super::data::record_schema_drift(format!("unknown ActivityLevel `{unknown_symbol}`"));
+        }
+        Ok(symbol)
+    }
+}
+
+/// Agent details.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Agent {
+    /// Account ID that is tied to this agent. Only included on your own agent.
+    pub account_id: Option<String>,
+    /// The number of credits the agent has available. Credits can be negative if funds have been overdrawn.
+    pub credits: i64,
+    /// The headquarters of the agent.
+    pub headquarters: String,
+    /// How many ships are owned by the agent.
+    pub ship_count: i64,
+    /// The faction the agent started with.
+    pub starting_faction: String,
+    /// Symbol of the agent.
+    pub symbol: String,
+    #[serde(flatten)]
+    pub extra: UnknownFields,
+}
+
+/// The chart of a system or waypoint, which makes the location visible to other agents.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Chart {
+    /// The agent that submitted the chart for this waypoint.
+    pub submitted_by: Option<String>,
+    /// The time the chart for this waypoint was submitted.
+    pub submitted_on: Option<DateTime<Utc>>,
+    pub waypoint_symbol: Option<WaypointSymbol>,
+    #[serde(flatten)]
+    pub extra: UnknownFields,
+}
+
+/// The construction details of a waypoint.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Construction {
+    /// Whether the waypoint has been constructed.
+    pub is_complete: bool,
+    /// The materials required to construct the waypoint.
+    pub materials: Vec<ConstructionMaterial>,
+    /// The symbol of the waypoint.
+    pub symbol: String,
+    #[serde(flatten)]
+    pub extra: UnknownFields,
+}
+
+/// The details of the required construction materials for a given waypoint under construction.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ConstructionMaterial {
+    /// The number of units fulfilled toward the required amount.
+    pub fulfilled: i64,
+    /// The number of units required.
+    pub required: i64,
+    pub trade_symbol: TradeSymbol,
+    #[serde(flatten)]
+    pub extra: UnknownFields,
+}
+
+/// Contract details.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Contract {
+    /// Whether the contract has been accepted by the agent
+    pub accepted: bool,
+    /// The time at which the contract is no longer available to be accepted
+    pub deadline_to_accept: Option<DateTime<Utc>>,
+    /// Deprecated in favor of deadlineToAccept
+    pub expiration: DateTime<Utc>,
+    /// The symbol of the faction that this contract is for.
+    pub faction_symbol: String,
+    /// Whether the contract has been fulfilled
+    pub fulfilled: bool,
+    /// ID of the contract.
+    pub id: String,
+    pub terms: ContractTerms,
+    /// Type of contract.
+    pub r#type: ContractType,
+    #[serde(flatten)]
+    pub extra: UnknownFields,
+}
+
+/// The details of a delivery contract. Includes the type of good, units needed, and the destination.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ContractDeliverGood {
+    /// The destination where goods need to be delivered.
+    pub destination_symbol: String,
+    /// The symbol of the trade good to deliver.
+    pub trade_symbol: String,
+    /// The number of units fulfilled on this contract.
+    pub units_fulfilled: i64,
+    /// The number of units that need to be delivered on this contract.
+    pub units_required: i64,
+    #[serde(flatten)]
+    pub extra: UnknownFields,
+}
+
+/// Payments for the contract.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ContractPayment {
+    /// The amount of credits received up front for accepting the contract.
+    pub on_accepted: i64,
+    /// The amount of credits received when the contract is fulfilled.
+    pub on_fulfilled: i64,
+    #[serde(flatten)]
+    pub extra: UnknownFields,
+}
+
+/// The terms to fulfill the contract.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ContractTerms {
+    /// The deadline for the contract.
+    pub deadline: DateTime<Utc>,
+    /// The cargo that needs to be delivered to fulfill the contract.
+    pub deliver: Option<Vec<ContractDeliverGood>>,
+    pub payment: ContractPayment,
+    #[serde(flatten)]
+    pub extra: UnknownFields,
+}
+
+/// Type of contract.
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+pub enum ContractType {
+    Procurement,
+    Transport,
+    Shuttle,
+    /// A symbol this client doesn't know about yet.
+    Unknown(String),
+}
+impl ContractType {
+    /// Symbol the API uses for this value.
+    pub fn as_str(&self) -> &str {
+        match self {
+            ContractType::Procurement => "PROCUREMENT",
+            ContractType::Transport => "TRANSPORT",
+            ContractType::Shuttle => "SHUTTLE",
+            ContractType::Unknown(symbol) => symbol,
+        }
+    }
+}
+impl From<&str> for ContractType {
+    fn from(symbol: &str) -> Self {
+        match symbol {
+            "PROCUREMENT" => ContractType::Procurement,
+            "TRANSPORT" => ContractType::Transport,
+            "SHUTTLE" => ContractType::Shuttle,
+            _ => ContractType::Unknown(symbol.to_string()),
+        }
+    }
+}
+impl std::fmt::Display for ContractType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+impl Serialize for ContractType {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+impl<'de> Deserialize<'de> for ContractType {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let symbol = Self::from(String::deserialize(deserializer)?.as_str());
+        if let ContractType::Unknown(ref unknown_symbol) = symbol {
+            super::data::record_schema_drift(format!("unknown ContractType `{unknown_symbol}`"));
+        }
+        Ok(symbol)
+    }
+}
+
+/// A cooldown is a period of time in which a ship cannot perform certain actions.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Cooldown {
+    /// The date and time when the cooldown expires in ISO 8601 format
+    pub expiration: Option<DateTime<Utc>>,
+    /// The remaining duration of the cooldown in seconds
+    pub remaining_seconds: i64,
+    /// The symbol of the ship that is on cooldown
+    pub ship_symbol: String,
+    /// The total duration of the cooldown in seconds
+    pub total_seconds: i64,
+    #[serde(flatten)]
+    pub extra: UnknownFields,
+}
+
+/// Extraction details.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Extraction {
+    /// Symbol of the ship that executed the extraction.
+    pub ship_symbol: String,
+    pub r#yield: ExtractionYield,
+    #[serde(flatten)]
+    pub extra: UnknownFields,
+}
+
+/// A yield from the extraction operation.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ExtractionYield {
+    pub symbol: TradeSymbol,
+    /// The number of units extracted that were placed into the ship's cargo hold.
+    pub units: i64,
+    #[serde(flatten)]
+    pub extra: UnknownFields,
+}
+
+/// Faction details.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Faction {
+    /// Description of the faction.
+    pub description: String,
+    /// The waypoint in which the faction's HQ is located in.
+    pub headquarters: Option<String>,
+    /// Whether or not the faction is currently recruiting new agents.
+    pub is_recruiting: bool,
+    /// Name of the faction.
+    pub name: String,
+    pub symbol: FactionSymbol,
+    /// List of traits that define this faction.
+    pub traits: Vec<FactionTrait>,
+    #[serde(flatten)]
+    pub extra: UnknownFields,
+}
+
+/// The symbol of the faction.
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+pub enum FactionSymbol {
+    Cosmic,
+    Void,
+    Galactic,
+    Quantum,
+    Dominion,
+    Astro,
+    Corsairs,
+    Obsidian,
+    Aegis,
+    United,
+    Solitary,
+    Cobalt,
+    Omega,
+    Echo,
+    Lords,
+    Cult,
+    Ancients,
+    Shadow,
+    Ethereal,
+    /// A symbol this client doesn't know about yet.
+    Unknown(String),
+}
+impl FactionSymbol {
+    /// Symbol the API uses for this value.
+    pub fn as_str(&self) -> &str {
+        match self {
+            FactionSymbol::Cosmic => "COSMIC",
+            FactionSymbol::Void => "VOID",
+            FactionSymbol::Galactic => "GALACTIC",
+            FactionSymbol::Quantum => "QUANTUM",
+            FactionSymbol::Dominion => "DOMINION",
+            FactionSymbol::Astro => "ASTRO",
+            FactionSymbol::Corsairs => "CORSAIRS",
+            FactionSymbol::Obsidian => "OBSIDIAN",
+            FactionSymbol::Aegis => "AEGIS",
+            FactionSymbol::United => "UNITED",
+            FactionSymbol::Solitary => "SOLITARY",
+            FactionSymbol::Cobalt => "COBALT",
+            FactionSymbol::Omega => "OMEGA",
+            FactionSymbol::Echo => "ECHO",
+            FactionSymbol::Lords => "LORDS",
+            FactionSymbol::Cult => "CULT",
+            FactionSymbol::Ancients => "ANCIENTS",
+            FactionSymbol::Shadow => "SHADOW",
+            FactionSymbol::Ethereal => "ETHEREAL",
+            FactionSymbol::Unknown(symbol) => symbol,
+        }
+    }
+}
+impl From<&str> for FactionSymbol {
+    fn from(symbol: &str) -> Self {
+        match symbol {
+            "COSMIC" => FactionSymbol::Cosmic,
+            "VOID" => FactionSymbol::Void,
+            "GALACTIC" => FactionSymbol::Galactic,
+            "QUANTUM" => FactionSymbol::Quantum,
+            "DOMINION" => FactionSymbol::Dominion,
+            "ASTRO" => FactionSymbol::Astro,
+            "CORSAIRS" => FactionSymbol::Corsairs,
+            "OBSIDIAN" => FactionSymbol::Obsidian,
+            "AEGIS" => FactionSymbol::Aegis,
+            "UNITED" => FactionSymbol::United,
+            "SOLITARY" => FactionSymbol::Solitary,
+            "COBALT" => FactionSymbol::Cobalt,
+            "OMEGA" => FactionSymbol::Omega,
+            "ECHO" => FactionSymbol::Echo,
+            "LORDS" => FactionSymbol::Lords,
+            "CULT" => FactionSymbol::Cult,
+            "ANCIENTS" => FactionSymbol::Ancients,
+            "SHADOW" => FactionSymbol::Shadow,
+            "ETHEREAL" => FactionSymbol::Ethereal,
+            _ => FactionSymbol::Unknown(symbol.to_string()),
+        }
+    }
+}
+impl std::fmt::Display for FactionSymbol {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+impl Serialize for FactionSymbol {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+impl<'de> Deserialize<'de> for FactionSymbol {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let symbol = Self::from(String::deserialize(deserializer)?.as_str());
+        if let FactionSymbol::Unknown(ref unknown_symbol) = symbol {
+            super::data::record_schema_drift(format!("unknown FactionSymbol `{unknown_symbol}`"));
+        }
+        Ok(symbol)
+    }
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FactionTrait {
+    /// A description of the trait.
+    pub description: String,
+    /// The name of the trait.
+    pub name: String,
+    pub symbol: FactionTraitSymbol,
+    #[serde(flatten)]
+    pub extra: UnknownFields,
+}
+
+/// The unique identifier of the trait.
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+pub enum FactionTraitSymbol {
+    Bureaucratic,
+    Secretive,
+    Capitalistic,
+    Industrious,
+    Peaceful,
+    Distrustful,
+    Welcoming,
+    Smugglers,
+    Scavengers,
+    Rebellious,
+    Exiles,
+    Pirates,
+    Raiders,
+    Clan,
+    Guild,
+    Dominion,
+    Fringe,
+    Forsaken,
+    Isolated,
+    Localized,
+    Established,
+    Notable,
+    Dominant,
+    Inescapable,
+    Innovative,
+    Bold,
+    Visionary,
+    Curious,
+    Daring,
+    Exploratory,
+    Resourceful,
+    Flexible,
+    Cooperative,
+    United,
+    Strategic,
+    Intelligent,
+    ResearchFocused,
+    Collaborative,
+    Progressive,
+    Militaristic,
+    TechnologicallyAdvanced,
+    Aggressive,
+    Imperialistic,
+    TreasureHunters,
+    Dexterous,
+    Unpredictable,
+    Brutal,
+    Fleeting,
+    Adaptable,
+    SelfSufficient,
+    Defensive,
+    Proud,
+    Diverse,
+    Independent,
+    SelfInterested,
+    Fragmented,
+    Commercial,
+    FreeMarkets,
+    Entrepreneurial,
+    /// A symbol this client doesn't know about yet.
+    Unknown(String),
+}
+impl FactionTraitSymbol {
+    /// Symbol the API uses for this value.
+    pub fn as_str(&self) -> &str {
+        match self {
+            FactionTraitSymbol::Bureaucratic => "BUREAUCRATIC",
+            FactionTraitSymbol::Secretive => "SECRETIVE",
+            FactionTraitSymbol::Capitalistic => "CAPITALISTIC",
+            FactionTraitSymbol::Industrious => "INDUSTRIOUS",
+            FactionTraitSymbol::Peaceful => "PEACEFUL",
+            FactionTraitSymbol::Distrustful => "DISTRUSTFUL",
+            FactionTraitSymbol::Welcoming => "WELCOMING",
+            FactionTraitSymbol::Smugglers => "SMUGGLERS",
+            FactionTraitSymbol::Scavengers => "SCAVENGERS",
+            FactionTraitSymbol::Rebellious => "REBELLIOUS",
+            FactionTraitSymbol::Exiles => "EXILES",
+            FactionTraitSymbol::Pirates => "PIRATES",
+            FactionTraitSymbol::Raiders => "RAIDERS",
+            FactionTraitSymbol::Clan => "CLAN",
+            FactionTraitSymbol::Guild => "GUILD",
+            FactionTraitSymbol::Dominion => "DOMINION",
+            FactionTraitSymbol::Fringe => "FRINGE",
+            FactionTraitSymbol::Forsaken => "FORSAKEN",
+            FactionTraitSymbol::Isolated => "ISOLATED",
+            FactionTraitSymbol::Localized => "LOCALIZED",
+            FactionTraitSymbol::Established => "ESTABLISHED",
+            FactionTraitSymbol::Notable => "NOTABLE",
+            FactionTraitSymbol::Dominant => "DOMINANT",
+            FactionTraitSymbol::Inescapable => "INESCAPABLE",
+            FactionTraitSymbol::Innovative => "INNOVATIVE",
+            FactionTraitSymbol::Bold => "BOLD",
+            FactionTraitSymbol::Visionary => "VISIONARY",
+            FactionTraitSymbol::Curious => "CURIOUS",
+            FactionTraitSymbol::Daring => "DARING",
+            FactionTraitSymbol::Exploratory => "EXPLORATORY",
+            FactionTraitSymbol::Resourceful => "RESOURCEFUL",
+            FactionTraitSymbol::Flexible => "FLEXIBLE",
+            FactionTraitSymbol::Cooperative => "COOPERATIVE",
+            FactionTraitSymbol::United => "UNITED",
+            FactionTraitSymbol::Strategic => "STRATEGIC",
+            FactionTraitSymbol::Intelligent => "INTELLIGENT",
+            FactionTraitSymbol::ResearchFocused => "RESEARCH_FOCUSED",
+            FactionTraitSymbol::Collaborative => "COLLABORATIVE",
+            FactionTraitSymbol::Progressive => "PROGRESSIVE",
+            FactionTraitSymbol::Militaristic => "MILITARISTIC",
+            FactionTraitSymbol::TechnologicallyAdvanced => "TECHNOLOGICALLY_ADVANCED",
+            FactionTraitSymbol::Aggressive => "AGGRESSIVE",
+            FactionTraitSymbol::Imperialistic => "IMPERIALISTIC",
+            FactionTraitSymbol::TreasureHunters => "TREASURE_HUNTERS",
+            FactionTraitSymbol::Dexterous => "DEXTEROUS",
+            FactionTraitSymbol::Unpredictable => "UNPREDICTABLE",
+            FactionTraitSymbol::Brutal => "BRUTAL",
+            FactionTraitSymbol::Fleeting => "FLEETING",
+            FactionTraitSymbol::Adaptable => "ADAPTABLE",
+            FactionTraitSymbol::SelfSufficient => "SELF_SUFFICIENT",
+            FactionTraitSymbol::Defensive => "DEFENSIVE",
+            FactionTraitSymbol::Proud => "PROUD",
+            FactionTraitSymbol::Diverse => "DIVERSE",
+            FactionTraitSymbol::Independent => "INDEPENDENT",
+            FactionTraitSymbol::SelfInterested => "SELF_INTERESTED",
+            FactionTraitSymbol::Fragmented => "FRAGMENTED",
+            FactionTraitSymbol::Commercial => "COMMERCIAL",
+            FactionTraitSymbol::FreeMarkets => "FREE_MARKETS",
+            FactionTraitSymbol::Entrepreneurial => "ENTREPRENEURIAL",
+            FactionTraitSymbol::Unknown(symbol) => symbol,
+        }
+    }
+}
+impl From<&str> for FactionTraitSymbol {
+    fn from(symbol: &str) -> Self {
+        match symbol {
+            "BUREAUCRATIC" => FactionTraitSymbol::Bureaucratic,
+            "SECRETIVE" => FactionTraitSymbol::Secretive,
+            "CAPITALISTIC" => FactionTraitSymbol::Capitalistic,
+            "INDUSTRIOUS" => FactionTraitSymbol::Industrious,
+            "PEACEFUL" => FactionTraitSymbol::Peaceful,
+            "DISTRUSTFUL" => FactionTraitSymbol::Distrustful,
+            "WELCOMING" => FactionTraitSymbol::Welcoming,
+            "SMUGGLERS" => FactionTraitSymbol::Smugglers,
+            "SCAVENGERS" => FactionTraitSymbol::Scavengers,
+            "REBELLIOUS" => FactionTraitSymbol::Rebellious,
+            "EXILES" => FactionTraitSymbol::Exiles,
+            "PIRATES" => FactionTraitSymbol::Pirates,
+            "RAIDERS" => FactionTraitSymbol::Raiders,
+            "CLAN" => FactionTraitSymbol::Clan,
+            "GUILD" => FactionTraitSymbol::Guild,
+            "DOMINION" => FactionTraitSymbol::Dominion,
+            "FRINGE" => FactionTraitSymbol::Fringe,
+            "FORSAKEN" => FactionTraitSymbol::Forsaken,
+            "ISOLATED" => FactionTraitSymbol::Isolated,
+            "LOCALIZED" => FactionTraitSymbol::Localized,
+            "ESTABLISHED" => FactionTraitSymbol::Established,
+            "NOTABLE" => FactionTraitSymbol::Notable,
+            "DOMINANT" => FactionTraitSymbol::Dominant,
+            "INESCAPABLE" => FactionTraitSymbol::Inescapable,
+            "INNOVATIVE" => FactionTraitSymbol::Innovative,
+            "BOLD" => FactionTraitSymbol::Bold,
+            "VISIONARY" => FactionTraitSymbol::Visionary,
+            "CURIOUS" => FactionTraitSymbol::Curious,
+            "DARING" => FactionTraitSymbol::Daring,
+            "EXPLORATORY" => FactionTraitSymbol::Exploratory,
+            "RESOURCEFUL" => FactionTraitSymbol::Resourceful,
+            "FLEXIBLE" => FactionTraitSymbol::Flexible,
+            "COOPERATIVE" => FactionTraitSymbol::Cooperative,
+            "UNITED" => FactionTraitSymbol::United,
+            "STRATEGIC" => FactionTraitSymbol::Strategic,
+            "INTELLIGENT" => FactionTraitSymbol::Intelligent,
+            "RESEARCH_FOCUSED" => FactionTraitSymbol::ResearchFocused,
+            "COLLABORATIVE" => FactionTraitSymbol::Collaborative,
+            "PROGRESSIVE" => FactionTraitSymbol::Progressive,
+            "MILITARISTIC" => FactionTraitSymbol::Militaristic,
+            "TECHNOLOGICALLY_ADVANCED" => FactionTraitSymbol::TechnologicallyAdvanced,
+            "AGGRESSIVE" => FactionTraitSymbol::Aggressive,
+            "IMPERIALISTIC" => FactionTraitSymbol::Imperialistic,
+            "TREASURE_HUNTERS" => FactionTraitSymbol::TreasureHunters,
+            "DEXTEROUS" => FactionTraitSymbol::Dexterous,
+            "UNPREDICTABLE" => FactionTraitSymbol::Unpredictable,
+            "BRUTAL" => FactionTraitSymbol::Brutal,
+            "FLEETING" => FactionTraitSymbol::Fleeting,
+            "ADAPTABLE" => FactionTraitSymbol::Adaptable,
+            "SELF_SUFFICIENT" => FactionTraitSymbol::SelfSufficient,
+            "DEFENSIVE" => FactionTraitSymbol::Defensive,
+            "PROUD" => FactionTraitSymbol::Proud,
+            "DIVERSE" => FactionTraitSymbol::Diverse,
+            "INDEPENDENT" => FactionTraitSymbol::Independent,
+            "SELF_INTERESTED" => FactionTraitSymbol::SelfInterested,
+            "FRAGMENTED" => FactionTraitSymbol::Fragmented,
+            "COMMERCIAL" => FactionTraitSymbol::Commercial,
+            "FREE_MARKETS" => FactionTraitSymbol::FreeMarkets,
+            "ENTREPRENEURIAL" => FactionTraitSymbol::Entrepreneurial,
+            _ => FactionTraitSymbol::Unknown(symbol.to_string()),
+        }
+    }
+}
+impl std::fmt::Display for FactionTraitSymbol {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+impl Serialize for FactionTraitSymbol {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+impl<'de> Deserialize<'de> for FactionTraitSymbol {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let symbol = Self::from(String::deserialize(deserializer)?.as_str());
+        if let FactionTraitSymbol::Unknown(ref unknown_symbol) = symbol {
+            super::data::record_schema_drift(format!(
+                "unknown FactionTraitSymbol `{unknown_symbol}`"
+            ));
+        }
+        Ok(symbol)
+    }
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct JumpGate {
+    /// All the gates that are connected to this waypoint.
+    pub connections: Vec<String>,
+    pub symbol: WaypointSymbol,
+    #[serde(flatten)]
+    pub extra: UnknownFields,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Market {
+    /// The list of goods that are bought and sold between agents at this market.
+    pub exchange: Vec<TradeGood>,
+    /// The list of goods that are exported from this market.
+    pub exports: Vec<TradeGood>,
+    /// The list of goods that are sought as imports in this market.
+    pub imports: Vec<TradeGood>,
+    /// The symbol of the market. The symbol is the same as the waypoint where the market is located.
+    pub symbol: String,
+    /// The list of goods that are traded at this market. Visible only when a ship is present at the market.
+    pub trade_goods: Option<Vec<MarketTradeGood>>,
+    /// The list of recent transactions at this market. Visible only when a ship is present at the market.
+    pub transactions: Option<Vec<MarketTransaction>>,
+    #[serde(flatten)]
+    pub extra: UnknownFields,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MarketTradeGood {
+    pub activity: Option<ActivityLevel>,
+    /// The price at which this good can be purchased from the market.
+    pub purchase_price: i64,
+    /// The price at which this good can be sold to the market.
+    pub sell_price: i64,
+    pub supply: SupplyLevel,
+    pub symbol: TradeSymbol,
+    /// This is the maximum number of units that can be purchased or sold at this market in a single trade for this good. Trade volume also gives an indication of price volatility. A market with a low trade volume will have large price swings, while high trade volume will be more resilient to price changes.
+    pub trade_volume: i64,
+    /// The type of trade good (export, import, or exchange).
+    pub r#type: MarketTradeGoodType,
+    #[serde(flatten)]
+    pub extra: UnknownFields,
+}
+
+/// The type of trade good (export, import, or exchange).
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+pub enum MarketTradeGoodType {
+    Export,
+    Import,
+    Exchange,
+    /// A symbol this client doesn't know about yet.
+    Unknown(String),
+}
+impl MarketTradeGoodType {
+    /// Symbol the API uses for this value.
+    pub fn as_str(&self) -> &str {
+        match self {
+            MarketTradeGoodType::Export => "EXPORT",
+            MarketTradeGoodType::Import => "IMPORT",
+            MarketTradeGoodType::Exchange => "EXCHANGE",
+            MarketTradeGoodType::Unknown(symbol) => symbol,
+        }
+    }
+}
+impl From<&str> for MarketTradeGoodType {
+    fn from(symbol: &str) -> Self {
+        match symbol {
+            "EXPORT" => MarketTradeGoodType::Export,
+            "IMPORT" => MarketTradeGoodType::Import,
+            "EXCHANGE" => MarketTradeGoodType::Exchange,
+            _ => MarketTradeGoodType::Unknown(symbol.to_string()),
+        }
+    }
+}
+impl std::fmt::Display for MarketTradeGoodType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+impl Serialize for MarketTradeGoodType {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+impl<'de> Deserialize<'de> for MarketTradeGoodType {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let symbol = Self::from(String::deserialize(deserializer)?.as_str());
+        if let MarketTradeGoodType::Unknown(ref unknown_symbol) = symbol {
+            super::data::record_schema_drift(format!(
+                "unknown MarketTradeGoodType `{unknown_symbol}`"
+            ));
+        }
+        Ok(symbol)
+    }
+}
+
+/// Result of a transaction with a market.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MarketTransaction {
+    /// The price per unit of the transaction.
+    pub price_per_unit: i64,
+    /// The symbol of the ship that made the transaction.
+    pub ship_symbol: String,
+    /// The timestamp of the transaction.
+    pub timestamp: DateTime<Utc>,
+    /// The total price of the transaction.
+    pub total_price: i64,
+    /// The symbol of the trade good.
+    pub trade_symbol: String,
+    /// The type of transaction.
+    pub r#type: MarketTransactionType,
+    /// The number of units of the transaction.
+    pub units: i64,
+    pub waypoint_symbol: WaypointSymbol,
+    #[serde(flatten)]
+    pub extra: UnknownFields,
+}
+
+/// The type of transaction.
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+pub enum MarketTransactionType {
+    Purchase,
+    Sell,
+    /// A symbol this client doesn't know about yet.
+    Unknown(String),
+}
+impl MarketTransactionType {
+    /// Symbol the API uses for this value.
+    pub fn as_str(&self) -> &str {
+        match self {
+            MarketTransactionType::Purchase => "PURCHASE",
+            MarketTransactionType::Sell => "SELL",
+            MarketTransactionType::Unknown(symbol) => symbol,
+        }
+    }
+}
+impl From<&str> for MarketTransactionType {
+    fn from(symbol: &str) -> Self {
+        match symbol {
+            "PURCHASE" => MarketTransactionType::Purchase,
+            "SELL" => MarketTransactionType::Sell,
+            _ => MarketTransactionType::Unknown(symbol.to_string()),
+        }
+    }
+}
+impl std::fmt::Display for MarketTransactionType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+impl Serialize for MarketTransactionType {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+impl<'de> Deserialize<'de> for MarketTransactionType {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let symbol = Self::from(String::deserialize(deserializer)?.as_str());
+        if let MarketTransactionType::Unknown(ref unknown_symbol) = symbol {
+            super::data::record_schema_drift(format!(
+                "unknown MarketTransactionType `{unknown_symbol}`"
+            ));
+        }
+        Ok(symbol)
+    }
+}
+
+/// Meta details for pagination.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Meta {
+    /// The amount of items in each page. Limits how many items can be fetched at once.
+    pub limit: i64,
+    /// A page denotes an amount of items, offset from the first item. Each page holds an amount of items equal to the `limit`.
+    pub page: i64,
+    /// Shows the total amount of items of this kind that exist.
+    pub total: i64,
+    #[serde(flatten)]
+    pub extra: UnknownFields,
+}
+
+/// Ship details.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Ship {
+    pub cargo: ShipCargo,
+    pub cooldown: Cooldown,
+    pub crew: ShipCrew,
+    pub engine: ShipEngine,
+    pub frame: ShipFrame,
+    pub fuel: ShipFuel,
+    /// Modules installed in this ship.
+    pub modules: Vec<ShipModule>,
+    /// Mounts installed in this ship.
+    pub mounts: Vec<ShipMount>,
+    pub nav: ShipNav,
+    pub reactor: ShipReactor,
+    pub registration: ShipRegistration,
+    /// The globally unique identifier of the ship in the following format: `[AGENT_SYMBOL]-[HEX_ID]`
+    pub symbol: String,
+    #[serde(flatten)]
+    pub extra: UnknownFields,
+}
+
+/// Ship cargo details.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ShipCargo {
+    /// The max number of items that can be stored in the cargo hold.
+    pub capacity: i64,
+    /// The items currently in the cargo hold.
+    pub inventory: Vec<ShipCargoItem>,
+    /// The number of items currently stored in the cargo hold.
+    pub units: i64,
+    #[serde(flatten)]
+    pub extra: UnknownFields,
+}
+
+/// The type of cargo item and the number of units.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ShipCargoItem {
+    /// The description of the cargo item type.
+    pub description: String,
+    /// The name of the cargo item type.
+    pub name: String,
+    pub symbol: TradeSymbol,
+    /// The number of units of the cargo item.
+    pub units: i64,
+    #[serde(flatten)]
+    pub extra: UnknownFields,
+}
+
+/// The ship's crew service and maintain the ship's systems and equipment.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ShipCrew {
+    /// The maximum number of crew members the ship can support.
+    pub capacity: i64,
+    /// The current number of crew members on the ship.
+    pub current: i64,
+    /// A rough measure of the crew's morale. A higher morale means the crew is happier and more productive. A lower morale means the ship is more prone to accidents.
+    pub morale: i64,
+    /// The minimum number of crew members required to maintain the ship.
+    pub required: i64,
+    /// The rotation of crew shifts. A stricter shift improves the ship's performance. A more relaxed shift improves the crew's morale.
+    pub rotation: ShipCrewRotation,
+    /// The amount of credits per crew member paid per hour. Wages are paid when a ship docks at a civilized waypoint.
+    pub wages: i64,
+    #[serde(flatten)]
+    pub extra: UnknownFields,
+}
+
+/// The rotation of crew shifts. A stricter shift improves the ship's performance. A more relaxed shift improves the crew's morale.
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+pub enum ShipCrewRotation {
+    Strict,
+    Relaxed,
+    /// A symbol this client doesn't know about yet.
+    Unknown(String),
+}
+impl ShipCrewRotation {
+    /// Symbol the API uses for this value.
+    pub fn as_str(&self) -> &str {
+        match self {
+            ShipCrewRotation::Strict => "STRICT",
+            ShipCrewRotation::Relaxed => "RELAXED",
+            ShipCrewRotation::Unknown(symbol) => symbol,
+        }
+    }
+}
+impl From<&str> for ShipCrewRotation {
+    fn from(symbol: &str) -> Self {
+        match symbol {
+            "STRICT" => ShipCrewRotation::Strict,
+            "RELAXED" => ShipCrewRotation::Relaxed,
+            _ => ShipCrewRotation::Unknown(symbol.to_string()),
+        }
+    }
+}
+impl std::fmt::Display for ShipCrewRotation {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+impl Serialize for ShipCrewRotation {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+impl<'de> Deserialize<'de> for ShipCrewRotation {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let symbol = Self::from(String::deserialize(deserializer)?.as_str());
+        if let ShipCrewRotation::Unknown(ref unknown_symbol) = symbol {
+            super::data::record_schema_drift(format!(
+                "unknown ShipCrewRotation `{unknown_symbol}`"
+            ));
+        }
+        Ok(symbol)
+    }
+}
+
+/// The engine determines how quickly a ship travels between waypoints.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ShipEngine {
+    /// The repairable condition of a component. A value of 0 indicates the component needs significant repairs, while a value of 1 indicates the component is in near perfect condition. As the condition of a component is repaired, the overall integrity of the component decreases.
+    pub condition: Option<f64>,
+    /// The description of the engine.
+    pub description: String,
+    /// The overall integrity of the component, which determines the performance of the component. A value of 0 indicates that the component is almost completely degraded, while a value of 1 indicates that the component is in near perfect condition. The integrity of the component is non-repairable, and represents permanent wear over time.
+    pub integrity: Option<f64>,
+    /// The name of the engine.
+    pub name: String,
+    pub requirements: ShipRequirements,
+    /// The speed stat of this engine. The higher the speed, the faster a ship can travel from one point to another. Reduces the time of arrival when navigating the ship.
+    pub speed: i64,
+    /// The symbol of the engine.
+    pub symbol: ShipEngineSymbol,
+    #[serde(flatten)]
+    pub extra: UnknownFields,
+}
+
+/// The symbol of the engine.
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+pub enum ShipEngineSymbol {
+    EngineImpulseDriveI,
+    EngineIonDriveI,
+    EngineIonDriveII,
+    EngineHyperDriveI,
+    /// A symbol this client doesn't know about yet.
+    Unknown(String),
+}
+impl ShipEngineSymbol {
+    /// Symbol the API uses for this value.
+    pub fn as_str(&self) -> &str {
+        match self {
+            ShipEngineSymbol::EngineImpulseDriveI => "ENGINE_IMPULSE_DRIVE_I",
+            ShipEngineSymbol::EngineIonDriveI => "ENGINE_ION_DRIVE_I",
+            ShipEngineSymbol::EngineIonDriveII => "ENGINE_ION_DRIVE_II",
+            ShipEngineSymbol::EngineHyperDriveI => "ENGINE_HYPER_DRIVE_I",
+            ShipEngineSymbol::Unknown(symbol) => symbol,
+        }
+    }
+}
+impl From<&str> for ShipEngineSymbol {
+    fn from(symbol: &str) -> Self {
+        match symbol {
+            "ENGINE_IMPULSE_DRIVE_I" => ShipEngineSymbol::EngineImpulseDriveI,
+            "ENGINE_ION_DRIVE_I" => ShipEngineSymbol::EngineIonDriveI,
+            "ENGINE_ION_DRIVE_II" => ShipEngineSymbol::EngineIonDriveII,
+            "ENGINE_HYPER_DRIVE_I" => ShipEngineSymbol::EngineHyperDriveI,
+            _ => ShipEngineSymbol::Unknown(symbol.to_string()),
+        }
+    }
+}
+impl std::fmt::Display for ShipEngineSymbol {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+impl Serialize for ShipEngineSymbol {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+impl<'de> Deserialize<'de> for ShipEngineSymbol {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let symbol = Self::from(String::deserialize(deserializer)?.as_str());
+        if let ShipEngineSymbol::Unknown(ref unknown_symbol) = symbol {
+            super::data::record_schema_drift(format!(
+                "unknown ShipEngineSymbol `{unknown_symbol}`"
+            ));
+        }
+        Ok(symbol)
+    }
+}
+
+/// The frame of the ship. The frame determines the number of modules and mounting points of the ship, as well as base fuel capacity. As the condition of the frame takes more wear, the ship will become more sluggish and less maneuverable.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ShipFrame {
+    /// The repairable condition of a component. A value of 0 indicates the component needs significant repairs, while a value of 1 indicates the component is in near perfect condition. As the condition of a component is repaired, the overall integrity of the component decreases.
+    pub condition: Option<f64>,
+    /// Description of the frame.
+    pub description: String,
+    /// The maximum amount of fuel that can be stored in this ship. When refueling, the ship will be refueled to this amount.
+    pub fuel_capacity: i64,
+    /// The overall integrity of the component, which determines the performance of the component. A value of 0 indicates that the component is almost completely degraded, while a value of 1 indicates that the component is in near perfect condition. The integrity of the component is non-repairable, and represents permanent wear over time.
+    pub integrity: Option<f64>,
+    /// The amount of slots that can be dedicated to modules installed in the ship. Each installed module take up a number of slots, and once there are no more slots, no new modules can be installed.
+    pub module_slots: i64,
+    /// The amount of slots that can be dedicated to mounts installed in the ship. Each installed mount takes up a number of points, and once there are no more points remaining, no new mounts can be installed.
+    pub mounting_points: i64,
+    /// Name of the frame.
+    pub name: String,
+    pub requirements: ShipRequirements,
+    /// Symbol of the frame.
+    pub symbol: ShipFrameSymbol,
+    #[serde(flatten)]
+    pub extra: UnknownFields,
+}
+
+/// Symbol of the frame.
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+pub enum ShipFrameSymbol {
+    FrameProbe,
+    FrameDrone,
+    FrameInterceptor,
+    FrameRacer,
+    FrameFighter,
+    FrameFrigate,
+    FrameShuttle,
+    FrameExplorer,
+    FrameMiner,
+    FrameLightFreighter,
+    FrameHeavyFreighter,
+    FrameTransport,
+    FrameDestroyer,
+    FrameCruiser,
+    FrameCarrier,
+    /// A symbol this client doesn't know about yet.
+    Unknown(String),
+}
+impl ShipFrameSymbol {
+    /// Symbol the API uses for this value.
+    pub fn as_str(&self) -> &str {
+        match self {
+            ShipFrameSymbol::FrameProbe => "FRAME_PROBE",
+            ShipFrameSymbol::FrameDrone => "FRAME_DRONE",
+            ShipFrameSymbol::FrameInterceptor => "FRAME_INTERCEPTOR",
+            ShipFrameSymbol::FrameRacer => "FRAME_RACER",
+            ShipFrameSymbol::FrameFighter => "FRAME_FIGHTER",
+            ShipFrameSymbol::FrameFrigate => "FRAME_FRIGATE",
+            ShipFrameSymbol::FrameShuttle => "FRAME_SHUTTLE",
+            ShipFrameSymbol::FrameExplorer => "FRAME_EXPLORER",
+            ShipFrameSymbol::FrameMiner => "FRAME_MINER",
+            ShipFrameSymbol::FrameLightFreighter => "FRAME_LIGHT_FREIGHTER",
+            ShipFrameSymbol::FrameHeavyFreighter => "FRAME_HEAVY_FREIGHTER",
+            ShipFrameSymbol::FrameTransport => "FRAME_TRANSPORT",
+            ShipFrameSymbol::FrameDestroyer => "FRAME_DESTROYER",
+            ShipFrameSymbol::FrameCruiser => "FRAME_CRUISER",
+            ShipFrameSymbol::FrameCarrier => "FRAME_CARRIER",
+            ShipFrameSymbol::Unknown(symbol) => symbol,
+        }
+    }
+}
+impl From<&str> for ShipFrameSymbol {
+    fn from(symbol: &str) -> Self {
+        match symbol {
+            "FRAME_PROBE" => ShipFrameSymbol::FrameProbe,
+            "FRAME_DRONE" => ShipFrameSymbol::FrameDrone,
+            "FRAME_INTERCEPTOR" => ShipFrameSymbol::FrameInterceptor,
+            "FRAME_RACER" => ShipFrameSymbol::FrameRacer,
+            "FRAME_FIGHTER" => ShipFrameSymbol::FrameFighter,
+            "FRAME_FRIGATE" => ShipFrameSymbol::FrameFrigate,
+            "FRAME_SHUTTLE" => ShipFrameSymbol::FrameShuttle,
+            "FRAME_EXPLORER" => ShipFrameSymbol::FrameExplorer,
+            "FRAME_MINER" => ShipFrameSymbol::FrameMiner,
+            "FRAME_LIGHT_FREIGHTER" => ShipFrameSymbol::FrameLightFreighter,
+            "FRAME_HEAVY_FREIGHTER" => ShipFrameSymbol::FrameHeavyFreighter,
+            "FRAME_TRANSPORT" => ShipFrameSymbol::FrameTransport,
+            "FRAME_DESTROYER" => ShipFrameSymbol::FrameDestroyer,
+            "FRAME_CRUISER" => ShipFrameSymbol::FrameCruiser,
+            "FRAME_CARRIER" => ShipFrameSymbol::FrameCarrier,
+            _ => ShipFrameSymbol::Unknown(symbol.to_string()),
+        }
+    }
+}
+impl std::fmt::Display for ShipFrameSymbol {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+impl Serialize for ShipFrameSymbol {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+impl<'de> Deserialize<'de> for ShipFrameSymbol {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let symbol = Self::from(String::deserialize(deserializer)?.as_str());
+        if let ShipFrameSymbol::Unknown(ref unknown_symbol) = symbol {
+            super::data::record_schema_drift(format!("unknown ShipFrameSymbol `{unknown_symbol}`"));
+        }
+        Ok(symbol)
+    }
+}
+
+/// Details of the ship's fuel tanks including how much fuel was consumed during the last transit or action.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ShipFuel {
+    /// The maximum amount of fuel the ship's tanks can hold.
+    pub capacity: i64,
+    /// An object that only shows up when an action has consumed fuel in the process. Shows the fuel consumption data.
+    pub consumed: Option<ShipFuelConsumed>,
+    /// The current amount of fuel in the ship's tanks.
+    pub current: i64,
+    #[serde(flatten)]
+    pub extra: UnknownFields,
+}
+
+/// An object that only shows up when an action has consumed fuel in the process. Shows the fuel consumption data.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ShipFuelConsumed {
+    /// The amount of fuel consumed by the most recent transit or action.
+    pub amount: i64,
+    /// The time at which the fuel was consumed.
+    pub timestamp: DateTime<Utc>,
+    #[serde(flatten)]
+    pub extra: UnknownFields,
+}
+
+/// A module can be installed in a ship and provides a set of capabilities such as storage space or quarters for crew. Module installations are permanent.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ShipModule {
+    /// Modules that provide capacity, such as cargo hold or crew quarters will show this value to denote how much of a bonus the module grants.
+    pub capacity: Option<i64>,
+    /// Description of this module.
+    pub description: String,
+    /// Name of this module.
+    pub name: String,
+    /// Modules that have a range will such as a sensor array show this value to denote how far can the module reach with its capabilities.
+    pub range: Option<i64>,
+    pub requirements: ShipRequirements,
+    /// The symbol of the module.
+    pub symbol: ShipModuleSymbol,
+    #[serde(flatten)]
+    pub extra: UnknownFields,
+}
+
+/// The symbol of the module.
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+pub enum ShipModuleSymbol {
+    ModuleMineralProcessorI,
+    ModuleGasProcessorI,
+    ModuleCargoHoldI,
+    ModuleCargoHoldII,
+    ModuleCargoHoldIII,
+    ModuleCrewQuartersI,
+    ModuleEnvoyQuartersI,
+    ModulePassengerCabinI,
+    ModuleMicroRefineryI,
+    ModuleOreRefineryI,
+    ModuleFuelRefineryI,
+    ModuleScienceLabI,
+    ModuleJumpDriveI,
+    ModuleJumpDriveII,
+    ModuleJumpDriveIII,
+    ModuleWarpDriveI,
+    ModuleWarpDriveII,
+    ModuleWarpDriveIII,
+    ModuleShieldGeneratorI,
+    ModuleShieldGeneratorII,
+    /// A symbol this client doesn't know about yet.
+    Unknown(String),
+}
+impl ShipModuleSymbol {
+    /// Symbol the API uses for this value.
+    pub fn as_str(&self) -> &str {
+        match self {
+            ShipModuleSymbol::ModuleMineralProcessorI => "MODULE_MINERAL_PROCESSOR_I",
+            ShipModuleSymbol::ModuleGasProcessorI => "MODULE_GAS_PROCESSOR_I",
+            ShipModuleSymbol::ModuleCargoHoldI => "MODULE_CARGO_HOLD_I",
+            ShipModuleSymbol::ModuleCargoHoldII => "MODULE_CARGO_HOLD_II",
+            ShipModuleSymbol::ModuleCargoHoldIII => "MODULE_CARGO_HOLD_III",
+            ShipModuleSymbol::ModuleCrewQuartersI => "MODULE_CREW_QUARTERS_I",
+            ShipModuleSymbol::ModuleEnvoyQuartersI => "MODULE_ENVOY_QUARTERS_I",
+            ShipModuleSymbol::ModulePassengerCabinI => "MODULE_PASSENGER_CABIN_I",
+            ShipModuleSymbol::ModuleMicroRefineryI => "MODULE_MICRO_REFINERY_I",
+            ShipModuleSymbol::ModuleOreRefineryI => "MODULE_ORE_REFINERY_I",
+            ShipModuleSymbol::ModuleFuelRefineryI => "MODULE_FUEL_REFINERY_I",
+            ShipModuleSymbol::ModuleScienceLabI => "MODULE_SCIENCE_LAB_I",
+            ShipModuleSymbol::ModuleJumpDriveI => "MODULE_JUMP_DRIVE_I",
+            ShipModuleSymbol::ModuleJumpDriveII => "MODULE_JUMP_DRIVE_II",
+            ShipModuleSymbol::ModuleJumpDriveIII => "MODULE_JUMP_DRIVE_III",
+            ShipModuleSymbol::ModuleWarpDriveI => "MODULE_WARP_DRIVE_I",
+            ShipModuleSymbol::ModuleWarpDriveII => "MODULE_WARP_DRIVE_II",
+            ShipModuleSymbol::ModuleWarpDriveIII => "MODULE_WARP_DRIVE_III",
+            ShipModuleSymbol::ModuleShieldGeneratorI => "MODULE_SHIELD_GENERATOR_I",
+            ShipModuleSymbol::ModuleShieldGeneratorII => "MODULE_SHIELD_GENERATOR_II",
+            ShipModuleSymbol::Unknown(symbol) => symbol,
+        }
+    }
+}
+impl From<&str> for ShipModuleSymbol {
+    fn from(symbol: &str) -> Self {
+        match symbol {
+            "MODULE_MINERAL_PROCESSOR_I" => ShipModuleSymbol::ModuleMineralProcessorI,
+            "MODULE_GAS_PROCESSOR_I" => ShipModuleSymbol::ModuleGasProcessorI,
+            "MODULE_CARGO_HOLD_I" => ShipModuleSymbol::ModuleCargoHoldI,
+            "MODULE_CARGO_HOLD_II" => ShipModuleSymbol::ModuleCargoHoldII,
+            "MODULE_CARGO_HOLD_III" => ShipModuleSymbol::ModuleCargoHoldIII,
+            "MODULE_CREW_QUARTERS_I" => ShipModuleSymbol::ModuleCrewQuartersI,
+            "MODULE_ENVOY_QUARTERS_I" => ShipModuleSymbol::ModuleEnvoyQuartersI,
+            "MODULE_PASSENGER_CABIN_I" => ShipModuleSymbol::ModulePassengerCabinI,
+            "MODULE_MICRO_REFINERY_I" => ShipModuleSymbol::ModuleMicroRefineryI,
+            "MODULE_ORE_REFINERY_I" => ShipModuleSymbol::ModuleOreRefineryI,
+            "MODULE_FUEL_REFINERY_I" => ShipModuleSymbol::ModuleFuelRefineryI,
+            "MODULE_SCIENCE_LAB_I" => ShipModuleSymbol::ModuleScienceLabI,
+            "MODULE_JUMP_DRIVE_I" => ShipModuleSymbol::ModuleJumpDriveI,
+            "MODULE_JUMP_DRIVE_II" => ShipModuleSymbol::ModuleJumpDriveII,
+            "MODULE_JUMP_DRIVE_III" => ShipModuleSymbol::ModuleJumpDriveIII,
+            "MODULE_WARP_DRIVE_I" => ShipModuleSymbol::ModuleWarpDriveI,
+            "MODULE_WARP_DRIVE_II" => ShipModuleSymbol::ModuleWarpDriveII,
+            "MODULE_WARP_DRIVE_III" => ShipModuleSymbol::ModuleWarpDriveIII,
+            "MODULE_SHIELD_GENERATOR_I" => ShipModuleSymbol::ModuleShieldGeneratorI,
+            "MODULE_SHIELD_GENERATOR_II" => ShipModuleSymbol::ModuleShieldGeneratorII,
+            _ => ShipModuleSymbol::Unknown(symbol.to_string()),
+        }
+    }
+}
+impl std::fmt::Display for ShipModuleSymbol {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+impl Serialize for ShipModuleSymbol {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+impl<'de> Deserialize<'de> for ShipModuleSymbol {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let symbol = Self::from(String::deserialize(deserializer)?.as_str());
+        if let ShipModuleSymbol::Unknown(ref unknown_symbol) = symbol {
+            super::data::record_schema_drift(format!(
+                "unknown ShipModuleSymbol `{unknown_symbol}`"
+            ));
+        }
+        Ok(symbol)
+    }
+}
+
+/// A mount is installed on the exterier of a ship.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ShipMount {
+    /// Mounts that have this value denote what goods can be produced from using the mount.
+    pub deposits: Option<Vec<ShipMountDeposits>>,
+    /// Description of this mount.
+    pub description: Option<String>,
+    /// Name of this mount.
+    pub name: String,
+    pub requirements: ShipRequirements,
+    /// Mounts that have this value, such as mining lasers, denote how powerful this mount's capabilities are.
+    pub strength: Option<i64>,
+    /// Symbo of this mount.
+    pub symbol: ShipMountSymbol,
+    #[serde(flatten)]
+    pub extra: UnknownFields,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+pub enum ShipMountDeposits {
+    QuartzSand,
+    SiliconCrystals,
+    PreciousStones,
+    IceWater,
+    AmmoniaIce,
+    IronOre,
+    CopperOre,
+    SilverOre,
+    AluminumOre,
+    GoldOre,
+    PlatinumOre,
+    Diamonds,
+    UraniteOre,
+    MeritiumOre,
+    /// A symbol this client doesn't know about yet.
+    Unknown(String),
+}
+impl ShipMountDeposits {
+    /// Symbol the API uses for this value.
+    pub fn as_str(&self) -> &str {
+        match self {
+            ShipMountDeposits::QuartzSand => "QUARTZ_SAND",
+            ShipMountDeposits::SiliconCrystals => "SILICON_CRYSTALS",
+            ShipMountDeposits::PreciousStones => "PRECIOUS_STONES",
+            ShipMountDeposits::IceWater => "ICE_WATER",
+            ShipMountDeposits::AmmoniaIce => "AMMONIA_ICE",
+            ShipMountDeposits::IronOre => "IRON_ORE",
+            ShipMountDeposits::CopperOre => "COPPER_ORE",
+            ShipMountDeposits::SilverOre => "SILVER_ORE",
+            ShipMountDeposits::AluminumOre => "ALUMINUM_ORE",
+            ShipMountDeposits::GoldOre => "GOLD_ORE",
+            ShipMountDeposits::PlatinumOre => "PLATINUM_ORE",
+            ShipMountDeposits::Diamonds => "DIAMONDS",
+            ShipMountDeposits::UraniteOre => "URANITE_ORE",
+            ShipMountDeposits::MeritiumOre => "MERITIUM_ORE",
+            ShipMountDeposits::Unknown(symbol) => symbol,
+        }
+    }
+}
+impl From<&str> for ShipMountDeposits {
+    fn from(symbol: &str) -> Self {
+        match symbol {
+            "QUARTZ_SAND" => ShipMountDeposits::QuartzSand,
+            "SILICON_CRYSTALS" => ShipMountDeposits::SiliconCrystals,
+            "PRECIOUS_STONES" => ShipMountDeposits::PreciousStones,
+            "ICE_WATER" => ShipMountDeposits::IceWater,
+            "AMMONIA_ICE" => ShipMountDeposits::AmmoniaIce,
+            "IRON_ORE" => ShipMountDeposits::IronOre,
+            "COPPER_ORE" => ShipMountDeposits::CopperOre,
+            "SILVER_ORE" => ShipMountDeposits::SilverOre,
+            "ALUMINUM_ORE" => ShipMountDeposits::AluminumOre,
+            "GOLD_ORE" => ShipMountDeposits::GoldOre,
+            "PLATINUM_ORE" => ShipMountDeposits::PlatinumOre,
+            "DIAMONDS" => ShipMountDeposits::Diamonds,
+            "URANITE_ORE" => ShipMountDeposits::UraniteOre,
+            "MERITIUM_ORE" => ShipMountDeposits::MeritiumOre,
+            _ => ShipMountDeposits::Unknown(symbol.to_string()),
+        }
+    }
+}
+impl std::fmt::Display for ShipMountDeposits {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+impl Serialize for ShipMountDeposits {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+impl<'de> Deserialize<'de> for ShipMountDeposits {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let symbol = Self::from(String::deserialize(deserializer)?.as_str());
+        if let ShipMountDeposits::Unknown(ref unknown_symbol) = symbol {
+            super::data::record_schema_drift(format!(
+                "unknown ShipMountDeposits `{unknown_symbol}`"
+            ));
+        }
+        Ok(symbol)
+    }
+}
+
+/// Symbo of this mount.
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+pub enum ShipMountSymbol {
+    MountGasSiphonI,
+    MountGasSiphonII,
+    MountGasSiphonIII,
+    MountSurveyorI,
+    MountSurveyorII,
+    MountSurveyorIII,
+    MountSensorArrayI,
+    MountSensorArrayII,
+    MountSensorArrayIII,
+    MountMiningLaserI,
+    MountMiningLaserII,
+    MountMiningLaserIII,
+    MountLaserCannonI,
+    MountMissileLauncherI,
+    MountTurretI,
+    /// A symbol this client doesn't know about yet.
+    Unknown(String),
+}
+impl ShipMountSymbol {
+    /// Symbol the API uses for this value.
+    pub fn as_str(&self) -> &str {
+        match self {
+            ShipMountSymbol::MountGasSiphonI => "MOUNT_GAS_SIPHON_I",
+            ShipMountSymbol::MountGasSiphonII => "MOUNT_GAS_SIPHON_II",
+            ShipMountSymbol::MountGasSiphonIII => "MOUNT_GAS_SIPHON_III",
+            ShipMountSymbol::MountSurveyorI => "MOUNT_SURVEYOR_I",
+            ShipMountSymbol::MountSurveyorII => "MOUNT_SURVEYOR_II",
+            ShipMountSymbol::MountSurveyorIII => "MOUNT_SURVEYOR_III",
+            ShipMountSymbol::MountSensorArrayI => "MOUNT_SENSOR_ARRAY_I",
+            ShipMountSymbol::MountSensorArrayII => "MOUNT_SENSOR_ARRAY_II",
+            ShipMountSymbol::MountSensorArrayIII => "MOUNT_SENSOR_ARRAY_III",
+            ShipMountSymbol::MountMiningLaserI => "MOUNT_MINING_LASER_I",
+            ShipMountSymbol::MountMiningLaserII => "MOUNT_MINING_LASER_II",
+            ShipMountSymbol::MountMiningLaserIII => "MOUNT_MINING_LASER_III",
+            ShipMountSymbol::MountLaserCannonI => "MOUNT_LASER_CANNON_I",
+            ShipMountSymbol::MountMissileLauncherI => "MOUNT_MISSILE_LAUNCHER_I",
+            ShipMountSymbol::MountTurretI => "MOUNT_TURRET_I",
+            ShipMountSymbol::Unknown(symbol) => symbol,
+        }
+    }
+}
+impl From<&str> for ShipMountSymbol {
+    fn from(symbol: &str) -> Self {
+        match symbol {
+            "MOUNT_GAS_SIPHON_I" => ShipMountSymbol::MountGasSiphonI,
+            "MOUNT_GAS_SIPHON_II" => ShipMountSymbol::MountGasSiphonII,
+            "MOUNT_GAS_SIPHON_III" => ShipMountSymbol::MountGasSiphonIII,
+            "MOUNT_SURVEYOR_I" => ShipMountSymbol::MountSurveyorI,
+            "MOUNT_SURVEYOR_II" => ShipMountSymbol::MountSurveyorII,
+            "MOUNT_SURVEYOR_III" => ShipMountSymbol::MountSurveyorIII,
+            "MOUNT_SENSOR_ARRAY_I" => ShipMountSymbol::MountSensorArrayI,
+            "MOUNT_SENSOR_ARRAY_II" => ShipMountSymbol::MountSensorArrayII,
+            "MOUNT_SENSOR_ARRAY_III" => ShipMountSymbol::MountSensorArrayIII,
+            "MOUNT_MINING_LASER_I" => ShipMountSymbol::MountMiningLaserI,
+            "MOUNT_MINING_LASER_II" => ShipMountSymbol::MountMiningLaserII,
+            "MOUNT_MINING_LASER_III" => ShipMountSymbol::MountMiningLaserIII,
+            "MOUNT_LASER_CANNON_I" => ShipMountSymbol::MountLaserCannonI,
+            "MOUNT_MISSILE_LAUNCHER_I" => ShipMountSymbol::MountMissileLauncherI,
+            "MOUNT_TURRET_I" => ShipMountSymbol::MountTurretI,
+            _ => ShipMountSymbol::Unknown(symbol.to_string()),
+        }
+    }
+}
+impl std::fmt::Display for ShipMountSymbol {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+impl Serialize for ShipMountSymbol {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+impl<'de> Deserialize<'de> for ShipMountSymbol {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let symbol = Self::from(String::deserialize(deserializer)?.as_str());
+        if let ShipMountSymbol::Unknown(ref unknown_symbol) = symbol {
+            super::data::record_schema_drift(format!("unknown ShipMountSymbol `{unknown_symbol}`"));
+        }
+        Ok(symbol)
+    }
+}
+
+/// The navigation information of the ship.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ShipNav {
+    pub flight_mode: ShipNavFlightMode,
+    pub route: ShipNavRoute,
+    pub status: ShipNavStatus,
+    pub system_symbol: SystemSymbol,
+    pub waypoint_symbol: WaypointSymbol,
+    #[serde(flatten)]
+    pub extra: UnknownFields,
+}
+
+/// The ship's set speed when traveling between waypoints or systems.
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+pub enum ShipNavFlightMode {
+    Drift,
+    Stealth,
+    Cruise,
+    Burn,
+    /// A symbol this client doesn't know about yet.
+    Unknown(String),
+}
+impl ShipNavFlightMode {
+    /// Symbol the API uses for this value.
+    pub fn as_str(&self) -> &str {
+        match self {
+            ShipNavFlightMode::Drift => "DRIFT",
+            ShipNavFlightMode::Stealth => "STEALTH",
+            ShipNavFlightMode::Cruise => "CRUISE",
+            ShipNavFlightMode::Burn => "BURN",
+            ShipNavFlightMode::Unknown(symbol) => symbol,
+        }
+    }
+}
+impl From<&str> for ShipNavFlightMode {
+    fn from(symbol: &str) -> Self {
+        match symbol {
+            "DRIFT" => ShipNavFlightMode::Drift,
+            "STEALTH" => ShipNavFlightMode::Stealth,
+            "CRUISE" => ShipNavFlightMode::Cruise,
+            "BURN" => ShipNavFlightMode::Burn,
+            _ => ShipNavFlightMode::Unknown(symbol.to_string()),
+        }
+    }
+}
+impl std::fmt::Display for ShipNavFlightMode {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+impl Serialize for ShipNavFlightMode {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+impl<'de> Deserialize<'de> for ShipNavFlightMode {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let symbol = Self::from(String::deserialize(deserializer)?.as_str());
+        if let ShipNavFlightMode::Unknown(ref unknown_symbol) = symbol {
+            super::data::record_schema_drift(format!(
+                "unknown ShipNavFlightMode `{unknown_symbol}`"
+            ));
+        }
+        Ok(symbol)
+    }
+}
+
+/// The routing information for the ship's most recent transit or current location.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ShipNavRoute {
+    /// The date time of the ship's arrival. If the ship is in-transit, this is the expected time of arrival.
+    pub arrival: DateTime<Utc>,
+    /// Deprecated in favor of origin
+    pub departure: Option<ShipNavRouteWaypoint>,
+    /// The date time of the ship's departure.
+    pub departure_time: DateTime<Utc>,
+    pub destination: ShipNavRouteWaypoint,
+    pub origin: ShipNavRouteWaypoint,
+    #[serde(flatten)]
+    pub extra: UnknownFields,
+}
+
+/// The destination or departure of a ships nav route.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ShipNavRouteWaypoint {
+    /// The symbol of the waypoint.
+    pub symbol: String,
+    pub system_symbol: SystemSymbol,
+    pub r#type: WaypointType,
+    /// Position in the universe in the x axis.
+    pub x: i64,
+    /// Position in the universe in the y axis.
+    pub y: i64,
+    #[serde(flatten)]
+    pub extra: UnknownFields,
+}
+
+/// The current status of the ship
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+pub enum ShipNavStatus {
+    InTransit,
+    InOrbit,
+    Docked,
+    /// A symbol this client doesn't know about yet.
+    Unknown(String),
+}
+impl ShipNavStatus {
+    /// Symbol the API uses for this value.
+    pub fn as_str(&self) -> &str {
+        match self {
+            ShipNavStatus::InTransit => "IN_TRANSIT",
+            ShipNavStatus::InOrbit => "IN_ORBIT",
+            ShipNavStatus::Docked => "DOCKED",
+            ShipNavStatus::Unknown(symbol) => symbol,
+        }
+    }
+}
+impl From<&str> for ShipNavStatus {
+    fn from(symbol: &str) -> Self {
+        match symbol {
+            "IN_TRANSIT" => ShipNavStatus::InTransit,
+            "IN_ORBIT" => ShipNavStatus::InOrbit,
+            "DOCKED" => ShipNavStatus::Docked,
+            _ => ShipNavStatus::Unknown(symbol.to_string()),
+        }
+    }
+}
+impl std::fmt::Display for ShipNavStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+impl Serialize for ShipNavStatus {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+impl<'de> Deserialize<'de> for ShipNavStatus {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let symbol = Self::from(String::deserialize(deserializer)?.as_str());
+        if let ShipNavStatus::Unknown(ref unknown_symbol) = symbol {
+            super::data::record_schema_drift(format!("unknown ShipNavStatus `{unknown_symbol}`"));
+        }
+        Ok(symbol)
+    }
+}
+
+/// The reactor of the ship. The reactor is responsible for powering the ship's systems and weapons.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ShipReactor {
+    /// The repairable condition of a component. A value of 0 indicates the component needs significant repairs, while a value of 1 indicates the component is in near perfect condition. As the condition of a component is repaired, the overall integrity of the component decreases.
+    pub condition: Option<f64>,
+    /// Description of the reactor.
+    pub description: String,
+    /// The overall integrity of the component, which determines the performance of the component. A value of 0 indicates that the component is almost completely degraded, while a value of 1 indicates that the component is in near perfect condition. The integrity of the component is non-repairable, and represents permanent wear over time.
+    pub integrity: Option<f64>,
+    /// Name of the reactor.
+    pub name: String,
+    /// The amount of power provided by this reactor. The more power a reactor provides to the ship, the lower the cooldown it gets when using a module or mount that taxes the ship's power.
+    pub power_output: i64,
+    pub requirements: ShipRequirements,
+    /// Symbol of the reactor.
+    pub symbol: ShipReactorSymbol,
+    #[serde(flatten)]
+    pub extra: UnknownFields,
+}
+
+/// Symbol of the reactor.
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+pub enum ShipReactorSymbol {
+    ReactorSolarI,
+    ReactorFusionI,
+    ReactorFissionI,
+    ReactorChemicalI,
+    ReactorAntimatterI,
+    /// A symbol this client doesn't know about yet.
+    Unknown(String),
+}
+impl ShipReactorSymbol {
+    /// Symbol the API uses for this value.
+    pub fn as_str(&self) -> &str {
+        match self {
+            ShipReactorSymbol::ReactorSolarI => "REACTOR_SOLAR_I",
+            ShipReactorSymbol::ReactorFusionI => "REACTOR_FUSION_I",
+            ShipReactorSymbol::ReactorFissionI => "REACTOR_FISSION_I",
+            ShipReactorSymbol::ReactorChemicalI => "REACTOR_CHEMICAL_I",
+            ShipReactorSymbol::ReactorAntimatterI => "REACTOR_ANTIMATTER_I",
+            ShipReactorSymbol::Unknown(symbol) => symbol,
+        }
+    }
+}
+impl From<&str> for ShipReactorSymbol {
+    fn from(symbol: &str) -> Self {
+        match symbol {
+            "REACTOR_SOLAR_I" => ShipReactorSymbol::ReactorSolarI,
+            "REACTOR_FUSION_I" => ShipReactorSymbol::ReactorFusionI,
+            "REACTOR_FISSION_I" => ShipReactorSymbol::ReactorFissionI,
+            "REACTOR_CHEMICAL_I" => ShipReactorSymbol::ReactorChemicalI,
+            "REACTOR_ANTIMATTER_I" => ShipReactorSymbol::ReactorAntimatterI,
+            _ => ShipReactorSymbol::Unknown(symbol.to_string()),
+        }
+    }
+}
+impl std::fmt::Display for ShipReactorSymbol {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+impl Serialize for ShipReactorSymbol {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+impl<'de> Deserialize<'de> for ShipReactorSymbol {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let symbol = Self::from(String::deserialize(deserializer)?.as_str());
+        if let ShipReactorSymbol::Unknown(ref unknown_symbol) = symbol {
+            super::data::record_schema_drift(format!(
+                "unknown ShipReactorSymbol `{unknown_symbol}`"
+            ));
+        }
+        Ok(symbol)
+    }
+}
+
+/// The public registration information of the ship
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ShipRegistration {
+    /// The symbol of the faction the ship is registered with
+    pub faction_symbol: String,
+    /// The agent's registered name of the ship
+    pub name: String,
+    pub role: ShipRole,
+    #[serde(flatten)]
+    pub extra: UnknownFields,
+}
+
+/// The requirements for installation on a ship
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ShipRequirements {
+    /// The number of crew required for operation.
+    pub crew: Option<i64>,
+    /// The amount of power required from the reactor.
+    pub power: Option<i64>,
+    /// The number of module slots required for installation.
+    pub slots: Option<i64>,
+    #[serde(flatten)]
+    pub extra: UnknownFields,
+}
+
+/// The registered role of the ship
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+pub enum ShipRole {
+    Fabricator,
+    Harvester,
+    Hauler,
+    Interceptor,
+    Excavator,
+    Transport,
+    Repair,
+    Surveyor,
+    Command,
+    Carrier,
+    Patrol,
+    Satellite,
+    Explorer,
+    Refinery,
+    /// A symbol this client doesn't know about yet.
+    Unknown(String),
+}
+impl ShipRole {
+    /// Symbol the API uses for this value.
+    pub fn as_str(&self) -> &str {
+        match self {
+            ShipRole::Fabricator => "FABRICATOR",
+            ShipRole::Harvester => "HARVESTER",
+            ShipRole::Hauler => "HAULER",
+            ShipRole::Interceptor => "INTERCEPTOR",
+            ShipRole::Excavator => "EXCAVATOR",
+            ShipRole::Transport => "TRANSPORT",
+            ShipRole::Repair => "REPAIR",
+            ShipRole::Surveyor => "SURVEYOR",
+            ShipRole::Command => "COMMAND",
+            ShipRole::Carrier => "CARRIER",
+            ShipRole::Patrol => "PATROL",
+            ShipRole::Satellite => "SATELLITE",
+            ShipRole::Explorer => "EXPLORER",
+            ShipRole::Refinery => "REFINERY",
+            ShipRole::Unknown(symbol) => symbol,
+        }
+    }
+}
+impl From<&str> for ShipRole {
+    fn from(symbol: &str) -> Self {
+        match symbol {
+            "FABRICATOR" => ShipRole::Fabricator,
+            "HARVESTER" => ShipRole::Harvester,
+            "HAULER" => ShipRole::Hauler,
+            "INTERCEPTOR" => ShipRole::Interceptor,
+            "EXCAVATOR" => ShipRole::Excavator,
+            "TRANSPORT" => ShipRole::Transport,
+            "REPAIR" => ShipRole::Repair,
+            "SURVEYOR" => ShipRole::Surveyor,
+            "COMMAND" => ShipRole::Command,
+            "CARRIER" => ShipRole::Carrier,
+            "PATROL" => ShipRole::Patrol,
+            "SATELLITE" => ShipRole::Satellite,
+            "EXPLORER" => ShipRole::Explorer,
+            "REFINERY" => ShipRole::Refinery,
+            _ => ShipRole::Unknown(symbol.to_string()),
+        }
+    }
+}
+impl std::fmt::Display for ShipRole {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+impl Serialize for ShipRole {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+impl<'de> Deserialize<'de> for ShipRole {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let symbol = Self::from(String::deserialize(deserializer)?.as_str());
+        if let ShipRole::Unknown(ref unknown_symbol) = symbol {
+            super::data::record_schema_drift(format!("unknown ShipRole `{unknown_symbol}`"));
+        }
+        Ok(symbol)
+    }
+}
+
+/// Type of ship
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+pub enum ShipType {
+    ShipProbe,
+    ShipMiningDrone,
+    ShipSiphonDrone,
+    ShipInterceptor,
+    ShipLightHauler,
+    ShipCommandFrigate,
+    ShipExplorer,
+    ShipHeavyFreighter,
+    ShipLightShuttle,
+    ShipOreHound,
+    ShipRefiningFreighter,
+    ShipSurveyor,
+    /// A symbol this client doesn't know about yet.
+    Unknown(String),
+}
+impl ShipType {
+    /// Symbol the API uses for this value.
+    pub fn as_str(&self) -> &str {
+        match self {
+            ShipType::ShipProbe => "SHIP_PROBE",
+            ShipType::ShipMiningDrone => "SHIP_MINING_DRONE",
+            ShipType::ShipSiphonDrone => "SHIP_SIPHON_DRONE",
+            ShipType::ShipInterceptor => "SHIP_INTERCEPTOR",
+            ShipType::ShipLightHauler => "SHIP_LIGHT_HAULER",
+            ShipType::ShipCommandFrigate => "SHIP_COMMAND_FRIGATE",
+            ShipType::ShipExplorer => "SHIP_EXPLORER",
+            ShipType::ShipHeavyFreighter => "SHIP_HEAVY_FREIGHTER",
+            ShipType::ShipLightShuttle => "SHIP_LIGHT_SHUTTLE",
+            ShipType::ShipOreHound => "SHIP_ORE_HOUND",
+            ShipType::ShipRefiningFreighter => "SHIP_REFINING_FREIGHTER",
+            ShipType::ShipSurveyor => "SHIP_SURVEYOR",
+            ShipType::Unknown(symbol) => symbol,
+        }
+    }
+}
+impl From<&str> for ShipType {
+    fn from(symbol: &str) -> Self {
+        match symbol {
+            "SHIP_PROBE" => ShipType::ShipProbe,
+            "SHIP_MINING_DRONE" => ShipType::ShipMiningDrone,
+            "SHIP_SIPHON_DRONE" => ShipType::ShipSiphonDrone,
+            "SHIP_INTERCEPTOR" => ShipType::ShipInterceptor,
+            "SHIP_LIGHT_HAULER" => ShipType::ShipLightHauler,
+            "SHIP_COMMAND_FRIGATE" => ShipType::ShipCommandFrigate,
+            "SHIP_EXPLORER" => ShipType::ShipExplorer,
+            "SHIP_HEAVY_FREIGHTER" => ShipType::ShipHeavyFreighter,
+            "SHIP_LIGHT_SHUTTLE" => ShipType::ShipLightShuttle,
+            "SHIP_ORE_HOUND" => ShipType::ShipOreHound,
+            "SHIP_REFINING_FREIGHTER" => ShipType::ShipRefiningFreighter,
+            "SHIP_SURVEYOR" => ShipType::ShipSurveyor,
+            _ => ShipType::Unknown(symbol.to_string()),
+        }
+    }
+}
+impl std::fmt::Display for ShipType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+impl Serialize for ShipType {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+impl<'de> Deserialize<'de> for ShipType {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let symbol = Self::from(String::deserialize(deserializer)?.as_str());
+        if let ShipType::Unknown(ref unknown_symbol) = symbol {
+            super::data::record_schema_drift(format!("unknown ShipType `{unknown_symbol}`"));
+        }
+        Ok(symbol)
+    }
+}
+
+/// The supply level of a trade good.
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+pub enum SupplyLevel {
+    Scarce,
+    Limited,
+    Moderate,
+    High,
+    Abundant,
+    /// A symbol this client doesn't know about yet.
+    Unknown(String),
+}
+impl SupplyLevel {
+    /// Symbol the API uses for this value.
+    pub fn as_str(&self) -> &str {
+        match self {
+            SupplyLevel::Scarce => "SCARCE",
+            SupplyLevel::Limited => "LIMITED",
+            SupplyLevel::Moderate => "MODERATE",
+            SupplyLevel::High => "HIGH",
+            SupplyLevel::Abundant => "ABUNDANT",
+            SupplyLevel::Unknown(symbol) => symbol,
+        }
+    }
+}
+impl From<&str> for SupplyLevel {
+    fn from(symbol: &str) -> Self {
+        match symbol {
+            "SCARCE" => SupplyLevel::Scarce,
+            "LIMITED" => SupplyLevel::Limited,
+            "MODERATE" => SupplyLevel::Moderate,
+            "HIGH" => SupplyLevel::High,
+            "ABUNDANT" => SupplyLevel::Abundant,
+            _ => SupplyLevel::Unknown(symbol.to_string()),
+        }
+    }
+}
+impl std::fmt::Display for SupplyLevel {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+impl Serialize for SupplyLevel {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+impl<'de> Deserialize<'de> for SupplyLevel {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let symbol = Self::from(String::deserialize(deserializer)?.as_str());
+        if let SupplyLevel::Unknown(ref unknown_symbol) = symbol {
+            super::data::record_schema_drift(format!("unknown SupplyLevel `{unknown_symbol}`"));
+        }
+        Ok(symbol)
+    }
+}
+
+/// A resource survey of a waypoint, detailing a specific extraction location and the types of resources that can be found there.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Survey {
+    /// A list of deposits that can be found at this location. A ship will extract one of these deposits when using this survey in an extraction request. If multiple deposits of the same type are present, the chance of extracting that deposit is increased.
+    pub deposits: Vec<SurveyDeposit>,
+    /// The date and time when the survey expires. After this date and time, the survey will no longer be available for extraction.
+    pub expiration: DateTime<Utc>,
+    /// A unique signature for the location of this survey. This signature is verified when attempting an extraction using this survey.
+    pub signature: String,
+    /// The size of the deposit. This value indicates how much can be extracted from the survey before it is exhausted.
+    pub size: SurveySize,
+    /// The symbol of the waypoint that this survey is for.
+    pub symbol: String,
+    #[serde(flatten)]
+    pub extra: UnknownFields,
+}
+
+/// A surveyed deposit of a mineral or resource available for extraction.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SurveyDeposit {
+    /// The symbol of the deposit.
+    pub symbol: String,
+    #[serde(flatten)]
+    pub extra: UnknownFields,
+}
+
+/// The size of the deposit. This value indicates how much can be extracted from the survey before it is exhausted.
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+pub enum SurveySize {
+    Small,
+    Moderate,
+    Large,
+    /// A symbol this client doesn't know about yet.
+    Unknown(String),
+}
+impl SurveySize {
+    /// Symbol the API uses for this value.
+    pub fn as_str(&self) -> &str {
+        match self {
+            SurveySize::Small => "SMALL",
+            SurveySize::Moderate => "MODERATE",
+            SurveySize::Large => "LARGE",
+            SurveySize::Unknown(symbol) => symbol,
+        }
+    }
+}
+impl From<&str> for SurveySize {
+    fn from(symbol: &str) -> Self {
+        match symbol {
+            "SMALL" => SurveySize::Small,
+            "MODERATE" => SurveySize::Moderate,
+            "LARGE" => SurveySize::Large,
+            _ => SurveySize::Unknown(symbol.to_string()),
+        }
+    }
+}
+impl std::fmt::Display for SurveySize {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+impl Serialize for SurveySize {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+impl<'de> Deserialize<'de> for SurveySize {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let symbol = Self::from(String::deserialize(deserializer)?.as_str());
+        if let SurveySize::Unknown(ref unknown_symbol) = symbol {
+            super::data::record_schema_drift(format!("unknown SurveySize `{unknown_symbol}`"));
+        }
+        Ok(symbol)
+    }
+}
+
+/// The symbol of the system.
+pub type SystemSymbol = String;
+
+/// A good that can be traded for other goods or currency.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TradeGood {
+    /// The description of the good.
+    pub description: String,
+    /// The name of the good.
+    pub name: String,
+    pub symbol: TradeSymbol,
+    #[serde(flatten)]
+    pub extra: UnknownFields,
+}
+
+/// The good's symbol.
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+pub enum TradeSymbol {
+    PreciousStones,
+    QuartzSand,
+    SiliconCrystals,
+    AmmoniaIce,
+    LiquidHydrogen,
+    LiquidNitrogen,
+    IceWater,
+    ExoticMatter,
+    AdvancedCircuitry,
+    GravitonEmitters,
+    Iron,
+    IronOre,
+    Copper,
+    CopperOre,
+    Aluminum,
+    AluminumOre,
+    Silver,
+    SilverOre,
+    Gold,
+    GoldOre,
+    Platinum,
+    PlatinumOre,
+    Diamonds,
+    Uranite,
+    UraniteOre,
+    Meritium,
+    MeritiumOre,
+    Hydrocarbon,
+    Antimatter,
+    FabMats,
+    Fertilizers,
+    Fabrics,
+    Food,
+    Jewelry,
+    Machinery,
+    Firearms,
+    AssaultRifles,
+    MilitaryEquipment,
+    Explosives,
+    LabInstruments,
+    Ammunition,
+    Electronics,
+    ShipPlating,
+    ShipParts,
+    Equipment,
+    Fuel,
+    Medicine,
+    Drugs,
+    Clothing,
+    Microprocessors,
+    Plastics,
+    Polynucleotides,
+    Biocomposites,
+    QuantumStabilizers,
+    Nanobots,
+    AiMainframes,
+    QuantumDrives,
+    RoboticDrones,
+    CyberImplants,
+    GeneTherapeutics,
+    NeuralChips,
+    MoodRegulators,
+    ViralAgents,
+    MicroFusionGenerators,
+    Supergrains,
+    LaserRifles,
+    Holographics,
+    ShipSalvage,
+    RelicTech,
+    NovelLifeforms,
+    BotanicalSpecimens,
+    CulturalArtifacts,
+    /// A symbol this client doesn't know about yet.
+    Unknown(String),
+}
+impl TradeSymbol {
+    /// Symbol the API uses for this value.
+    pub fn as_str(&self) -> &str {
+        match self {
+            TradeSymbol::PreciousStones => "PRECIOUS_STONES",
+            TradeSymbol::QuartzSand => "QUARTZ_SAND",
+            TradeSymbol::SiliconCrystals => "SILICON_CRYSTALS",
+            TradeSymbol::AmmoniaIce => "AMMONIA_ICE",
+            TradeSymbol::LiquidHydrogen => "LIQUID_HYDROGEN",
+            TradeSymbol::LiquidNitrogen => "LIQUID_NITROGEN",
+            TradeSymbol::IceWater => "ICE_WATER",
+            TradeSymbol::ExoticMatter => "EXOTIC_MATTER",
+            TradeSymbol::AdvancedCircuitry => "ADVANCED_CIRCUITRY",
+            TradeSymbol::GravitonEmitters => "GRAVITON_EMITTERS",
+            TradeSymbol::Iron => "IRON",
+            TradeSymbol::IronOre => "IRON_ORE",
+            TradeSymbol::Copper => "COPPER",
+            TradeSymbol::CopperOre => "COPPER_ORE",
+            TradeSymbol::Aluminum => "ALUMINUM",
+            TradeSymbol::AluminumOre => "ALUMINUM_ORE",
+            TradeSymbol::Silver => "SILVER",
+            TradeSymbol::SilverOre => "SILVER_ORE",
+            TradeSymbol::Gold => "GOLD",
+            TradeSymbol::GoldOre => "GOLD_ORE",
+            TradeSymbol::Platinum => "PLATINUM",
+            TradeSymbol::PlatinumOre => "PLATINUM_ORE",
+            TradeSymbol::Diamonds => "DIAMONDS",
+            TradeSymbol::Uranite => "URANITE",
+            TradeSymbol::UraniteOre => "URANITE_ORE",
+            TradeSymbol::Meritium => "MERITIUM",
+            TradeSymbol::MeritiumOre => "MERITIUM_ORE",
+            TradeSymbol::Hydrocarbon => "HYDROCARBON",
+            TradeSymbol::Antimatter => "ANTIMATTER",
+            TradeSymbol::FabMats => "FAB_MATS",
+            TradeSymbol::Fertilizers => "FERTILIZERS",
+            TradeSymbol::Fabrics => "FABRICS",
+            TradeSymbol::Food => "FOOD",
+            TradeSymbol::Jewelry => "JEWELRY",
+            TradeSymbol::Machinery => "MACHINERY",
+            TradeSymbol::Firearms => "FIREARMS",
+            TradeSymbol::AssaultRifles => "ASSAULT_RIFLES",
+            TradeSymbol::MilitaryEquipment => "MILITARY_EQUIPMENT",
+            TradeSymbol::Explosives => "EXPLOSIVES",
+            TradeSymbol::LabInstruments => "LAB_INSTRUMENTS",
+            TradeSymbol::Ammunition => "AMMUNITION",
+            TradeSymbol::Electronics => "ELECTRONICS",
+            TradeSymbol::ShipPlating => "SHIP_PLATING",
+            TradeSymbol::ShipParts => "SHIP_PARTS",
+            TradeSymbol::Equipment => "EQUIPMENT",
+            TradeSymbol::Fuel => "FUEL",
+            TradeSymbol::Medicine => "MEDICINE",
+            TradeSymbol::Drugs => "DRUGS",
+            TradeSymbol::Clothing => "CLOTHING",
+            TradeSymbol::Microprocessors => "MICROPROCESSORS",
+            TradeSymbol::Plastics => "PLASTICS",
+            TradeSymbol::Polynucleotides => "POLYNUCLEOTIDES",
+            TradeSymbol::Biocomposites => "BIOCOMPOSITES",
+            TradeSymbol::QuantumStabilizers => "QUANTUM_STABILIZERS",
+            TradeSymbol::Nanobots => "NANOBOTS",
+            TradeSymbol::AiMainframes => "AI_MAINFRAMES",
+            TradeSymbol::QuantumDrives => "QUANTUM_DRIVES",
+            TradeSymbol::RoboticDrones => "ROBOTIC_DRONES",
+            TradeSymbol::CyberImplants => "CYBER_IMPLANTS",
+            TradeSymbol::GeneTherapeutics => "GENE_THERAPEUTICS",
+            TradeSymbol::NeuralChips => "NEURAL_CHIPS",
+            TradeSymbol::MoodRegulators => "MOOD_REGULATORS",
+            TradeSymbol::ViralAgents => "VIRAL_AGENTS",
+            TradeSymbol::MicroFusionGenerators => "MICRO_FUSION_GENERATORS",
+            TradeSymbol::Supergrains => "SUPERGRAINS",
+            TradeSymbol::LaserRifles => "LASER_RIFLES",
+            TradeSymbol::Holographics => "HOLOGRAPHICS",
+            TradeSymbol::ShipSalvage => "SHIP_SALVAGE",
+            TradeSymbol::RelicTech => "RELIC_TECH",
+            TradeSymbol::NovelLifeforms => "NOVEL_LIFEFORMS",
+            TradeSymbol::BotanicalSpecimens => "BOTANICAL_SPECIMENS",
+            TradeSymbol::CulturalArtifacts => "CULTURAL_ARTIFACTS",
+            TradeSymbol::Unknown(symbol) => symbol,
+        }
+    }
+}
+impl From<&str> for TradeSymbol {
+    fn from(symbol: &str) -> Self {
+        match symbol {
+            "PRECIOUS_STONES" => TradeSymbol::PreciousStones,
+            "QUARTZ_SAND" => TradeSymbol::QuartzSand,
+            "SILICON_CRYSTALS" => TradeSymbol::SiliconCrystals,
+            "AMMONIA_ICE" => TradeSymbol::AmmoniaIce,
+            "LIQUID_HYDROGEN" => TradeSymbol::LiquidHydrogen,
+            "LIQUID_NITROGEN" => TradeSymbol::LiquidNitrogen,
+            "ICE_WATER" => TradeSymbol::IceWater,
+            "EXOTIC_MATTER" => TradeSymbol::ExoticMatter,
+            "ADVANCED_CIRCUITRY" => TradeSymbol::AdvancedCircuitry,
+            "GRAVITON_EMITTERS" => TradeSymbol::GravitonEmitters,
+            "IRON" => TradeSymbol::Iron,
+            "IRON_ORE" => TradeSymbol::IronOre,
+            "COPPER" => TradeSymbol::Copper,
+            "COPPER_ORE" => TradeSymbol::CopperOre,
+            "ALUMINUM" => TradeSymbol::Aluminum,
+            "ALUMINUM_ORE" => TradeSymbol::AluminumOre,
+            "SILVER" => TradeSymbol::Silver,
+            "SILVER_ORE" => TradeSymbol::SilverOre,
+            "GOLD" => TradeSymbol::Gold,
+            "GOLD_ORE" => TradeSymbol::GoldOre,
+            "PLATINUM" => TradeSymbol::Platinum,
+            "PLATINUM_ORE" => TradeSymbol::PlatinumOre,
+            "DIAMONDS" => TradeSymbol::Diamonds,
+            "URANITE" => TradeSymbol::Uranite,
+            "URANITE_ORE" => TradeSymbol::UraniteOre,
+            "MERITIUM" => TradeSymbol::Meritium,
+            "MERITIUM_ORE" => TradeSymbol::MeritiumOre,
+            "HYDROCARBON" => TradeSymbol::Hydrocarbon,
+            "ANTIMATTER" => TradeSymbol::Antimatter,
+            "FAB_MATS" => TradeSymbol::FabMats,
+            "FERTILIZERS" => TradeSymbol::Fertilizers,
+            "FABRICS" => TradeSymbol::Fabrics,
+            "FOOD" => TradeSymbol::Food,
+            "JEWELRY" => TradeSymbol::Jewelry,
+            "MACHINERY" => TradeSymbol::Machinery,
+            "FIREARMS" => TradeSymbol::Firearms,
+            "ASSAULT_RIFLES" => TradeSymbol::AssaultRifles,
+            "MILITARY_EQUIPMENT" => TradeSymbol::MilitaryEquipment,
+            "EXPLOSIVES" => TradeSymbol::Explosives,
+            "LAB_INSTRUMENTS" => TradeSymbol::LabInstruments,
+            "AMMUNITION" => TradeSymbol::Ammunition,
+            "ELECTRONICS" => TradeSymbol::Electronics,
+            "SHIP_PLATING" => TradeSymbol::ShipPlating,
+            "SHIP_PARTS" => TradeSymbol::ShipParts,
+            "EQUIPMENT" => TradeSymbol::Equipment,
+            "FUEL" => TradeSymbol::Fuel,
+            "MEDICINE" => TradeSymbol::Medicine,
+            "DRUGS" => TradeSymbol::Drugs,
+            "CLOTHING" => TradeSymbol::Clothing,
+            "MICROPROCESSORS" => TradeSymbol::Microprocessors,
+            "PLASTICS" => TradeSymbol::Plastics,
+            "POLYNUCLEOTIDES" => TradeSymbol::Polynucleotides,
+            "BIOCOMPOSITES" => TradeSymbol::Biocomposites,
+            "QUANTUM_STABILIZERS" => TradeSymbol::QuantumStabilizers,
+            "NANOBOTS" => TradeSymbol::Nanobots,
+            "AI_MAINFRAMES" => TradeSymbol::AiMainframes,
+            "QUANTUM_DRIVES" => TradeSymbol::QuantumDrives,
+            "ROBOTIC_DRONES" => TradeSymbol::RoboticDrones,
+            "CYBER_IMPLANTS" => TradeSymbol::CyberImplants,
+            "GENE_THERAPEUTICS" => TradeSymbol::GeneTherapeutics,
+            "NEURAL_CHIPS" => TradeSymbol::NeuralChips,
+            "MOOD_REGULATORS" => TradeSymbol::MoodRegulators,
+            "VIRAL_AGENTS" => TradeSymbol::ViralAgents,
+            "MICRO_FUSION_GENERATORS" => TradeSymbol::MicroFusionGenerators,
+            "SUPERGRAINS" => TradeSymbol::Supergrains,
+            "LASER_RIFLES" => TradeSymbol::LaserRifles,
+            "HOLOGRAPHICS" => TradeSymbol::Holographics,
+            "SHIP_SALVAGE" => TradeSymbol::ShipSalvage,
+            "RELIC_TECH" => TradeSymbol::RelicTech,
+            "NOVEL_LIFEFORMS" => TradeSymbol::NovelLifeforms,
+            "BOTANICAL_SPECIMENS" => TradeSymbol::BotanicalSpecimens,
+            "CULTURAL_ARTIFACTS" => TradeSymbol::CulturalArtifacts,
+            _ => TradeSymbol::Unknown(symbol.to_string()),
+        }
+    }
+}
+impl std::fmt::Display for TradeSymbol {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+impl Serialize for TradeSymbol {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+impl<'de> Deserialize<'de> for TradeSymbol {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let symbol = Self::from(String::deserialize(deserializer)?.as_str());
+        if let TradeSymbol::Unknown(ref unknown_symbol) = symbol {
+            super::data::record_schema_drift(format!("unknown TradeSymbol `{unknown_symbol}`"));
+        }
+        Ok(symbol)
+    }
+}
+
+/// A waypoint is a location that ships can travel to such as a Planet, Moon or Space Station.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Waypoint {
+    pub chart: Option<Chart>,
+    pub faction: Option<WaypointFaction>,
+    /// True if the waypoint is under construction.
+    pub is_under_construction: bool,
+    /// The modifiers of the waypoint.
+    pub modifiers: Option<Vec<WaypointModifier>>,
+    /// Waypoints that orbit this waypoint.
+    pub orbitals: Vec<WaypointOrbital>,
+    /// The symbol of the parent waypoint, if this waypoint is in orbit around another waypoint. Otherwise this value is undefined.
+    pub orbits: Option<String>,
+    pub symbol: WaypointSymbol,
+    pub system_symbol: SystemSymbol,
+    /// The traits of the waypoint.
+    pub traits: Vec<WaypointTrait>,
+    pub r#type: WaypointType,
+    /// Relative position of the waypoint on the system's x axis. This is not an absolute position in the universe.
+    pub x: i64,
+    /// Relative position of the waypoint on the system's y axis. This is not an absolute position in the universe.
+    pub y: i64,
+    #[serde(flatten)]
+    pub extra: UnknownFields,
+}
+
+/// The faction that controls the waypoint.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WaypointFaction {
+    pub symbol: FactionSymbol,
+    #[serde(flatten)]
+    pub extra: UnknownFields,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WaypointModifier {
+    /// A description of the trait.
+    pub description: String,
+    /// The name of the trait.
+    pub name: String,
+    pub symbol: WaypointModifierSymbol,
+    #[serde(flatten)]
+    pub extra: UnknownFields,
+}
+
+/// The unique identifier of the modifier.
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+pub enum WaypointModifierSymbol {
+    Stripped,
+    Unstable,
+    RadiationLeak,
+    CriticalLimit,
+    CivilUnrest,
+    /// A symbol this client doesn't know about yet.
+    Unknown(String),
+}
+impl WaypointModifierSymbol {
+    /// Symbol the API uses for this value.
+    pub fn as_str(&self) -> &str {
+        match self {
+            WaypointModifierSymbol::Stripped => "STRIPPED",
+            WaypointModifierSymbol::Unstable => "UNSTABLE",
+            WaypointModifierSymbol::RadiationLeak => "RADIATION_LEAK",
+            WaypointModifierSymbol::CriticalLimit => "CRITICAL_LIMIT",
+            WaypointModifierSymbol::CivilUnrest => "CIVIL_UNREST",
+            WaypointModifierSymbol::Unknown(symbol) => symbol,
+        }
+    }
+}
+impl From<&str> for WaypointModifierSymbol {
+    fn from(symbol: &str) -> Self {
+        match symbol {
+            "STRIPPED" => WaypointModifierSymbol::Stripped,
+            "UNSTABLE" => WaypointModifierSymbol::Unstable,
+            "RADIATION_LEAK" => WaypointModifierSymbol::RadiationLeak,
+            "CRITICAL_LIMIT" => WaypointModifierSymbol::CriticalLimit,
+            "CIVIL_UNREST" => WaypointModifierSymbol::CivilUnrest,
+            _ => WaypointModifierSymbol::Unknown(symbol.to_string()),
+        }
+    }
+}
+impl std::fmt::Display for WaypointModifierSymbol {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+impl Serialize for WaypointModifierSymbol {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+impl<'de> Deserialize<'de> for WaypointModifierSymbol {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let symbol = Self::from(String::deserialize(deserializer)?.as_str());
+        if let WaypointModifierSymbol::Unknown(ref unknown_symbol) = symbol {
+            super::data::record_schema_drift(format!(
+                "unknown WaypointModifierSymbol `{unknown_symbol}`"
+            ));
+        }
+        Ok(symbol)
+    }
+}
+
+/// An orbital is another waypoint that orbits a parent waypoint.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WaypointOrbital {
+    /// The symbol of the orbiting waypoint.
+    pub symbol: String,
+    #[serde(flatten)]
+    pub extra: UnknownFields,
+}
+
+/// The symbol of the waypoint.
+pub type WaypointSymbol = String;
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WaypointTrait {
+    /// A description of the trait.
+    pub description: String,
+    /// The name of the trait.
+    pub name: String,
+    pub symbol: WaypointTraitSymbol,
+    #[serde(flatten)]
+    pub extra: UnknownFields,
+}
+
+/// The unique identifier of the trait.
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+pub enum WaypointTraitSymbol {
+    Uncharted,
+    UnderConstruction,
+    Marketplace,
+    Shipyard,
+    Outpost,
+    ScatteredSettlements,
+    SprawlingCities,
+    MegaStructures,
+    PirateBase,
+    Overcrowded,
+    HighTech,
+    Corrupt,
+    Bureaucratic,
+    TradingHub,
+    Industrial,
+    BlackMarket,
+    ResearchFacility,
+    MilitaryBase,
+    SurveillanceOutpost,
+    ExplorationOutpost,
+    MineralDeposits,
+    CommonMetalDeposits,
+    PreciousMetalDeposits,
+    RareMetalDeposits,
+    MethanePools,
+    IceCrystals,
+    ExplosiveGases,
+    StrongMagnetosphere,
+    VibrantAuroras,
+    SaltFlats,
+    Canyons,
+    PerpetualDaylight,
+    PerpetualOvercast,
+    DrySeabeds,
+    MagmaSeas,
+    Supervolcanoes,
+    AshClouds,
+    VastRuins,
+    MutatedFlora,
+    Terraformed,
+    ExtremeTemperatures,
+    ExtremePressure,
+    DiverseLife,
+    ScarceLife,
+    Fossils,
+    WeakGravity,
+    StrongGravity,
+    CrushingGravity,
+    ToxicAtmosphere,
+    CorrosiveAtmosphere,
+    BreathableAtmosphere,
+    ThinAtmosphere,
+    Jovian,
+    Rocky,
+    Volcanic,
+    Frozen,
+    Swamp,
+    Barren,
+    Temperate,
+    Jungle,
+    Ocean,
+    Radioactive,
+    MicroGravityAnomalies,
+    DebrisCluster,
+    DeepCraters,
+    ShallowCraters,
+    UnstableComposition,
+    HollowedInterior,
+    Stripped,
+    /// A symbol this client doesn't know about yet.
+    Unknown(String),
+}
+impl WaypointTraitSymbol {
+    /// Symbol the API uses for this value.
+    pub fn as_str(&self) -> &str {
+        match self {
+            WaypointTraitSymbol::Uncharted => "UNCHARTED",
+            WaypointTraitSymbol::UnderConstruction => "UNDER_CONSTRUCTION",
+            WaypointTraitSymbol::Marketplace => "MARKETPLACE",
+            WaypointTraitSymbol::Shipyard => "SHIPYARD",
+            WaypointTraitSymbol::Outpost => "OUTPOST",
+            WaypointTraitSymbol::ScatteredSettlements => "SCATTERED_SETTLEMENTS",
+            WaypointTraitSymbol::SprawlingCities => "SPRAWLING_CITIES",
+            WaypointTraitSymbol::MegaStructures => "MEGA_STRUCTURES",
+            WaypointTraitSymbol::PirateBase => "PIRATE_BASE",
+            WaypointTraitSymbol::Overcrowded => "OVERCROWDED",
+            WaypointTraitSymbol::HighTech => "HIGH_TECH",
+            WaypointTraitSymbol::Corrupt => "CORRUPT",
+            WaypointTraitSymbol::Bureaucratic => "BUREAUCRATIC",
+            WaypointTraitSymbol::TradingHub => "TRADING_HUB",
+            WaypointTraitSymbol::Industrial => "INDUSTRIAL",
+            WaypointTraitSymbol::BlackMarket => "BLACK_MARKET",
+            WaypointTraitSymbol::ResearchFacility => "RESEARCH_FACILITY",
+            WaypointTraitSymbol::MilitaryBase => "MILITARY_BASE",
+            WaypointTraitSymbol::SurveillanceOutpost => "SURVEILLANCE_OUTPOST",
+            WaypointTraitSymbol::ExplorationOutpost => "EXPLORATION_OUTPOST",
+            WaypointTraitSymbol::MineralDeposits => "MINERAL_DEPOSITS",
+            WaypointTraitSymbol::CommonMetalDeposits => "COMMON_METAL_DEPOSITS",
+            WaypointTraitSymbol::PreciousMetalDeposits => "PRECIOUS_METAL_DEPOSITS",
+            WaypointTraitSymbol::RareMetalDeposits => "RARE_METAL_DEPOSITS",
+            WaypointTraitSymbol::MethanePools => "METHANE_POOLS",
+            WaypointTraitSymbol::IceCrystals => "ICE_CRYSTALS",
+            WaypointTraitSymbol::ExplosiveGases => "EXPLOSIVE_GASES",
+            WaypointTraitSymbol::StrongMagnetosphere => "STRONG_MAGNETOSPHERE",
+            WaypointTraitSymbol::VibrantAuroras => "VIBRANT_AURORAS",
+            WaypointTraitSymbol::SaltFlats => "SALT_FLATS",
+            WaypointTraitSymbol::Canyons => "CANYONS",
+            WaypointTraitSymbol::PerpetualDaylight => "PERPETUAL_DAYLIGHT",
+            WaypointTraitSymbol::PerpetualOvercast => "PERPETUAL_OVERCAST",
+            WaypointTraitSymbol::DrySeabeds => "DRY_SEABEDS",
+            WaypointTraitSymbol::MagmaSeas => "MAGMA_SEAS",
+            WaypointTraitSymbol::Supervolcanoes => "SUPERVOLCANOES",
+            WaypointTraitSymbol::AshClouds => "ASH_CLOUDS",
+            WaypointTraitSymbol::VastRuins => "VAST_RUINS",
+            WaypointTraitSymbol::MutatedFlora => "MUTATED_FLORA",
+            WaypointTraitSymbol::Terraformed => "TERRAFORMED",
+            WaypointTraitSymbol::ExtremeTemperatures => "EXTREME_TEMPERATURES",
+            WaypointTraitSymbol::ExtremePressure => "EXTREME_PRESSURE",
+            WaypointTraitSymbol::DiverseLife => "DIVERSE_LIFE",
+            WaypointTraitSymbol::ScarceLife => "SCARCE_LIFE",
+            WaypointTraitSymbol::Fossils => "FOSSILS",
+            WaypointTraitSymbol::WeakGravity => "WEAK_GRAVITY",
+            WaypointTraitSymbol::StrongGravity => "STRONG_GRAVITY",
+            WaypointTraitSymbol::CrushingGravity => "CRUSHING_GRAVITY",
+            WaypointTraitSymbol::ToxicAtmosphere => "TOXIC_ATMOSPHERE",
+            WaypointTraitSymbol::CorrosiveAtmosphere => "CORROSIVE_ATMOSPHERE",
+            WaypointTraitSymbol::BreathableAtmosphere => "BREATHABLE_ATMOSPHERE",
+            WaypointTraitSymbol::ThinAtmosphere => "THIN_ATMOSPHERE",
+            WaypointTraitSymbol::Jovian => "JOVIAN",
+            WaypointTraitSymbol::Rocky => "ROCKY",
+            WaypointTraitSymbol::Volcanic => "VOLCANIC",
+            WaypointTraitSymbol::Frozen => "FROZEN",
+            WaypointTraitSymbol::Swamp => "SWAMP",
+            WaypointTraitSymbol::Barren => "BARREN",
+            WaypointTraitSymbol::Temperate => "TEMPERATE",
+            WaypointTraitSymbol::Jungle => "JUNGLE",
+            WaypointTraitSymbol::Ocean => "OCEAN",
+            WaypointTraitSymbol::Radioactive => "RADIOACTIVE",
+            WaypointTraitSymbol::MicroGravityAnomalies => "MICRO_GRAVITY_ANOMALIES",
+            WaypointTraitSymbol::DebrisCluster => "DEBRIS_CLUSTER",
+            WaypointTraitSymbol::DeepCraters => "DEEP_CRATERS",
+            WaypointTraitSymbol::ShallowCraters => "SHALLOW_CRATERS",
+            WaypointTraitSymbol::UnstableComposition => "UNSTABLE_COMPOSITION",
+            WaypointTraitSymbol::HollowedInterior => "HOLLOWED_INTERIOR",
+            WaypointTraitSymbol::Stripped => "STRIPPED",
+            WaypointTraitSymbol::Unknown(symbol) => symbol,
+        }
+    }
+}
+impl From<&str> for WaypointTraitSymbol {
+    fn from(symbol: &str) -> Self {
+        match symbol {
+            "UNCHARTED" => WaypointTraitSymbol::Uncharted,
+            "UNDER_CONSTRUCTION" => WaypointTraitSymbol::UnderConstruction,
+            "MARKETPLACE" => WaypointTraitSymbol::Marketplace,
+            "SHIPYARD" => WaypointTraitSymbol::Shipyard,
+            "OUTPOST" => WaypointTraitSymbol::Outpost,
+            "SCATTERED_SETTLEMENTS" => WaypointTraitSymbol::ScatteredSettlements,
+            "SPRAWLING_CITIES" => WaypointTraitSymbol::SprawlingCities,
+            "MEGA_STRUCTURES" => WaypointTraitSymbol::MegaStructures,
+            "PIRATE_BASE" => WaypointTraitSymbol::PirateBase,
+            "OVERCROWDED" => WaypointTraitSymbol::Overcrowded,
+            "HIGH_TECH" => WaypointTraitSymbol::HighTech,
+            "CORRUPT" => WaypointTraitSymbol::Corrupt,
+            "BUREAUCRATIC" => WaypointTraitSymbol::Bureaucratic,
+            "TRADING_HUB" => WaypointTraitSymbol::TradingHub,
+            "INDUSTRIAL" => WaypointTraitSymbol::Industrial,
+            "BLACK_MARKET" => WaypointTraitSymbol::BlackMarket,
+            "RESEARCH_FACILITY" => WaypointTraitSymbol::ResearchFacility,
+            "MILITARY_BASE" => WaypointTraitSymbol::MilitaryBase,
+            "SURVEILLANCE_OUTPOST" => WaypointTraitSymbol::SurveillanceOutpost,
+            "EXPLORATION_OUTPOST" => WaypointTraitSymbol::ExplorationOutpost,
+            "MINERAL_DEPOSITS" => WaypointTraitSymbol::MineralDeposits,
+            "COMMON_METAL_DEPOSITS" => WaypointTraitSymbol::CommonMetalDeposits,
+            "PRECIOUS_METAL_DEPOSITS" => WaypointTraitSymbol::PreciousMetalDeposits,
+            "RARE_METAL_DEPOSITS" => WaypointTraitSymbol::RareMetalDeposits,
+            "METHANE_POOLS" => WaypointTraitSymbol::MethanePools,
+            "ICE_CRYSTALS" => WaypointTraitSymbol::IceCrystals,
+            "EXPLOSIVE_GASES" => WaypointTraitSymbol::ExplosiveGases,
+            "STRONG_MAGNETOSPHERE" => WaypointTraitSymbol::StrongMagnetosphere,
+            "VIBRANT_AURORAS" => WaypointTraitSymbol::VibrantAuroras,
+            "SALT_FLATS" => WaypointTraitSymbol::SaltFlats,
+            "CANYONS" => WaypointTraitSymbol::Canyons,
+            "PERPETUAL_DAYLIGHT" => WaypointTraitSymbol::PerpetualDaylight,
+            "PERPETUAL_OVERCAST" => WaypointTraitSymbol::PerpetualOvercast,
+            "DRY_SEABEDS" => WaypointTraitSymbol::DrySeabeds,
+            "MAGMA_SEAS" => WaypointTraitSymbol::MagmaSeas,
+            "SUPERVOLCANOES" => WaypointTraitSymbol::Supervolcanoes,
+            "ASH_CLOUDS" => WaypointTraitSymbol::AshClouds,
+            "VAST_RUINS" => WaypointTraitSymbol::VastRuins,
+            "MUTATED_FLORA" => WaypointTraitSymbol::MutatedFlora,
+            "TERRAFORMED" => WaypointTraitSymbol::Terraformed,
+            "EXTREME_TEMPERATURES" => WaypointTraitSymbol::ExtremeTemperatures,
+            "EXTREME_PRESSURE" => WaypointTraitSymbol::ExtremePressure,
+            "DIVERSE_LIFE" => WaypointTraitSymbol::DiverseLife,
+            "SCARCE_LIFE" => WaypointTraitSymbol::ScarceLife,
+            "FOSSILS" => WaypointTraitSymbol::Fossils,
+            "WEAK_GRAVITY" => WaypointTraitSymbol::WeakGravity,
+            "STRONG_GRAVITY" => WaypointTraitSymbol::StrongGravity,
+            "CRUSHING_GRAVITY" => WaypointTraitSymbol::CrushingGravity,
+            "TOXIC_ATMOSPHERE" => WaypointTraitSymbol::ToxicAtmosphere,
+            "CORROSIVE_ATMOSPHERE" => WaypointTraitSymbol::CorrosiveAtmosphere,
+            "BREATHABLE_ATMOSPHERE" => WaypointTraitSymbol::BreathableAtmosphere,
+            "THIN_ATMOSPHERE" => WaypointTraitSymbol::ThinAtmosphere,
+            "JOVIAN" => WaypointTraitSymbol::Jovian,
+            "ROCKY" => WaypointTraitSymbol::Rocky,
+            "VOLCANIC" => WaypointTraitSymbol::Volcanic,
+            "FROZEN" => WaypointTraitSymbol::Frozen,
+            "SWAMP" => WaypointTraitSymbol::Swamp,
+            "BARREN" => WaypointTraitSymbol::Barren,
+            "TEMPERATE" => WaypointTraitSymbol::Temperate,
+            "JUNGLE" => WaypointTraitSymbol::Jungle,
+            "OCEAN" => WaypointTraitSymbol::Ocean,
+            "RADIOACTIVE" => WaypointTraitSymbol::Radioactive,
+            "MICRO_GRAVITY_ANOMALIES" => WaypointTraitSymbol::MicroGravityAnomalies,
+            "DEBRIS_CLUSTER" => WaypointTraitSymbol::DebrisCluster,
+            "DEEP_CRATERS" => WaypointTraitSymbol::DeepCraters,
+            "SHALLOW_CRATERS" => WaypointTraitSymbol::ShallowCraters,
+            "UNSTABLE_COMPOSITION" => WaypointTraitSymbol::UnstableComposition,
+            "HOLLOWED_INTERIOR" => WaypointTraitSymbol::HollowedInterior,
+            "STRIPPED" => WaypointTraitSymbol::Stripped,
+            _ => WaypointTraitSymbol::Unknown(symbol.to_string()),
+        }
+    }
+}
+impl std::fmt::Display for WaypointTraitSymbol {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+impl Serialize for WaypointTraitSymbol {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+impl<'de> Deserialize<'de> for WaypointTraitSymbol {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let symbol = Self::from(String::deserialize(deserializer)?.as_str());
+        if let WaypointTraitSymbol::Unknown(ref unknown_symbol) = symbol {
+            super::data::record_schema_drift(format!(
+                "unknown WaypointTraitSymbol `{unknown_symbol}`"
+            ));
+        }
+        Ok(symbol)
+    }
+}
+
+/// The type of waypoint.
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+pub enum WaypointType {
+    Planet,
+    GasGiant,
+    Moon,
+    OrbitalStation,
+    JumpGate,
+    AsteroidField,
+    Asteroid,
+    EngineeredAsteroid,
+    AsteroidBase,
+    Nebula,
+    DebrisField,
+    GravityWell,
+    ArtificialGravityWell,
+    FuelStation,
+    /// A symbol this client doesn't know about yet.
+    Unknown(String),
+}
+impl WaypointType {
+    /// Symbol the API uses for this value.
+    pub fn as_str(&self) -> &str {
+        match self {
+            WaypointType::Planet => "PLANET",
+            WaypointType::GasGiant => "GAS_GIANT",
+            WaypointType::Moon => "MOON",
+            WaypointType::OrbitalStation => "ORBITAL_STATION",
+            WaypointType::JumpGate => "JUMP_GATE",
+            WaypointType::AsteroidField => "ASTEROID_FIELD",
+            WaypointType::Asteroid => "ASTEROID",
+            WaypointType::EngineeredAsteroid => "ENGINEERED_ASTEROID",
+            WaypointType::AsteroidBase => "ASTEROID_BASE",
+            WaypointType::Nebula => "NEBULA",
+            WaypointType::DebrisField => "DEBRIS_FIELD",
+            WaypointType::GravityWell => "GRAVITY_WELL",
+            WaypointType::ArtificialGravityWell => "ARTIFICIAL_GRAVITY_WELL",
+            WaypointType::FuelStation => "FUEL_STATION",
+            WaypointType::Unknown(symbol) => symbol,
+        }
+    }
+}
+impl From<&str> for WaypointType {
+    fn from(symbol: &str) -> Self {
+        match symbol {
+            "PLANET" => WaypointType::Planet,
+            "GAS_GIANT" => WaypointType::GasGiant,
+            "MOON" => WaypointType::Moon,
+            "ORBITAL_STATION" => WaypointType::OrbitalStation,
+            "JUMP_GATE" => WaypointType::JumpGate,
+            "ASTEROID_FIELD" => WaypointType::AsteroidField,
+            "ASTEROID" => WaypointType::Asteroid,
+            "ENGINEERED_ASTEROID" => WaypointType::EngineeredAsteroid,
+            "ASTEROID_BASE" => WaypointType::AsteroidBase,
+            "NEBULA" => WaypointType::Nebula,
+            "DEBRIS_FIELD" => WaypointType::DebrisField,
+            "GRAVITY_WELL" => WaypointType::GravityWell,
+            "ARTIFICIAL_GRAVITY_WELL" => WaypointType::ArtificialGravityWell,
+            "FUEL_STATION" => WaypointType::FuelStation,
+            _ => WaypointType::Unknown(symbol.to_string()),
+        }
+    }
+}
+impl std::fmt::Display for WaypointType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+impl Serialize for WaypointType {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+impl<'de> Deserialize<'de> for WaypointType {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let symbol = Self::from(String::deserialize(deserializer)?.as_str());
+        if let WaypointType::Unknown(ref unknown_symbol) = symbol {
+            super::data::record_schema_drift(format!("unknown WaypointType `{unknown_symbol}`"));
+        }
+        Ok(symbol)
+    }
+}
+
+/// Paths of the API's endpoints, relative to the root URL.
+pub mod endpoints {
+    /// Get Status.
+    ///
+    /// `GET /`
+    pub fn get_status() -> String {
+        String::from("/")
+    }
+
+    /// List Factions.
+    ///
+    /// `GET /factions`
+    pub fn get_factions() -> String {
+        String::from("/factions")
+    }
+
+    /// Get Faction.
+    ///
+    /// `GET /factions/{factionSymbol}`
+    pub fn get_faction(faction_symbol: &str) -> String {
+        format!("/factions/{faction_symbol}")
+    }
+
+    /// Get Agent.
+    ///
+    /// `GET /my/agent`
+    pub fn get_my_agent() -> String {
+        String::from("/my/agent")
+    }
+
+    /// List Contracts.
+    ///
+    /// `GET /my/contracts`
+    pub fn get_contracts() -> String {
+        String::from("/my/contracts")
+    }
+
+    /// Accept Contract.
+    ///
+    /// `POST /my/contracts/{contractId}/accept`
+    pub fn accept_contract(contract_id: &str) -> String {
+        format!("/my/contracts/{contract_id}/accept")
+    }
+
+    /// Deliver Cargo to Contract.
+    ///
+    /// `POST /my/contracts/{contractId}/deliver`
+    pub fn deliver_contract(contract_id: &str) -> String {
+        format!("/my/contracts/{contract_id}/deliver")
+    }
+
+    /// Fulfill Contract.
+    ///
+    /// `POST /my/contracts/{contractId}/fulfill`
+    pub fn fulfill_contract(contract_id: &str) -> String {
+        format!("/my/contracts/{contract_id}/fulfill")
+    }
+
+    /// Get My Factions.
+    ///
+    /// `GET /my/factions`
+    pub fn get_my_factions() -> String {
+        String::from("/my/factions")
+    }
+
+    /// List Ships.
+    ///
+    /// `GET /my/ships`
+    pub fn get_my_ships() -> String {
+        String::from("/my/ships")
+    }
+
+    /// Purchase Ship.
+    ///
+    /// `POST /my/ships`
+    pub fn purchase_ship() -> String {
+        String::from("/my/ships")
+    }
+
+    /// Get Ship.
+    ///
+    /// `GET /my/ships/{shipSymbol}`
+    pub fn get_my_ship(ship_symbol: &str) -> String {
+        format!("/my/ships/{ship_symbol}")
+    }
+
+    /// Create Chart.
+    ///
+    /// `POST /my/ships/{shipSymbol}/chart`
+    pub fn create_chart(ship_symbol: &str) -> String {
+        format!("/my/ships/{ship_symbol}/chart")
+    }
+
+    /// Dock Ship.
+    ///
+    /// `POST /my/ships/{shipSymbol}/dock`
+    pub fn dock_ship(ship_symbol: &str) -> String {
+        format!("/my/ships/{ship_symbol}/dock")
+    }
+
+    /// Extract Resources.
+    ///
+    /// `POST /my/ships/{shipSymbol}/extract`
+    pub fn extract_resources(ship_symbol: &str) -> String {
+        format!("/my/ships/{ship_symbol}/extract")
+    }
+
+    /// Jettison Cargo.
+    ///
+    /// `POST /my/ships/{shipSymbol}/jettison`
+    pub fn jettison(ship_symbol: &str) -> String {
+        format!("/my/ships/{ship_symbol}/jettison")
+    }
+
+    /// Jump Ship.
+    ///
+    /// `POST /my/ships/{shipSymbol}/jump`
+    pub fn jump_ship(ship_symbol: &str) -> String {
+        format!("/my/ships/{ship_symbol}/jump")
+    }
+
+    /// Install Ship Module.
+    ///
+    /// `POST /my/ships/{shipSymbol}/modules/install`
+    pub fn install_ship_module(ship_symbol: &str) -> String {
+        format!("/my/ships/{ship_symbol}/modules/install")
+    }
+
+    /// Remove Ship Module.
+    ///
+    /// `POST /my/ships/{shipSymbol}/modules/remove`
+    pub fn remove_ship_module(ship_symbol: &str) -> String {
+        format!("/my/ships/{ship_symbol}/modules/remove")
+    }
+
+    /// Install Mount.
+    ///
+    /// `POST /my/ships/{shipSymbol}/mounts/install`
+    pub fn install_mount(ship_symbol: &str) -> String {
+        format!("/my/ships/{ship_symbol}/mounts/install")
+    }
+
+    /// Remove Mount.
+    ///
+    /// `POST /my/ships/{shipSymbol}/mounts/remove`
+    pub fn remove_mount(ship_symbol: &str) -> String {
+        format!("/my/ships/{ship_symbol}/mounts/remove")
+    }
+
+    /// Get Ship Nav.
+    ///
+    /// `GET /my/ships/{shipSymbol}/nav`
+    pub fn get_ship_nav(ship_symbol: &str) -> String {
+        format!("/my/ships/{ship_symbol}/nav")
+    }
+
+    /// Patch Ship Nav.
+    ///
+    /// `PATCH /my/ships/{shipSymbol}/nav`
+    pub fn patch_ship_nav(ship_symbol: &str) -> String {
+        format!("/my/ships/{ship_symbol}/nav")
+    }
+
+    /// Navigate Ship.
+    ///
+    /// `POST /my/ships/{shipSymbol}/navigate`
+    pub fn navigate_ship(ship_symbol: &str) -> String {
+        format!("/my/ships/{ship_symbol}/navigate")
+    }
+
+    /// Negotiate Contract.
+    ///
+    /// `POST /my/ships/{shipSymbol}/negotiate/contract`
+    pub fn negotiate_contract(ship_symbol: &str) -> String {
+        format!("/my/ships/{ship_symbol}/negotiate/contract")
+    }
+
+    /// Orbit Ship.
+    ///
+    /// `POST /my/ships/{shipSymbol}/orbit`
+    pub fn orbit_ship(ship_symbol: &str) -> String {
+        format!("/my/ships/{ship_symbol}/orbit")
+    }
+
+    /// Purchase Cargo.
+    ///
+    /// `POST /my/ships/{shipSymbol}/purchase`
+    pub fn purchase_cargo(ship_symbol: &str) -> String {
+        format!("/my/ships/{ship_symbol}/purchase")
+    }
+
+    /// Refuel Ship.
+    ///
+    /// `POST /my/ships/{shipSymbol}/refuel`
+    pub fn refuel_ship(ship_symbol: &str) -> String {
+        format!("/my/ships/{ship_symbol}/refuel")
+    }
+
+    /// Get Repair Ship.
+    ///
+    /// `GET /my/ships/{shipSymbol}/repair`
+    pub fn get_repair_ship(ship_symbol: &str) -> String {
+        format!("/my/ships/{ship_symbol}/repair")
+    }
+
+    /// Repair Ship.
+    ///
+    /// `POST /my/ships/{shipSymbol}/repair`
+    pub fn repair_ship(ship_symbol: &str) -> String {
+        format!("/my/ships/{ship_symbol}/repair")
+    }
+
+    /// Scan Ships.
+    ///
+    /// `POST /my/ships/{shipSymbol}/scan/ships`
+    pub fn create_ship_ship_scan(ship_symbol: &str) -> String {
+        format!("/my/ships/{ship_symbol}/scan/ships")
+    }
+
+    /// Scan Systems.
+    ///
+    /// `POST /my/ships/{shipSymbol}/scan/systems`
+    pub fn create_ship_system_scan(ship_symbol: &str) -> String {
+        format!("/my/ships/{ship_symbol}/scan/systems")
+    }
+
+    /// Scan Waypoints.
+    ///
+    /// `POST /my/ships/{shipSymbol}/scan/waypoints`
+    pub fn create_ship_waypoint_scan(ship_symbol: &str) -> String {
+        format!("/my/ships/{ship_symbol}/scan/waypoints")
+    }
+
+    /// Get Scrap Ship.
+    ///
+    /// `GET /my/ships/{shipSymbol}/scrap`
+    pub fn get_scrap_ship(ship_symbol: &str) -> String {
+        format!("/my/ships/{ship_symbol}/scrap")
+    }
+
+    /// Scrap Ship.
+    ///
+    /// `POST /my/ships/{shipSymbol}/scrap`
+    pub fn scrap_ship(ship_symbol: &str) -> String {
+        format!("/my/ships/{ship_symbol}/scrap")
+    }
+
+    /// Sell Cargo.
+    ///
+    /// `POST /my/ships/{shipSymbol}/sell`
+    pub fn sell_cargo(ship_symbol: &str) -> String {
+        format!("/my/ships/{ship_symbol}/sell")
+    }
+
+    /// Siphon Resources.
+    ///
+    /// `POST /my/ships/{shipSymbol}/siphon`
+    pub fn siphon_resources(ship_symbol: &str) -> String {
+        format!("/my/ships/{ship_symbol}/siphon")
+    }
+
+    /// Create Survey.
+    ///
+    /// `POST /my/ships/{shipSymbol}/survey`
+    pub fn create_survey(ship_symbol: &str) -> String {
+        format!("/my/ships/{ship_symbol}/survey")
+    }
+
+    /// Transfer Cargo.
+    ///
+    /// `POST /my/ships/{shipSymbol}/transfer`
+    pub fn transfer_cargo(ship_symbol: &str) -> String {
+        format!("/my/ships/{ship_symbol}/transfer")
+    }
+
+    /// Warp Ship.
+    ///
+    /// `POST /my/ships/{shipSymbol}/warp`
+    pub fn warp_ship(ship_symbol: &str) -> String {
+        format!("/my/ships/{ship_symbol}/warp")
+    }
+
+    /// Register New Agent.
+    ///
+    /// `POST /register`
+    pub fn register() -> String {
+        String::from("/register")
+    }
+
+    /// List Waypoints in System.
+    ///
+    /// `GET /systems/{systemSymbol}/waypoints`
+    pub fn get_system_waypoints(system_symbol: &str) -> String {
+        format!("/systems/{system_symbol}/waypoints")
+    }
+
+    /// Get Waypoint.
+    ///
+    /// `GET /systems/{systemSymbol}/waypoints/{waypointSymbol}`
+    pub fn get_waypoint(system_symbol: &str, waypoint_symbol: &str) -> String {
+        format!("/systems/{system_symbol}/waypoints/{waypoint_symbol}")
+    }
+
+    /// Get Construction Site.
+    ///
+    /// `GET /systems/{systemSymbol}/waypoints/{waypointSymbol}/construction`
+    pub fn get_construction(system_symbol: &str, waypoint_symbol: &str) -> String {
+        format!("/systems/{system_symbol}/waypoints/{waypoint_symbol}/construction")
+    }
+
+    /// Supply Construction Site.
+    ///
+    /// `POST /systems/{systemSymbol}/waypoints/{waypointSymbol}/construction/supply`
+    pub fn supply_construction(system_symbol: &str, waypoint_symbol: &str) -> String {
+        format!("/systems/{system_symbol}/waypoints/{waypoint_symbol}/construction/supply")
+    }
+
+    /// Get Jump Gate.
+    ///
+    /// `GET /systems/{systemSymbol}/waypoints/{waypointSymbol}/jump-gate`
+    pub fn get_jump_gate(system_symbol: &str, waypoint_symbol: &str) -> String {
+        format!("/systems/{system_symbol}/waypoints/{waypoint_symbol}/jump-gate")
+    }
+
+    /// Get Market.
+    ///
+    /// `GET /systems/{systemSymbol}/waypoints/{waypointSymbol}/market`
+    pub fn get_market(system_symbol: &str, waypoint_symbol: &str) -> String {
+        format!("/systems/{system_symbol}/waypoints/{waypoint_symbol}/market")
+    }
+}
@@ -1,2 +1,7 @@
 pub mod client;
 pub mod data;
+pub mod generated;
+pub mod rate_limit;
+#[cfg(test)]
+pub(crate) mod stub;
+pub mod token_store;
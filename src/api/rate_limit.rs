@@ -0,0 +1,102 @@
+use std::{
+    sync::Mutex,
+    thread,
+    time::{Duration, Instant},
+};
+
+/// Sustained number of requests per second the SpaceTraders API allows each agent.
+pub const DEFAULT_REQUESTS_PER_SECOND: f64 = 2.0;
+
+/// Number of requests that can be sent back to back before being held to the sustained rate.
+pub const DEFAULT_BURST: u32 = 2;
+
+/// Token bucket keeping an agent's requests under the API's rate limit, so they're delayed on our side instead of
+/// being rejected by the server.
+#[derive(Debug)]
+pub struct RateLimiter {
+    requests_per_second: f64,
+    burst: u32,
+    /// Requests that can currently be sent without waiting, and when that was last worked out.
+    bucket: Mutex<(f64, Instant)>,
+}
+
+impl Default for RateLimiter {
+    fn default() -> Self {
+        Self::new(DEFAULT_REQUESTS_PER_SECOND, DEFAULT_BURST)
+    }
+}
+
+impl RateLimiter {
+    /// Creates a [`RateLimiter`] that starts with a full burst available.
+    ///
+    /// * `requests_per_second` - sustained rate requests are allowed at.
+    /// * `burst` - number of requests that can be sent back to back.
+    pub fn new(requests_per_second: f64, burst: u32) -> Self {
+        Self {
+            requests_per_second,
+            burst,
+            bucket: Mutex::new((burst.into(), Instant::now())),
+        }
+    }
+
+    /// Reserves the next request, without waiting for it.
+    ///
+    /// * `now` - the current time.
+    ///
+    /// Returns how long to wait before sending the request.
+    pub fn reserve(&self, now: Instant) -> Duration {
+        let mut bucket = self
+            .bucket
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner());
+        let (available, updated) = *bucket;
+        let refilled =
+            now.saturating_duration_since(updated).as_secs_f64() * self.requests_per_second;
+        let available = (available + refilled).min(self.burst.into()) - 1.0;
+        *bucket = (available, now.max(updated));
+
+        if available >= 0.0 {
+            Duration::ZERO
+        } else {
+            Duration::from_secs_f64(-available / self.requests_per_second)
+        }
+    }
+
+    /// Waits until the next request can be sent without going over the rate limit.
+    pub fn acquire(&self) {
+        let delay = self.reserve(Instant::now());
+        if !delay.is_zero() {
+            thread::sleep(delay);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::RateLimiter;
+
+    use std::time::{Duration, Instant};
+
+    #[test]
+    fn verify_rate_limit_delays() {
+        // Setup
+        let start = Instant::now();
+        let rate_limiter = RateLimiter::new(2.0, 2);
+
+        // Test
+        let delays: Vec<Duration> = (0..4).map(|_| rate_limiter.reserve(start)).collect();
+        let delay_after_refill = rate_limiter.reserve(start + Duration::from_secs(5));
+
+        // Verify
+        assert_eq!(
+            delays,
+            vec![
+                Duration::ZERO,
+                Duration::ZERO,
+                Duration::from_millis(500),
+                Duration::from_secs(1),
+            ]
+        );
+        assert_eq!(delay_after_refill, Duration::ZERO);
+    }
+}
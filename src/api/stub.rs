@@ -0,0 +1,385 @@
+use super::{
+    client::{ApiResult, TraderApis},
+    data::{
+        AgentData, CargoInfo, ChartData, Construction, ConstructionSupplyData, ContractData,
+        ContractUpdateData, Cooldown, DeliveryData, Extraction, ExtractionData, ExtractionYield,
+        FactionData, FactionReputation, Factions, FlightMode, JumpData, JumpGate, LocationData,
+        MarketData, ModulesData, MountsData, NavInfo, NavigationData, RefuelData, RegistrationData,
+        RepairData, ScrapData, ShipData, ShipModuleSymbol, ShipMountSymbol, ShipPurchaseData,
+        ShipScanData, ShipServiceTransaction, ShipType, SiphonData, StatusData, Survey, SurveyData,
+        SystemScanData, TradeData, TradeSymbol, TransferData, WaypointScanData,
+    },
+};
+use crate::{
+    automation::{STATUS_DOCKED, STATUS_IN_ORBIT, STATUS_IN_TRANSIT},
+    logistics,
+};
+
+use chrono::{Duration, Utc};
+use std::{collections::HashMap, sync::Mutex};
+
+/// Fuel every trip of a [`StubApi`] ship burns.
+pub const TRIP_FUEL: u32 = 10;
+
+/// Units of iron ore every extraction of a [`StubApi`] ship yields.
+pub const EXTRACTION_UNITS: u32 = 5;
+
+/// Seconds a [`StubApi`] ship's reactor cools down after an extraction.
+pub const EXTRACTION_COOLDOWN_SECS: u32 = 70;
+
+/// Stand-in for the SpaceTraders API, keeping a handful of ships in memory and answering the ship requests the way
+/// the server would. Requests that aren't stubbed panic.
+#[derive(Debug, Default)]
+pub struct StubApi {
+    ships: Mutex<HashMap<String, ShipData>>,
+}
+
+impl StubApi {
+    /// Creates a [`StubApi`] owning some ships.
+    ///
+    /// * `ships` - [`ShipData`] of the ships.
+    pub fn new(ships: Vec<ShipData>) -> Self {
+        Self {
+            ships: Mutex::new(
+                ships
+                    .into_iter()
+                    .map(|ship| (ship.symbol.clone(), ship))
+                    .collect(),
+            ),
+        }
+    }
+
+    /// Applies a request to one of the ships.
+    fn update<T>(&self, ship: &str, apply: impl FnOnce(&mut ShipData) -> T) -> T {
+        let mut ships = self.ships.lock().unwrap();
+        apply(
+            ships
+                .get_mut(ship)
+                .unwrap_or_else(|| panic!("the stub has no ship {ship}")),
+        )
+    }
+}
+
+impl TraderApis for StubApi {
+    fn get_status(&self) -> ApiResult<StatusData> {
+        unimplemented!()
+    }
+
+    fn register_new_agent(
+        &self,
+        _agent_name: &str,
+        _faction_name: Factions,
+    ) -> ApiResult<RegistrationData> {
+        unimplemented!()
+    }
+
+    fn get_agent_data(&self) -> ApiResult<AgentData> {
+        unimplemented!()
+    }
+
+    fn list_reputations(&self) -> ApiResult<Vec<FactionReputation>> {
+        unimplemented!()
+    }
+
+    fn list_factions(&self) -> ApiResult<Vec<FactionData>> {
+        unimplemented!()
+    }
+
+    fn get_faction(&self, _faction: &Factions) -> ApiResult<FactionData> {
+        unimplemented!()
+    }
+
+    fn get_waypoint_location_data(&self, _waypoint: &str) -> ApiResult<LocationData> {
+        unimplemented!()
+    }
+
+    fn get_waypoints_location_data(&self, _waypoints: &[&str]) -> ApiResult<Vec<LocationData>> {
+        unimplemented!()
+    }
+
+    fn get_market(&self, _waypoint: &str) -> ApiResult<MarketData> {
+        unimplemented!()
+    }
+
+    fn list_ships(&self) -> ApiResult<Vec<ShipData>> {
+        let mut ships: Vec<ShipData> = self.ships.lock().unwrap().values().cloned().collect();
+        ships.sort_by(|a, b| a.symbol.cmp(&b.symbol));
+        Ok(ships)
+    }
+
+    fn get_ship(&self, ship: &str) -> ApiResult<ShipData> {
+        Ok(self.update(ship, |ship_data| ship_data.clone()))
+    }
+
+    fn list_waypoints(&self, _system: &str) -> ApiResult<Vec<LocationData>> {
+        unimplemented!()
+    }
+
+    fn get_construction(&self, _waypoint: &str) -> ApiResult<Construction> {
+        unimplemented!()
+    }
+
+    fn supply_construction(
+        &self,
+        _ship: &str,
+        _trade_symbol: &TradeSymbol,
+        _units: u32,
+    ) -> ApiResult<ConstructionSupplyData> {
+        unimplemented!()
+    }
+
+    fn get_jump_gate(&self, _waypoint: &str) -> ApiResult<JumpGate> {
+        unimplemented!()
+    }
+
+    fn orbit_ship(&self, ship: &str) -> ApiResult<NavInfo> {
+        Ok(self.update(ship, |ship_data| {
+            ship_data.nav.status = String::from(STATUS_IN_ORBIT);
+            ship_data.nav.clone()
+        }))
+    }
+
+    fn dock_ship(&self, ship: &str) -> ApiResult<NavInfo> {
+        Ok(self.update(ship, |ship_data| {
+            ship_data.nav.status = String::from(STATUS_DOCKED);
+            ship_data.nav.clone()
+        }))
+    }
+
+    fn navigate_ship(&self, ship: &str, waypoint: &str) -> ApiResult<NavigationData> {
+        Ok(self.update(ship, |ship_data| {
+            let route = &mut ship_data.nav.route;
+            route.origin = route.destination.clone();
+            route.destination.symbol = waypoint.to_string();
+            route.departure_time = Some(Utc::now());
+            route.arrival = Some(Utc::now() + Duration::minutes(1));
+            ship_data.nav.waypoint_symbol = waypoint.to_string();
+            ship_data.nav.status = String::from(STATUS_IN_TRANSIT);
+            ship_data.fuel.current = ship_data.fuel.current.saturating_sub(TRIP_FUEL);
+            NavigationData {
+                fuel: ship_data.fuel.clone(),
+                nav: ship_data.nav.clone(),
+                extra: Default::default(),
+            }
+        }))
+    }
+
+    fn jump_ship(&self, _ship: &str, _system: &str) -> ApiResult<JumpData> {
+        unimplemented!()
+    }
+
+    fn warp_ship(&self, _ship: &str, _waypoint: &str) -> ApiResult<NavigationData> {
+        unimplemented!()
+    }
+
+    fn set_flight_mode(&self, _ship: &str, _flight_mode: FlightMode) -> ApiResult<NavInfo> {
+        unimplemented!()
+    }
+
+    fn create_chart(&self, _ship: &str) -> ApiResult<ChartData> {
+        unimplemented!()
+    }
+
+    fn create_survey(&self, _ship: &str) -> ApiResult<SurveyData> {
+        unimplemented!()
+    }
+
+    fn scan_systems(&self, _ship: &str) -> ApiResult<SystemScanData> {
+        unimplemented!()
+    }
+
+    fn scan_waypoints(&self, _ship: &str) -> ApiResult<WaypointScanData> {
+        unimplemented!()
+    }
+
+    fn scan_ships(&self, _ship: &str) -> ApiResult<ShipScanData> {
+        unimplemented!()
+    }
+
+    fn siphon_resources(&self, _ship: &str) -> ApiResult<SiphonData> {
+        unimplemented!()
+    }
+
+    fn extract_resources(&self, ship: &str, _survey: Option<&Survey>) -> ApiResult<ExtractionData> {
+        Ok(self.update(ship, |ship_data| {
+            logistics::add_cargo(
+                &mut ship_data.cargo,
+                &TradeSymbol::IronOre,
+                EXTRACTION_UNITS,
+            );
+            ExtractionData {
+                cooldown: Cooldown {
+                    ship_symbol: ship.to_string(),
+                    total_seconds: EXTRACTION_COOLDOWN_SECS,
+                    remaining_seconds: EXTRACTION_COOLDOWN_SECS,
+                    expiration: Some(
+                        Utc::now() + Duration::seconds(EXTRACTION_COOLDOWN_SECS.into()),
+                    ),
+                    extra: Default::default(),
+                },
+                extraction: Extraction {
+                    ship_symbol: ship.to_string(),
+                    r#yield: ExtractionYield {
+                        symbol: TradeSymbol::IronOre,
+                        units: EXTRACTION_UNITS,
+                        extra: Default::default(),
+                    },
+                    extra: Default::default(),
+                },
+                cargo: ship_data.cargo.clone(),
+                extra: Default::default(),
+            }
+        }))
+    }
+
+    fn jettison_cargo(
+        &self,
+        _ship: &str,
+        _trade_symbol: &TradeSymbol,
+        _units: u32,
+    ) -> ApiResult<CargoInfo> {
+        unimplemented!()
+    }
+
+    fn transfer_cargo(
+        &self,
+        from_ship: &str,
+        to_ship: &str,
+        trade_symbol: &TradeSymbol,
+        units: u32,
+    ) -> ApiResult<TransferData> {
+        let cargo = self.update(from_ship, |ship_data| {
+            let cargo = &mut ship_data.cargo;
+            cargo.units -= units;
+            if let Some(cargo_item) = cargo
+                .inventory
+                .iter_mut()
+                .find(|cargo_item| cargo_item.symbol == *trade_symbol)
+            {
+                cargo_item.units -= u16::try_from(units).unwrap();
+            }
+            cargo.inventory.retain(|cargo_item| cargo_item.units > 0);
+            cargo.clone()
+        });
+        let target_cargo = self.update(to_ship, |ship_data| {
+            logistics::add_cargo(&mut ship_data.cargo, trade_symbol, units);
+            ship_data.cargo.clone()
+        });
+        Ok(TransferData {
+            cargo,
+            target_cargo: Some(target_cargo),
+            extra: Default::default(),
+        })
+    }
+
+    fn sell_cargo(
+        &self,
+        _ship: &str,
+        _trade_symbol: &TradeSymbol,
+        _units: u32,
+    ) -> ApiResult<TradeData> {
+        unimplemented!()
+    }
+
+    fn refuel_ship(
+        &self,
+        _ship: &str,
+        _units: Option<u32>,
+        _from_cargo: bool,
+    ) -> ApiResult<RefuelData> {
+        unimplemented!()
+    }
+
+    fn get_repair_ship(&self, _ship: &str) -> ApiResult<ShipServiceTransaction> {
+        unimplemented!()
+    }
+
+    fn repair_ship(&self, _ship: &str) -> ApiResult<RepairData> {
+        unimplemented!()
+    }
+
+    fn get_scrap_ship(&self, _ship: &str) -> ApiResult<ShipServiceTransaction> {
+        unimplemented!()
+    }
+
+    fn scrap_ship(&self, ship: &str) -> ApiResult<ScrapData> {
+        let ship_data = self
+            .ships
+            .lock()
+            .unwrap()
+            .remove(ship)
+            .unwrap_or_else(|| panic!("the stub has no ship {ship}"));
+        Ok(ScrapData {
+            agent: AgentData {
+                account_id: None,
+                symbol: String::from("TEST_AGENT"),
+                headquarters: ship_data.nav.waypoint_symbol.clone(),
+                credits: 0,
+                extra: Default::default(),
+            },
+            transaction: ShipServiceTransaction {
+                waypoint_symbol: ship_data.nav.waypoint_symbol,
+                ship_symbol: ship_data.symbol,
+                total_price: 0,
+                timestamp: Utc::now(),
+                extra: Default::default(),
+            },
+            extra: Default::default(),
+        })
+    }
+
+    fn purchase_ship(&self, _ship_type: &ShipType, _waypoint: &str) -> ApiResult<ShipPurchaseData> {
+        unimplemented!()
+    }
+
+    fn install_mount(&self, _ship: &str, _mount: &ShipMountSymbol) -> ApiResult<MountsData> {
+        unimplemented!()
+    }
+
+    fn remove_mount(&self, _ship: &str, _mount: &ShipMountSymbol) -> ApiResult<MountsData> {
+        unimplemented!()
+    }
+
+    fn install_module(&self, _ship: &str, _module: &ShipModuleSymbol) -> ApiResult<ModulesData> {
+        unimplemented!()
+    }
+
+    fn remove_module(&self, _ship: &str, _module: &ShipModuleSymbol) -> ApiResult<ModulesData> {
+        unimplemented!()
+    }
+
+    fn purchase_cargo(
+        &self,
+        _ship: &str,
+        _trade_symbol: &TradeSymbol,
+        _units: u32,
+    ) -> ApiResult<TradeData> {
+        unimplemented!()
+    }
+
+    fn list_contracts(&self) -> ApiResult<Vec<ContractData>> {
+        unimplemented!()
+    }
+
+    fn accept_contract(&self, _contract_id: &str) -> ApiResult<ContractUpdateData> {
+        unimplemented!()
+    }
+
+    fn deliver_contract(
+        &self,
+        _contract_id: &str,
+        _ship: &str,
+        _trade_symbol: &TradeSymbol,
+        _units: u32,
+    ) -> ApiResult<DeliveryData> {
+        unimplemented!()
+    }
+
+    fn fulfill_contract(&self, _contract_id: &str) -> ApiResult<ContractUpdateData> {
+        unimplemented!()
+    }
+
+    fn negotiate_contract(&self, _ship: &str) -> ApiResult<ContractData> {
+        unimplemented!()
+    }
+}
@@ -0,0 +1,604 @@
+use super::data::{without_schema_drift, Factions, RegistrationData};
+use crate::utils::config::{self, ConfigData, ConfigError, ProfileData};
+
+use serde::{Deserialize, Serialize};
+
+use std::{
+    env, fmt,
+    io::Write,
+    path::PathBuf,
+    process::{Command, Stdio},
+    sync::Mutex,
+};
+
+/// Environment variable [`EnvTokenStore`] reads the agent token from by default.
+pub const TOKEN_ENV_VAR: &str = "SPACETRADERS_TOKEN";
+
+/// Service name the agent token is filed under in the OS keyring.
+const KEYRING_SERVICE: &str = "spacetraders_client";
+
+pub type TokenStoreResult<T> = Result<T, TokenStoreError>;
+
+#[derive(Clone, Debug)]
+pub enum TokenStoreError {
+    Config(ConfigError),
+    Keyring(String),
+}
+
+/// What is kept about an agent once it's registered.
+#[derive(Clone, Debug, Eq, PartialEq, Deserialize, Serialize)]
+pub struct StoredAgent {
+    pub token: String,
+    /// Symbol the agent was registered with, if the store knows it.
+    #[serde(default)]
+    pub symbol: Option<String>,
+    /// [`Faction`](`Factions`) the agent was registered in, if the store knows it.
+    #[serde(default)]
+    pub faction: Option<Factions>,
+    /// Server reset date at the time the agent was registered, used to detect universe wipes.
+    #[serde(default)]
+    pub reset_date: Option<String>,
+}
+
+impl StoredAgent {
+    /// Gets what needs keeping about a newly registered agent.
+    ///
+    /// * `registration_data` - [`RegistrationData`] returned by the registration.
+    /// * `reset_date` - the server's current reset date, if known.
+    pub fn from_registration(
+        registration_data: &RegistrationData,
+        reset_date: Option<String>,
+    ) -> Self {
+        Self {
+            token: registration_data.token.clone(),
+            symbol: Some(registration_data.agent.symbol.clone()),
+            faction: Some(registration_data.faction.symbol.clone()),
+            reset_date,
+        }
+    }
+}
+
+/// Somewhere the agent token is kept between runs, so registering an agent doesn't dictate where its token ends up.
+///
+/// Stores are shared by clients acting on different threads, so they must be thread-safe.
+pub trait TokenStore: fmt::Debug + Send + Sync {
+    /// Gets the stored agent.
+    ///
+    /// Returns the [`StoredAgent`], or [`Option::None`] if no agent has been stored.
+    fn load(&self) -> Option<StoredAgent>;
+
+    /// Stores an agent, replacing any agent stored before.
+    ///
+    /// * `agent` - the [`StoredAgent`].
+    ///
+    /// Returns unit on success, or the [`TokenStoreError`] reason for failure.
+    fn save(&self, agent: &StoredAgent) -> TokenStoreResult<()>;
+
+    /// Gets the stored agent token.
+    ///
+    /// Returns the token, or [`Option::None`] if no agent has been stored.
+    fn token(&self) -> Option<String> {
+        self.load()
+            .map(|agent| agent.token)
+            .filter(|token| !token.is_empty())
+    }
+
+    /// Keeps a copy of the stored agent before it's replaced, for stores that can.
+    ///
+    /// * `label` - label telling the copy apart from others, e.g. the reset date it belonged to.
+    ///
+    /// Returns a description of where the copy was kept, [`Option::None`] if the store doesn't keep copies or has
+    /// nothing to copy, or the [`TokenStoreError`] reason for failure.
+    fn archive(&self, _label: &str) -> TokenStoreResult<Option<String>> {
+        Ok(None)
+    }
+}
+
+/// Keeps the agent in the config file, next to the client's other settings, either at the top level or as one of
+/// its profiles.
+#[derive(Clone, Debug)]
+pub struct FileTokenStore {
+    config_file_path: PathBuf,
+    profile_name: Option<String>,
+}
+
+impl Default for FileTokenStore {
+    fn default() -> Self {
+        Self::new(config::get_default_config_file_path())
+    }
+}
+
+impl FileTokenStore {
+    /// Creates a [`FileTokenStore`] for the top-level agent of a config file.
+    ///
+    /// * `config_file_path` - [`PathBuf`] path to the config file.
+    pub fn new(config_file_path: PathBuf) -> Self {
+        Self {
+            config_file_path,
+            profile_name: None,
+        }
+    }
+
+    /// Creates a [`FileTokenStore`] for the agent of one of the profiles in a config file.
+    ///
+    /// * `config_file_path` - [`PathBuf`] path to the config file.
+    /// * `profile_name` - name of the profile, which is added when an agent is first saved to it.
+    pub fn with_profile(config_file_path: PathBuf, profile_name: &str) -> Self {
+        Self {
+            config_file_path,
+            profile_name: Some(profile_name.to_string()),
+        }
+    }
+
+    /// Creates a [`FileTokenStore`] for the agent of one of the profiles in the default config file.
+    ///
+    /// * `profile_name` - name of the profile.
+    pub fn for_profile(profile_name: &str) -> Self {
+        Self::with_profile(config::get_default_config_file_path(), profile_name)
+    }
+}
+
+impl TokenStore for FileTokenStore {
+    fn load(&self) -> Option<StoredAgent> {
+        let config_data = config::read_config_file(self.config_file_path.clone())?;
+        let stored_agent = match self.profile_name {
+            Some(ref profile_name) => {
+                let profile = config_data.profile(profile_name)?;
+                StoredAgent {
+                    token: profile.token.clone(),
+                    symbol: profile.symbol.clone(),
+                    faction: profile.faction.clone(),
+                    reset_date: profile.reset_date.clone(),
+                }
+            }
+            None => StoredAgent {
+                token: config_data.token,
+                symbol: config_data.symbol,
+                faction: config_data.faction,
+                reset_date: config_data.reset_date,
+            },
+        };
+        Some(stored_agent)
+    }
+
+    fn save(&self, agent: &StoredAgent) -> TokenStoreResult<()> {
+        // Settings and other agents aren't tied to this agent, so they survive re-registering.
+        let mut config_data =
+            config::read_config_file(self.config_file_path.clone()).unwrap_or_default();
+        match self.profile_name {
+            Some(ref profile_name) => {
+                let profile = ProfileData {
+                    name: profile_name.clone(),
+                    token: agent.token.clone(),
+                    symbol: agent.symbol.clone(),
+                    faction: agent.faction.clone(),
+                    reset_date: agent.reset_date.clone(),
+                };
+                match config_data
+                    .profiles
+                    .iter_mut()
+                    .find(|profile| profile.name == *profile_name)
+                {
+                    Some(stored_profile) => *stored_profile = profile,
+                    None => config_data.profiles.push(profile),
+                }
+            }
+            None => {
+                config_data = ConfigData {
+                    token: agent.token.clone(),
+                    symbol: agent.symbol.clone(),
+                    faction: agent.faction.clone(),
+                    reset_date: agent.reset_date.clone(),
+                    ..config_data
+                };
+            }
+        }
+        config::write_config_file(config_data, self.config_file_path.clone())
+            .map_err(TokenStoreError::Config)
+    }
+
+    fn archive(&self, label: &str) -> TokenStoreResult<Option<String>> {
+        let archive_suffix = match self.profile_name {
+            Some(ref profile_name) => format!("{profile_name}.{label}"),
+            None => label.to_string(),
+        };
+        config::archive_config_file(self.config_file_path.clone(), &archive_suffix)
+            .map(|archive_file_path| Some(archive_file_path.display().to_string()))
+            .map_err(TokenStoreError::Config)
+    }
+}
+
+/// Keeps the agent in memory only, e.g. for tests or throwaway agents.
+#[derive(Debug, Default)]
+pub struct MemoryTokenStore {
+    agent: Mutex<Option<StoredAgent>>,
+}
+
+impl MemoryTokenStore {
+    /// Creates an empty [`MemoryTokenStore`].
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl TokenStore for MemoryTokenStore {
+    fn load(&self) -> Option<StoredAgent> {
+        self.agent
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+            .clone()
+    }
+
+    fn save(&self, agent: &StoredAgent) -> TokenStoreResult<()> {
+        *self
+            .agent
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner()) = Some(agent.clone());
+        Ok(())
+    }
+}
+
+/// Reads the agent token from an environment variable, e.g. when the token is injected by a deployment.
+///
+/// Only the token is kept, and a process can't change its parent's environment, so saving only sets the variable
+/// for the current process.
+#[derive(Clone, Debug)]
+pub struct EnvTokenStore {
+    variable: String,
+}
+
+impl Default for EnvTokenStore {
+    fn default() -> Self {
+        Self::new(TOKEN_ENV_VAR)
+    }
+}
+
+impl EnvTokenStore {
+    /// Creates an [`EnvTokenStore`] for an environment variable.
+    ///
+    /// * `variable` - name of the environment variable holding the token.
+    pub fn new(variable: &str) -> Self {
+        Self {
+            variable: variable.to_string(),
+        }
+    }
+}
+
+impl TokenStore for EnvTokenStore {
+    fn load(&self) -> Option<StoredAgent> {
+        let token = env::var(&self.variable).ok()?;
+        Some(StoredAgent {
+            token,
+            symbol: None,
+            faction: None,
+            reset_date: None,
+        })
+    }
+
+    fn save(&self, agent: &StoredAgent) -> TokenStoreResult<()> {
+        env::set_var(&self.variable, &agent.token);
+        Ok(())
+    }
+}
+
+/// Keeps the agent in the OS keyring, using `security` on macOS and `secret-tool` elsewhere.
+#[derive(Clone, Debug)]
+pub struct KeyringTokenStore {
+    account: String,
+}
+
+impl KeyringTokenStore {
+    /// Creates a [`KeyringTokenStore`] for an entry of the keyring.
+    ///
+    /// * `account` - name the agent is filed under, e.g. the profile it belongs to.
+    pub fn new(account: &str) -> Self {
+        Self {
+            account: account.to_string(),
+        }
+    }
+}
+
+impl KeyringTokenStore {
+    /// Reads the secret of a keyring entry.
+    ///
+    /// * `account` - name the entry is filed under.
+    ///
+    /// Returns the secret, or [`Option::None`] if there's no such entry or the keyring can't be reached.
+    fn read_secret(account: &str) -> Option<String> {
+        let output = if cfg!(target_os = "macos") {
+            Command::new("security")
+                .args(["find-generic-password", "-s", KEYRING_SERVICE])
+                .args(["-a", account, "-w"])
+                .output()
+        } else {
+            Command::new("secret-tool")
+                .args(["lookup", "service", KEYRING_SERVICE])
+                .args(["account", account])
+                .output()
+        }
+        .ok()
+        .filter(|output| output.status.success())?;
+
+        Some(String::from_utf8(output.stdout).ok()?.trim().to_string())
+    }
+
+    /// Writes the secret of a keyring entry, replacing any secret it had.
+    ///
+    /// * `account` - name the entry is filed under.
+    /// * `label` - description of the entry shown by keyring managers.
+    /// * `secret` - the secret.
+    ///
+    /// Returns unit on success, or the [`TokenStoreError`] reason for failure.
+    fn write_secret(account: &str, label: &str, secret: &str) -> TokenStoreResult<()> {
+        let keyring_error = |e: std::io::Error| TokenStoreError::Keyring(e.to_string());
+
+        // The secret is passed on stdin so it doesn't show up in the process list.
+        let (mut command, stdin_input) = if cfg!(target_os = "macos") {
+            // Without a value after `-w`, `security` prompts for the password and then for it again.
+            let mut security = Command::new("security");
+            security
+                .args(["add-generic-password", "-U", "-s", KEYRING_SERVICE])
+                .args(["-a", account, "-l", label, "-w"]);
+            (security, format!("{secret}\n{secret}\n"))
+        } else {
+            let mut secret_tool = Command::new("secret-tool");
+            secret_tool.args(["store", "--label", label]).args([
+                "service",
+                KEYRING_SERVICE,
+                "account",
+                account,
+            ]);
+            (secret_tool, secret.to_string())
+        };
+        let mut child = command
+            .stdin(Stdio::piped())
+            .spawn()
+            .map_err(keyring_error)?;
+        if let Some(mut stdin) = child.stdin.take() {
+            stdin
+                .write_all(stdin_input.as_bytes())
+                .map_err(keyring_error)?;
+        }
+        let status = child.wait().map_err(keyring_error)?;
+
+        if status.success() {
+            Ok(())
+        } else {
+            Err(TokenStoreError::Keyring(format!(
+                "the keyring refused to store the token ({status})"
+            )))
+        }
+    }
+}
+
+/// Reads the agent out of a keyring secret.
+///
+/// * `secret` - the secret, either a serialized [`StoredAgent`] or, for entries written before the whole agent was
+///   kept, just the token.
+fn parse_keyring_secret(secret: String) -> StoredAgent {
+    without_schema_drift(|| serde_json::from_str(&secret)).unwrap_or(StoredAgent {
+        token: secret,
+        symbol: None,
+        faction: None,
+        reset_date: None,
+    })
+}
+
+impl TokenStore for KeyringTokenStore {
+    fn load(&self) -> Option<StoredAgent> {
+        Self::read_secret(&self.account).map(parse_keyring_secret)
+    }
+
+    fn save(&self, agent: &StoredAgent) -> TokenStoreResult<()> {
+        let secret =
+            serde_json::to_string(agent).map_err(|e| TokenStoreError::Keyring(e.to_string()))?;
+        let label = format!(
+            "SpaceTraders agent {}",
+            agent.symbol.as_deref().unwrap_or(&self.account)
+        );
+        Self::write_secret(&self.account, &label, &secret)
+    }
+
+    fn archive(&self, label: &str) -> TokenStoreResult<Option<String>> {
+        let Some(secret) = Self::read_secret(&self.account) else {
+            return Ok(None);
+        };
+        // The copy is filed next to the original under an account of its own.
+        let archive_account = format!("{}.{label}", self.account);
+        Self::write_secret(
+            &archive_account,
+            &format!("Archived SpaceTraders agent {archive_account}"),
+            &secret,
+        )?;
+        Ok(Some(format!(
+            "the {KEYRING_SERVICE} keyring entry for {archive_account}"
+        )))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        parse_keyring_secret, EnvTokenStore, FileTokenStore, MemoryTokenStore, StoredAgent,
+        TokenStore,
+    };
+    use crate::{
+        api::data::Factions,
+        utils::config::{self, ConfigData, ProfileData},
+    };
+
+    use tempfile;
+
+    #[test]
+    fn verify_token_stores_save_agents() {
+        // Setup
+        let tmp_config_dir = tempfile::tempdir().unwrap();
+        let config_file_path = tmp_config_dir.path().join("config.json");
+        let profiles = vec![ProfileData {
+            name: String::from("trader"),
+            token: String::from("TEST_TRADER_TOKEN"),
+            ..Default::default()
+        }];
+        config::write_config_file(
+            ConfigData {
+                token: String::from("TEST_OLD_TOKEN"),
+                strict_mode: true,
+                profiles: profiles.clone(),
+                ..Default::default()
+            },
+            config_file_path.clone(),
+        )
+        .unwrap();
+        let file_token_store = FileTokenStore::new(config_file_path.clone());
+        let memory_token_store = MemoryTokenStore::new();
+        let agent = StoredAgent {
+            token: String::from("TEST_NEW_TOKEN"),
+            symbol: Some(String::from("TEST_AGENT")),
+            faction: Some(Factions::Cosmic),
+            reset_date: Some(String::from("2023-05-20")),
+        };
+
+        // Test
+        file_token_store.save(&agent).unwrap();
+        memory_token_store.save(&agent).unwrap();
+
+        // Verify
+        assert_eq!(
+            config::read_config_file(config_file_path),
+            Some(ConfigData {
+                token: String::from("TEST_NEW_TOKEN"),
+                symbol: Some(String::from("TEST_AGENT")),
+                faction: Some(Factions::Cosmic),
+                reset_date: Some(String::from("2023-05-20")),
+                strict_mode: true,
+                profiles,
+            })
+        );
+        assert_eq!(
+            file_token_store.token(),
+            Some(String::from("TEST_NEW_TOKEN"))
+        );
+        assert_eq!(memory_token_store.load(), Some(agent));
+    }
+
+    #[test]
+    fn verify_profile_token_stores() {
+        // Setup
+        let tmp_config_dir = tempfile::tempdir().unwrap();
+        let config_file_path = tmp_config_dir.path().join("config.json");
+        config::write_config_file(
+            ConfigData {
+                token: String::from("TEST_MAIN_TOKEN"),
+                profiles: vec![ProfileData {
+                    name: String::from("trader"),
+                    token: String::from("TEST_OLD_TOKEN"),
+                    ..Default::default()
+                }],
+                ..Default::default()
+            },
+            config_file_path.clone(),
+        )
+        .unwrap();
+        let trader_token_store = FileTokenStore::with_profile(config_file_path.clone(), "trader");
+        let miner_token_store = FileTokenStore::with_profile(config_file_path.clone(), "miner");
+        let agent = StoredAgent {
+            token: String::from("TEST_TRADER_TOKEN"),
+            symbol: Some(String::from("TEST_TRADER")),
+            faction: Some(Factions::Cosmic),
+            reset_date: Some(String::from("2023-05-20")),
+        };
+
+        // Test
+        let archive_file_path = trader_token_store.archive("2023-05-06").unwrap();
+        trader_token_store.save(&agent).unwrap();
+        miner_token_store
+            .save(&StoredAgent {
+                token: String::from("TEST_MINER_TOKEN"),
+                ..agent.clone()
+            })
+            .unwrap();
+
+        // Verify
+        let config_data = config::read_config_file(config_file_path).unwrap();
+        assert_eq!(config_data.token, "TEST_MAIN_TOKEN");
+        assert_eq!(
+            config_data
+                .profiles
+                .iter()
+                .map(|profile| profile.token.as_str())
+                .collect::<Vec<&str>>(),
+            vec!["TEST_TRADER_TOKEN", "TEST_MINER_TOKEN"]
+        );
+        assert_eq!(trader_token_store.load(), Some(agent));
+        let expected_archive_path = tmp_config_dir.path().join("config.trader.2023-05-06.json");
+        assert_eq!(
+            archive_file_path,
+            Some(expected_archive_path.display().to_string())
+        );
+        assert_eq!(
+            config::read_config_file(expected_archive_path)
+                .and_then(|config_data| config_data.profile("trader").cloned())
+                .map(|profile| profile.token),
+            Some(String::from("TEST_OLD_TOKEN"))
+        );
+    }
+
+    #[test]
+    fn verify_env_token_store() {
+        // Setup
+        let token_store = EnvTokenStore::new("SPACETRADERS_TOKEN_VERIFY_ENV_TOKEN_STORE");
+        let agent = StoredAgent {
+            token: String::from("TEST_ENV_TOKEN"),
+            symbol: Some(String::from("TEST_AGENT")),
+            faction: Some(Factions::Cosmic),
+            reset_date: Some(String::from("2023-05-20")),
+        };
+
+        // Test
+        let unset_agent = token_store.load();
+        token_store.save(&agent).unwrap();
+        let archive_location = token_store.archive("2023-05-06").unwrap();
+
+        // Verify
+        assert_eq!(unset_agent, None);
+        assert_eq!(
+            token_store.load(),
+            Some(StoredAgent {
+                token: String::from("TEST_ENV_TOKEN"),
+                symbol: None,
+                faction: None,
+                reset_date: None,
+            })
+        );
+        assert_eq!(token_store.token(), Some(String::from("TEST_ENV_TOKEN")));
+        assert_eq!(archive_location, None);
+    }
+
+    #[test]
+    fn verify_keyring_secrets_are_parsed() {
+        // Setup
+        let agent = StoredAgent {
+            token: String::from("TEST_KEYRING_TOKEN"),
+            symbol: Some(String::from("TEST_AGENT")),
+            faction: Some(Factions::Cosmic),
+            reset_date: Some(String::from("2023-05-20")),
+        };
+        let agent_secret = serde_json::to_string(&agent).unwrap();
+
+        // Test
+        let parsed_agent = parse_keyring_secret(agent_secret);
+        let parsed_token = parse_keyring_secret(String::from("TEST_RAW_TOKEN"));
+
+        // Verify
+        assert_eq!(parsed_agent, agent);
+        assert_eq!(
+            parsed_token,
+            StoredAgent {
+                token: String::from("TEST_RAW_TOKEN"),
+                symbol: None,
+                faction: None,
+                reset_date: None,
+            }
+        );
+    }
+}
@@ -0,0 +1,403 @@
+use super::{dock, orbit, travel_to, Action, Behavior, ShipContext};
+use crate::{
+    api::{
+        client::{system_of, ApiResult},
+        data::{without_schema_drift, ContractData, DeliveryInfo, TradeSymbol, WaypointSymbol},
+    },
+    history::{self, PriceRecord},
+    scheduler,
+};
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+/// Name the contract behavior is saved under.
+pub const CONTRACT_BEHAVIOR_NAME: &str = "contracts";
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Deserialize, Serialize)]
+enum ContractStep {
+    FindContract,
+    Source,
+    Deliver,
+    Fulfill,
+}
+
+/// Accepts profitable contracts and works through them: sourcing goods by buying or mining, delivering them,
+/// fulfilling the contract and negotiating the next one.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct ContractBehavior {
+    /// Lowest expected profit, after buying the goods, for a contract to be accepted.
+    pub min_profit: i64,
+    /// Asteroid field to mine goods at when no known market sells them.
+    pub asteroid_field: Option<WaypointSymbol>,
+    step: ContractStep,
+    contract: Option<ContractData>,
+}
+
+impl ContractBehavior {
+    /// Creates a [`ContractBehavior`] starting with a search for a contract.
+    ///
+    /// * `min_profit` - lowest expected profit for a contract to be accepted.
+    /// * `asteroid_field` - asteroid field to mine goods at when no known market sells them.
+    pub fn new(min_profit: i64, asteroid_field: Option<&str>) -> Self {
+        Self {
+            min_profit,
+            asteroid_field: asteroid_field.map(String::from),
+            step: ContractStep::FindContract,
+            contract: None,
+        }
+    }
+
+    /// [`BehaviorLoader`](super::BehaviorLoader) for restoring a saved [`ContractBehavior`].
+    pub fn load(state: serde_json::Value) -> Option<Box<dyn Behavior>> {
+        without_schema_drift(|| serde_json::from_value::<Self>(state))
+            .ok()
+            .map(|behavior| Box::new(behavior) as Box<dyn Behavior>)
+    }
+
+    fn find_contract(&mut self, ctx: &mut ShipContext) -> ApiResult<Action> {
+        // Contracts past their deadline can't be fulfilled anymore, and expired offers can't be accepted.
+        let now = Utc::now();
+        let contracts: Vec<ContractData> = ctx
+            .api
+            .list_contracts()?
+            .into_iter()
+            .filter(|contract| is_open(contract, now))
+            .collect();
+        if let Some(contract) = contracts
+            .iter()
+            .find(|contract| contract.accepted && !contract.fulfilled)
+        {
+            self.contract = Some(contract.clone());
+            self.step = ContractStep::Source;
+            return Ok(Action::Continue);
+        }
+
+        let can_mine = self.asteroid_field.is_some()
+            && ctx
+                .ship
+                .mounts
+                .iter()
+                .any(|mount| mount.component_info.symbol.is_mining_laser());
+        let price_records = history::latest_prices();
+        let offered_contracts: Vec<&ContractData> = contracts
+            .iter()
+            .filter(|contract| !contract.accepted)
+            .collect();
+
+        for contract in &offered_contracts {
+            let is_profitable =
+                match estimated_profit(contract, &price_records, &ctx.ship.nav.system_symbol) {
+                    Some(profit) => profit >= self.min_profit,
+                    // Goods nobody is known to sell can only be mined, which costs nothing but time.
+                    None => can_mine && total_payment(contract) >= self.min_profit,
+                };
+            if is_profitable {
+                let contract_update_data = ctx.api.accept_contract(&contract.id)?;
+                self.contract = Some(contract_update_data.contract);
+                self.step = ContractStep::Source;
+                return Ok(Action::Continue);
+            }
+        }
+
+        if !offered_contracts.is_empty() {
+            return Ok(Action::Stop("no offered contract is worth accepting"));
+        }
+
+        dock(ctx)?;
+        ctx.api.negotiate_contract(&ctx.ship.symbol)?;
+        Ok(Action::Continue)
+    }
+
+    fn source(&mut self, ctx: &mut ShipContext) -> ApiResult<Action> {
+        let Some(delivery) = self.current_delivery() else {
+            self.step = ContractStep::Fulfill;
+            return Ok(Action::Continue);
+        };
+
+        let held_units = held_units(ctx, &delivery.trade_symbol);
+        let free_capacity = ctx.ship.cargo.capacity.saturating_sub(ctx.ship.cargo.units);
+        let missing_units = remaining_units(&delivery).saturating_sub(held_units);
+        if missing_units == 0 || (free_capacity == 0 && held_units > 0) {
+            self.step = ContractStep::Deliver;
+            return Ok(Action::Continue);
+        }
+        if free_capacity == 0 {
+            return Ok(Action::Stop(
+                "cargo hold is full of goods the contract doesn't need",
+            ));
+        }
+
+        // Markets in other systems would need jumps to reach, so only the ship's own system is searched.
+        let cheapest_market = history::latest_prices()
+            .into_iter()
+            .filter(|record| {
+                record.trade_symbol == delivery.trade_symbol
+                    && system_of(&record.waypoint_symbol) == ctx.ship.nav.system_symbol
+            })
+            .min_by_key(|record| record.purchase_price);
+        if let Some(price_record) = cheapest_market {
+            if let Some(action) = travel_to(ctx, &price_record.waypoint_symbol)? {
+                return Ok(action);
+            }
+            dock(ctx)?;
+            let units = missing_units
+                .min(free_capacity)
+                .min(price_record.trade_volume.max(1));
+            let trade_data =
+                ctx.api
+                    .purchase_cargo(&ctx.ship.symbol, &delivery.trade_symbol, units)?;
+            ctx.ship.cargo = trade_data.cargo;
+            return Ok(Action::Continue);
+        }
+
+        let Some(asteroid_field) = self.asteroid_field.clone() else {
+            return Ok(Action::Stop(
+                "no known market or asteroid field to source the contract's goods from",
+            ));
+        };
+        if let Some(action) = travel_to(ctx, &asteroid_field)? {
+            return Ok(action);
+        }
+        orbit(ctx)?;
+        let extraction_data = ctx.api.extract_resources(&ctx.ship.symbol, None)?;
+        ctx.ship.cargo = extraction_data.cargo;
+
+        // Anything other than the contract's good only takes up room.
+        for cargo_item in ctx.ship.cargo.inventory.clone() {
+            if cargo_item.symbol != delivery.trade_symbol {
+                ctx.ship.cargo = ctx.api.jettison_cargo(
+                    &ctx.ship.symbol,
+                    &cargo_item.symbol,
+                    u32::from(cargo_item.units),
+                )?;
+            }
+        }
+        Ok(Action::WaitUntil(scheduler::cooldown_expiration(
+            &extraction_data.cooldown,
+        )))
+    }
+
+    fn deliver(&mut self, ctx: &mut ShipContext) -> ApiResult<Action> {
+        let (Some(contract_id), Some(delivery)) = (
+            self.contract.as_ref().map(|contract| contract.id.clone()),
+            self.current_delivery(),
+        ) else {
+            self.step = ContractStep::Fulfill;
+            return Ok(Action::Continue);
+        };
+
+        if let Some(action) = travel_to(ctx, &delivery.destination_symbol)? {
+            return Ok(action);
+        }
+        dock(ctx)?;
+
+        let units = held_units(ctx, &delivery.trade_symbol).min(remaining_units(&delivery));
+        if units > 0 {
+            let delivery_data = ctx.api.deliver_contract(
+                &contract_id,
+                &ctx.ship.symbol,
+                &delivery.trade_symbol,
+                units,
+            )?;
+            self.contract = Some(delivery_data.contract);
+            ctx.ship.cargo = delivery_data.cargo;
+        }
+        self.step = ContractStep::Source;
+        Ok(Action::Continue)
+    }
+
+    fn fulfill(&mut self, ctx: &mut ShipContext) -> ApiResult<Action> {
+        if let Some(ref contract) = self.contract {
+            ctx.api.fulfill_contract(&contract.id)?;
+        }
+        self.contract = None;
+        self.step = ContractStep::FindContract;
+        Ok(Action::Continue)
+    }
+
+    /// Gets the first delivery of the current contract that still needs goods.
+    fn current_delivery(&self) -> Option<DeliveryInfo> {
+        self.contract
+            .as_ref()?
+            .terms
+            .deliver
+            .iter()
+            .find(|delivery| remaining_units(delivery) > 0)
+            .cloned()
+    }
+}
+
+impl Behavior for ContractBehavior {
+    fn name(&self) -> &'static str {
+        CONTRACT_BEHAVIOR_NAME
+    }
+
+    fn tick(&mut self, ctx: &mut ShipContext) -> ApiResult<Action> {
+        match self.step {
+            ContractStep::FindContract => self.find_contract(ctx),
+            ContractStep::Source => self.source(ctx),
+            ContractStep::Deliver => self.deliver(ctx),
+            ContractStep::Fulfill => self.fulfill(ctx),
+        }
+    }
+
+    fn save_state(&self) -> serde_json::Value {
+        serde_json::to_value(self).unwrap_or_default()
+    }
+}
+
+fn remaining_units(delivery: &DeliveryInfo) -> u32 {
+    (delivery.units_required - delivery.units_fulfilled).max(0) as u32
+}
+
+fn held_units(ctx: &ShipContext, trade_symbol: &TradeSymbol) -> u32 {
+    ctx.ship
+        .cargo
+        .inventory
+        .iter()
+        .filter(|cargo_item| cargo_item.symbol == *trade_symbol)
+        .map(|cargo_item| u32::from(cargo_item.units))
+        .sum()
+}
+
+/// Checks whether a contract can still be worked on: its offer hasn't expired, unless it was already accepted,
+/// and its deadline hasn't passed.
+///
+/// * `contract` - [`ContractData`] of the contract.
+/// * `now` - the current time.
+fn is_open(contract: &ContractData, now: DateTime<Utc>) -> bool {
+    let offer_open = contract
+        .accept_deadline()
+        .is_none_or(|accept_deadline| accept_deadline > now);
+    (contract.accepted || offer_open) && contract.terms.deadline > now
+}
+
+fn total_payment(contract: &ContractData) -> i64 {
+    contract.terms.payment.on_accepted + contract.terms.payment.on_fulfilled
+}
+
+/// Estimates what a contract earns after buying its goods at the cheapest known markets in a system.
+///
+/// * `contract` - [`ContractData`] of the contract.
+/// * `price_records` - latest known prices of goods at markets.
+/// * `system` - symbol of the system the goods are bought in.
+///
+/// Returns the expected profit, or [`Option::None`] if no known market in the system sells one of the goods.
+fn estimated_profit(
+    contract: &ContractData,
+    price_records: &[PriceRecord],
+    system: &str,
+) -> Option<i64> {
+    let mut sourcing_cost = 0;
+    for delivery in &contract.terms.deliver {
+        let cheapest_price = price_records
+            .iter()
+            .filter(|record| {
+                record.trade_symbol == delivery.trade_symbol
+                    && system_of(&record.waypoint_symbol) == system
+            })
+            .map(|record| i64::from(record.purchase_price))
+            .min()?;
+        sourcing_cost += i64::from(remaining_units(delivery)) * cheapest_price;
+    }
+    Some(total_payment(contract) - sourcing_cost)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{estimated_profit, is_open};
+    use crate::{
+        api::data::{
+            ContractData, ContractTerms, DeliveryInfo, PaymentInfo, TradeSymbol, UnknownFields,
+        },
+        history::PriceRecord,
+    };
+
+    use chrono::{Duration, TimeZone, Utc};
+
+    fn price_record(waypoint: &str, good: &str, purchase: u32) -> PriceRecord {
+        PriceRecord {
+            timestamp: 0,
+            waypoint_symbol: String::from(waypoint),
+            trade_symbol: TradeSymbol::from(good),
+            purchase_price: purchase,
+            sell_price: purchase - 2,
+            supply: String::from("MODERATE"),
+            trade_volume: 10,
+            agent: None,
+        }
+    }
+
+    fn contract() -> ContractData {
+        ContractData {
+            id: String::from("TEST-CONTRACT"),
+            faction_symbol: String::from("COSMIC"),
+            r#type: String::from("PROCUREMENT"),
+            terms: ContractTerms {
+                deadline: Utc.with_ymd_and_hms(2023, 5, 27, 0, 0, 0).unwrap(),
+                payment: PaymentInfo {
+                    on_accepted: 1000,
+                    on_fulfilled: 5000,
+                    extra: UnknownFields::default(),
+                },
+                deliver: vec![DeliveryInfo {
+                    trade_symbol: TradeSymbol::IronOre,
+                    destination_symbol: String::from("X1-TEST-A1"),
+                    units_required: 100,
+                    units_fulfilled: 20,
+                    extra: UnknownFields::default(),
+                }],
+                extra: UnknownFields::default(),
+            },
+            accepted: false,
+            fulfilled: false,
+            expiration: None,
+            deadline_to_accept: Some(Utc.with_ymd_and_hms(2023, 5, 21, 0, 0, 0).unwrap()),
+            extra: UnknownFields::default(),
+        }
+    }
+
+    #[test]
+    fn verify_estimated_profit() {
+        // Setup
+        let contract = contract();
+        let price_records = vec![
+            price_record("X1-TEST-A1", "IRON_ORE", 50),
+            price_record("X1-TEST-B2", "IRON_ORE", 40),
+            price_record("X1-OTHER-C3", "IRON_ORE", 10),
+        ];
+
+        // Test
+        let profit = estimated_profit(&contract, &price_records, "X1-TEST");
+        let unknown_profit = estimated_profit(&contract, &price_records, "X1-NONE");
+
+        // Verify
+        assert_eq!(profit, Some(6000 - 80 * 40));
+        assert_eq!(unknown_profit, None);
+    }
+
+    #[test]
+    fn verify_open_contracts() {
+        // Setup
+        let now = Utc.with_ymd_and_hms(2023, 5, 22, 0, 0, 0).unwrap();
+        let mut contract = contract();
+        contract.deadline_to_accept = Some(now + Duration::days(1));
+        contract.terms.deadline = now + Duration::days(7);
+        // Older responses only have the deprecated expiration.
+        let mut expired_offer = contract.clone();
+        expired_offer.deadline_to_accept = None;
+        expired_offer.expiration = Some(now - Duration::days(1));
+        let mut accepted = expired_offer.clone();
+        accepted.accepted = true;
+        let mut overdue = accepted.clone();
+        overdue.terms.deadline = now - Duration::hours(1);
+
+        // Test
+        let open = [&contract, &expired_offer, &accepted, &overdue]
+            .map(|candidate| is_open(candidate, now));
+
+        // Verify
+        assert_eq!(open, [true, false, true, false]);
+    }
+}
@@ -0,0 +1,373 @@
+use super::{dock, orbit, travel_to, Action, Behavior, ShipContext};
+use crate::{
+    api::{
+        client::{system_of, ApiResult},
+        data::{
+            without_schema_drift, CargoInfo, Survey, TradeSymbol, WaypointSymbol,
+            WaypointTraitSymbol,
+        },
+    },
+    history::{self, PriceRecord},
+    scheduler,
+};
+
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+/// Name the mining behavior is saved under.
+pub const MINING_BEHAVIOR_NAME: &str = "mining";
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Deserialize, Serialize)]
+enum MiningStep {
+    GoToField,
+    Extract,
+    GoToMarket,
+    Sell,
+}
+
+/// Mines an asteroid field until the cargo hold is full, sells the haul at the best-paying market, refuels
+/// and heads back to the field. Goods the market doesn't buy are sold at the next best market, or jettisoned
+/// if no known market buys them.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct MiningBehavior {
+    /// Symbol of the asteroid field to mine.
+    pub asteroid_field: WaypointSymbol,
+    /// Goods that sell for less than this per unit at every known market are jettisoned after extraction.
+    pub min_sell_price: u32,
+    step: MiningStep,
+    survey: Option<Survey>,
+    market: Option<WaypointSymbol>,
+}
+
+impl MiningBehavior {
+    /// Creates a [`MiningBehavior`] starting with a trip to the asteroid field.
+    ///
+    /// * `asteroid_field` - symbol of the asteroid field to mine.
+    /// * `min_sell_price` - per-unit price below which extracted goods are jettisoned.
+    pub fn new(asteroid_field: &str, min_sell_price: u32) -> Self {
+        Self {
+            asteroid_field: asteroid_field.to_string(),
+            min_sell_price,
+            step: MiningStep::GoToField,
+            survey: None,
+            market: None,
+        }
+    }
+
+    /// [`BehaviorLoader`](super::BehaviorLoader) for restoring a saved [`MiningBehavior`].
+    pub fn load(state: serde_json::Value) -> Option<Box<dyn Behavior>> {
+        without_schema_drift(|| serde_json::from_value::<Self>(state))
+            .ok()
+            .map(|behavior| Box::new(behavior) as Box<dyn Behavior>)
+    }
+
+    fn extract(&mut self, ctx: &mut ShipContext) -> ApiResult<Action> {
+        if ctx.ship.cargo.units >= ctx.ship.cargo.capacity {
+            self.step = MiningStep::GoToMarket;
+            return Ok(Action::Continue);
+        }
+        orbit(ctx)?;
+
+        let has_surveyor = ctx
+            .ship
+            .mounts
+            .iter()
+            .any(|mount| mount.component_info.symbol.is_surveyor());
+        if self.survey.is_none() && has_surveyor {
+            let survey_data = ctx.api.create_survey(&ctx.ship.symbol)?;
+            self.survey = survey_data
+                .surveys
+                .into_iter()
+                .max_by_key(|survey| survey.deposits.len());
+            return Ok(Action::WaitUntil(scheduler::cooldown_expiration(
+                &survey_data.cooldown,
+            )));
+        }
+
+        let extraction_data = match ctx
+            .api
+            .extract_resources(&ctx.ship.symbol, self.survey.as_ref())
+        {
+            Ok(extraction_data) => extraction_data,
+            Err(e) => {
+                // Surveys expire or get exhausted, so start over with a fresh one.
+                self.survey = None;
+                return Err(e);
+            }
+        };
+        ctx.ship.cargo = extraction_data.cargo;
+
+        let system = system_of(&self.asteroid_field);
+        let price_records = history::latest_prices();
+        for (trade_symbol, units) in low_value_goods(
+            &ctx.ship.cargo,
+            &price_records,
+            &system,
+            self.min_sell_price,
+        ) {
+            ctx.ship.cargo = ctx
+                .api
+                .jettison_cargo(&ctx.ship.symbol, &trade_symbol, units)?;
+        }
+        Ok(Action::WaitUntil(scheduler::cooldown_expiration(
+            &extraction_data.cooldown,
+        )))
+    }
+
+    fn go_to_market(&mut self, ctx: &mut ShipContext) -> ApiResult<Action> {
+        let market = match self.market {
+            Some(ref market) => market.clone(),
+            None => {
+                let system = system_of(&ctx.ship.nav.waypoint_symbol);
+                let market = match best_market(&ctx.ship.cargo, &history::latest_prices(), &system)
+                {
+                    Some(market) => Some(market),
+                    None => nearest_marketplace(ctx)?,
+                };
+                let Some(market) = market else {
+                    return Ok(Action::Stop("no marketplace found to sell cargo at"));
+                };
+                self.market = Some(market.clone());
+                market
+            }
+        };
+
+        if let Some(action) = travel_to(ctx, &market)? {
+            return Ok(action);
+        }
+        self.step = MiningStep::Sell;
+        Ok(Action::Continue)
+    }
+
+    fn sell(&mut self, ctx: &mut ShipContext) -> ApiResult<Action> {
+        dock(ctx)?;
+        let market_data = ctx.api.get_market(&ctx.ship.nav.waypoint_symbol)?;
+        let trade_goods = market_data.trade_goods.unwrap_or_default();
+
+        for cargo_item in ctx.ship.cargo.inventory.clone() {
+            let Some(trade_good) = trade_goods
+                .iter()
+                .find(|trade_good| trade_good.symbol == cargo_item.symbol)
+            else {
+                continue;
+            };
+
+            // Markets cap how many units change hands in a single transaction.
+            let mut remaining_units = u32::from(cargo_item.units);
+            while remaining_units > 0 {
+                let units = remaining_units.min(trade_good.trade_volume.max(1));
+                let trade_data = ctx
+                    .api
+                    .sell_cargo(&ctx.ship.symbol, &cargo_item.symbol, units)?;
+                ctx.ship.cargo = trade_data.cargo;
+                remaining_units -= units;
+            }
+        }
+
+        let sells_fuel = trade_goods
+            .iter()
+            .any(|trade_good| trade_good.symbol == TradeSymbol::Fuel);
+        if sells_fuel && ctx.ship.fuel.current < ctx.ship.fuel.capacity {
+            ctx.ship.fuel = ctx.api.refuel_ship(&ctx.ship.symbol, None, false)?.fuel;
+        }
+
+        // Goods this market doesn't buy are taken to the next best market, or thrown out if no known market
+        // buys them, so they don't send the ship back here forever.
+        let system = system_of(&ctx.ship.nav.waypoint_symbol);
+        let next_market = best_market(&ctx.ship.cargo, &history::latest_prices(), &system)
+            .filter(|market| *market != ctx.ship.nav.waypoint_symbol);
+        if next_market.is_some() {
+            self.market = next_market;
+            self.step = MiningStep::GoToMarket;
+            return Ok(Action::Continue);
+        }
+        for cargo_item in ctx.ship.cargo.inventory.clone() {
+            ctx.ship.cargo = ctx.api.jettison_cargo(
+                &ctx.ship.symbol,
+                &cargo_item.symbol,
+                cargo_item.units.into(),
+            )?;
+        }
+
+        self.market = None;
+        self.step = MiningStep::GoToField;
+        Ok(Action::Continue)
+    }
+}
+
+impl Behavior for MiningBehavior {
+    fn name(&self) -> &'static str {
+        MINING_BEHAVIOR_NAME
+    }
+
+    fn tick(&mut self, ctx: &mut ShipContext) -> ApiResult<Action> {
+        match self.step {
+            MiningStep::GoToField => {
+                if let Some(action) = travel_to(ctx, &self.asteroid_field)? {
+                    return Ok(action);
+                }
+                self.step = MiningStep::Extract;
+                Ok(Action::Continue)
+            }
+            MiningStep::Extract => self.extract(ctx),
+            MiningStep::GoToMarket => self.go_to_market(ctx),
+            MiningStep::Sell => self.sell(ctx),
+        }
+    }
+
+    fn save_state(&self) -> serde_json::Value {
+        serde_json::to_value(self).unwrap_or_default()
+    }
+}
+
+/// Gets the best known sell price of every good at markets in a system.
+fn best_sell_prices<'a>(
+    price_records: &'a [PriceRecord],
+    system: &str,
+) -> HashMap<&'a TradeSymbol, u32> {
+    let mut best_prices = HashMap::new();
+    for record in price_records
+        .iter()
+        .filter(|record| system_of(&record.waypoint_symbol) == system)
+    {
+        let best_price = best_prices.entry(&record.trade_symbol).or_insert(0);
+        *best_price = record.sell_price.max(*best_price);
+    }
+    best_prices
+}
+
+/// Gets the goods in a cargo hold that aren't worth selling at any known market in the system.
+///
+/// Goods that no known market buys are kept, since their value is unknown rather than low.
+///
+/// Returns the symbol and units of each good to jettison.
+fn low_value_goods(
+    cargo: &CargoInfo,
+    price_records: &[PriceRecord],
+    system: &str,
+    min_sell_price: u32,
+) -> Vec<(TradeSymbol, u32)> {
+    let best_prices = best_sell_prices(price_records, system);
+    cargo
+        .inventory
+        .iter()
+        .filter(|cargo_item| {
+            best_prices
+                .get(&cargo_item.symbol)
+                .is_some_and(|best_price| *best_price < min_sell_price)
+        })
+        .map(|cargo_item| (cargo_item.symbol.clone(), u32::from(cargo_item.units)))
+        .collect()
+}
+
+/// Gets the known market in the system that pays the most for a cargo hold's contents.
+fn best_market(
+    cargo: &CargoInfo,
+    price_records: &[PriceRecord],
+    system: &str,
+) -> Option<WaypointSymbol> {
+    let mut market_revenues: HashMap<&str, u64> = HashMap::new();
+    for record in price_records
+        .iter()
+        .filter(|record| system_of(&record.waypoint_symbol) == system)
+    {
+        if let Some(cargo_item) = cargo
+            .inventory
+            .iter()
+            .find(|cargo_item| cargo_item.symbol == record.trade_symbol)
+        {
+            *market_revenues
+                .entry(record.waypoint_symbol.as_str())
+                .or_default() += u64::from(cargo_item.units) * u64::from(record.sell_price);
+        }
+    }
+
+    market_revenues
+        .into_iter()
+        .filter(|(_, revenue)| *revenue > 0)
+        .max_by_key(|(_, revenue)| *revenue)
+        .map(|(market, _)| market.to_string())
+}
+
+/// Finds the closest marketplace to the ship, for when no market prices have been recorded yet.
+fn nearest_marketplace(ctx: &mut ShipContext) -> ApiResult<Option<WaypointSymbol>> {
+    let waypoints = ctx.api.list_waypoints(&ctx.ship.nav.system_symbol)?;
+    let Some(ship_location) = waypoints
+        .iter()
+        .find(|location_data| location_data.symbol == ctx.ship.nav.waypoint_symbol)
+    else {
+        return Ok(None);
+    };
+
+    Ok(waypoints
+        .iter()
+        .filter(|location_data| location_data.has_trait(&WaypointTraitSymbol::Marketplace))
+        .min_by(|a, b| {
+            let a_distance = ship_location.coords.distance_to(&a.coords);
+            let b_distance = ship_location.coords.distance_to(&b.coords);
+            a_distance.total_cmp(&b_distance)
+        })
+        .map(|location_data| location_data.symbol.clone()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{best_market, low_value_goods};
+    use crate::{
+        api::data::{CargoInfo, CargoItem, TradeSymbol, UnknownFields},
+        history::PriceRecord,
+    };
+
+    fn cargo_item(symbol: &str, units: u16) -> CargoItem {
+        CargoItem {
+            symbol: TradeSymbol::from(symbol),
+            name: String::from(symbol),
+            description: String::new(),
+            units,
+            extra: UnknownFields::default(),
+        }
+    }
+
+    fn price_record(waypoint: &str, good: &str, sell: u32) -> PriceRecord {
+        PriceRecord {
+            timestamp: 0,
+            waypoint_symbol: String::from(waypoint),
+            trade_symbol: TradeSymbol::from(good),
+            purchase_price: sell + 2,
+            sell_price: sell,
+            supply: String::from("MODERATE"),
+            trade_volume: 10,
+            agent: None,
+        }
+    }
+
+    #[test]
+    fn verify_market_selection_and_jettison() {
+        // Setup
+        let cargo = CargoInfo {
+            capacity: 30,
+            units: 30,
+            inventory: vec![
+                cargo_item("IRON_ORE", 10),
+                cargo_item("ICE_WATER", 15),
+                cargo_item("QUARTZ_SAND", 5),
+            ],
+            extra: UnknownFields::default(),
+        };
+        let price_records = vec![
+            price_record("X1-TEST-A1", "IRON_ORE", 40),
+            price_record("X1-TEST-A1", "ICE_WATER", 3),
+            price_record("X1-TEST-B2", "ICE_WATER", 8),
+            price_record("X1-TEST-B2", "IRON_ORE", 20),
+            price_record("X1-OTHER-C3", "IRON_ORE", 1000),
+        ];
+
+        // Test
+        let market = best_market(&cargo, &price_records, "X1-TEST");
+        let jettisoned = low_value_goods(&cargo, &price_records, "X1-TEST", 10);
+
+        // Verify
+        assert_eq!(market.as_deref(), Some("X1-TEST-A1"));
+        assert_eq!(jettisoned, vec![(TradeSymbol::IceWater, 15)]);
+    }
+}
@@ -0,0 +1,740 @@
+pub mod contracts;
+pub mod mining;
+
+use crate::{
+    api::{
+        client::{system_of, ApiError, ApiResult, TraderApis},
+        data::{FlightMode, ShipData, WaypointType},
+    },
+    history, ledger, nav,
+    scheduler::{self, Scheduler},
+    utils,
+};
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::{
+    collections::HashMap,
+    fmt, fs,
+    path::{Path, PathBuf},
+    time::Duration,
+};
+
+const AUTOMATION_FILE_NAME: &str = "automation.json";
+
+/// How long a ship waits before retrying after its behavior fails.
+const ERROR_RETRY_DELAY: Duration = Duration::from_secs(30);
+
+/// How long to wait before checking again on a ship that should have arrived but is still in transit.
+const TRANSIT_POLL_DELAY: Duration = Duration::from_secs(15);
+
+/// Navigation status of a ship that is docked at its waypoint.
+pub const STATUS_DOCKED: &str = "DOCKED";
+/// Navigation status of a ship that is orbiting its waypoint.
+pub const STATUS_IN_ORBIT: &str = "IN_ORBIT";
+/// Navigation status of a ship that is travelling between waypoints.
+pub const STATUS_IN_TRANSIT: &str = "IN_TRANSIT";
+
+pub type AutomationResult<T> = Result<T, AutomationError>;
+
+#[derive(Clone, Copy, Debug)]
+pub enum AutomationError {
+    FileWrite,
+}
+
+/// What a ship should do after one of its behavior's ticks.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Action {
+    /// Tick again right away.
+    Continue,
+    /// Sleep for a while before ticking again.
+    Wait(Duration),
+    /// Sleep until a given time before ticking again, e.g. when the ship arrives or a cooldown expires.
+    WaitUntil(DateTime<Utc>),
+    /// The behavior is finished and the ship is released from automation.
+    Done,
+    /// The behavior can't go on for the given reason, so the ship is released from automation and the
+    /// [`Runner`] reports why.
+    Stop(&'static str),
+}
+
+/// Something that happened while a [`Runner`] was running, for its caller to report.
+#[derive(Debug)]
+pub enum RunnerEvent {
+    /// A ship's behavior gave up before finishing, and the ship was released from automation.
+    Stopped {
+        ship_symbol: String,
+        behavior: &'static str,
+        reason: &'static str,
+    },
+    /// A step of a ship's behavior failed, and will be retried after a while.
+    Failed {
+        ship_symbol: String,
+        behavior: &'static str,
+        error: ApiError,
+    },
+    /// The state of the behaviors couldn't be saved. The ships keep going regardless.
+    SaveFailed(AutomationError),
+}
+
+impl fmt::Display for RunnerEvent {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            RunnerEvent::Stopped {
+                ship_symbol,
+                behavior,
+                reason,
+            } => write!(f, "{ship_symbol} ({behavior}) stopped: {reason}"),
+            RunnerEvent::Failed {
+                ship_symbol,
+                behavior,
+                error,
+            } => write!(f, "{ship_symbol} ({behavior}) failed: {error}"),
+            RunnerEvent::SaveFailed(e) => write!(f, "Error saving automation state: {e:?}"),
+        }
+    }
+}
+
+/// Everything a [`Behavior`] can see and use while deciding what its ship does next.
+pub struct ShipContext<'a> {
+    /// Latest known state of the ship. Behaviors should update it with the results of their requests.
+    pub ship: &'a mut ShipData,
+    pub api: &'a dyn TraderApis,
+}
+
+/// A strategy a single ship follows, advanced one step at a time by a [`Runner`].
+///
+/// Runners of different agents run on threads of their own, so behaviors must be able to move between threads.
+pub trait Behavior: Send {
+    /// Unique name of the behavior, used to restore it after a restart.
+    fn name(&self) -> &'static str;
+
+    /// Performs the next step of the behavior.
+    ///
+    /// * `ctx` - [`ShipContext`] of the ship following the behavior.
+    ///
+    /// Returns the [`Action`] the ship should take next, or the [`ApiError`](crate::api::client::ApiError)
+    /// reason the step failed.
+    fn tick(&mut self, ctx: &mut ShipContext) -> ApiResult<Action>;
+
+    /// Serializes whatever the behavior needs to pick up where it left off.
+    fn save_state(&self) -> serde_json::Value;
+}
+
+/// Recreates a [`Behavior`] from the state it saved, or returns [`Option::None`] if the state is unusable.
+pub type BehaviorLoader = fn(serde_json::Value) -> Option<Box<dyn Behavior>>;
+
+/// Persisted form of a ship's assigned behavior.
+#[derive(Clone, Debug, Eq, PartialEq, Deserialize, Serialize)]
+pub struct SavedBehavior {
+    pub name: String,
+    pub state: serde_json::Value,
+}
+
+struct Assignment {
+    ship: ShipData,
+    behavior: Box<dyn Behavior>,
+}
+
+/// Runs the behaviors assigned to ships of the fleet, persisting their state after every step.
+pub struct Runner<'a> {
+    api: &'a dyn TraderApis,
+    assignments: HashMap<String, Assignment>,
+    /// When each assigned ship is next due to tick.
+    scheduler: Scheduler<String>,
+    loaders: HashMap<&'static str, BehaviorLoader>,
+    /// Behaviors saved by a previous run that haven't been restored or replaced yet. They're written back with the
+    /// assigned ones, so they aren't lost before their loader is registered.
+    saved: HashMap<String, SavedBehavior>,
+    state_file_path: PathBuf,
+    /// Receives every [`RunnerEvent`]. Events are dropped unless one is set.
+    event_handler: Box<dyn FnMut(RunnerEvent) + Send + 'a>,
+}
+
+impl<'a> Runner<'a> {
+    /// Creates a [`Runner`] that persists behavior state to the default automation file.
+    ///
+    /// * `api` - client used by the behaviors.
+    pub fn new(api: &'a dyn TraderApis) -> Self {
+        Self::with_state_file(api, utils::get_app_file_path(AUTOMATION_FILE_NAME))
+    }
+
+    /// Creates a [`Runner`] for one of the profiles in the config, persisting behavior state to a file of its own
+    /// so agents run from the same process don't overwrite each other.
+    ///
+    /// * `api` - client for the profile's agent, used by the behaviors.
+    /// * `profile_name` - name of the profile.
+    pub fn for_profile(api: &'a dyn TraderApis, profile_name: &str) -> Self {
+        Self::with_state_file(
+            api,
+            utils::get_app_file_path(&format!("automation.{profile_name}.json")),
+        )
+    }
+
+    /// Creates a [`Runner`] persisting behavior state to a given file, holding on to the behaviors already saved
+    /// there until they're restored.
+    ///
+    /// * `api` - client used by the behaviors.
+    /// * `state_file_path` - [`PathBuf`] path to the automation file.
+    fn with_state_file(api: &'a dyn TraderApis, state_file_path: PathBuf) -> Self {
+        Self {
+            api,
+            assignments: HashMap::new(),
+            scheduler: Scheduler::new(),
+            loaders: HashMap::new(),
+            saved: read_automation_file(&state_file_path),
+            state_file_path,
+            event_handler: Box::new(|_| {}),
+        }
+    }
+
+    /// Sets what is done with the [`RunnerEvent`]s of this runner, such as failed steps, e.g. showing them to
+    /// the user.
+    ///
+    /// * `event_handler` - called with each event as it happens.
+    pub fn set_event_handler(&mut self, event_handler: impl FnMut(RunnerEvent) + Send + 'a) {
+        self.event_handler = Box::new(event_handler);
+    }
+
+    /// Registers how to restore a kind of behavior from its saved state.
+    ///
+    /// * `name` - [`Behavior::name`] of the behavior.
+    /// * `loader` - [`BehaviorLoader`] recreating the behavior.
+    pub fn register(&mut self, name: &'static str, loader: BehaviorLoader) {
+        self.loaders.insert(name, loader);
+    }
+
+    /// Assigns a behavior to a ship, replacing any behavior it had.
+    ///
+    /// * `ship` - [`ShipData`] of the ship.
+    /// * `behavior` - the [`Behavior`] to follow.
+    pub fn assign(&mut self, ship: ShipData, behavior: Box<dyn Behavior>) {
+        self.saved.remove(&ship.symbol);
+        self.insert_assignment(ship, behavior);
+        self.persist();
+    }
+
+    /// Assigns behaviors to ships of the fleet.
+    ///
+    /// * `choose_behavior` - picks the [`Behavior`] for a ship, or [`Option::None`] to leave it alone.
+    ///
+    /// Returns the number of ships assigned a behavior, or the [`ApiError`](crate::api::client::ApiError)
+    /// reason the fleet couldn't be listed.
+    pub fn assign_fleet(
+        &mut self,
+        choose_behavior: impl Fn(&ShipData) -> Option<Box<dyn Behavior>>,
+    ) -> ApiResult<usize> {
+        let mut assigned_count = 0;
+        for ship in self.api.list_ships()? {
+            if let Some(behavior) = choose_behavior(&ship) {
+                self.assign(ship, behavior);
+                assigned_count += 1;
+            }
+        }
+        Ok(assigned_count)
+    }
+
+    /// Restores the behaviors saved by a previous run. Behaviors without a registered loader are kept saved, so
+    /// they can be restored once their loader is registered, while ones whose state is unusable are dropped.
+    ///
+    /// Returns the number of ships restored, or the [`ApiError`](crate::api::client::ApiError) reason their
+    /// latest data couldn't be fetched.
+    pub fn restore(&mut self) -> ApiResult<usize> {
+        let mut restored_count = 0;
+        let ship_symbols: Vec<String> = self.saved.keys().cloned().collect();
+        for ship_symbol in ship_symbols {
+            let saved_behavior = &self.saved[&ship_symbol];
+            let Some(loader) = self.loaders.get(saved_behavior.name.as_str()) else {
+                continue;
+            };
+            if let Some(behavior) = loader(saved_behavior.state.clone()) {
+                let ship = self.api.get_ship(&ship_symbol)?;
+                self.insert_assignment(ship, behavior);
+                restored_count += 1;
+            }
+            self.saved.remove(&ship_symbol);
+        }
+
+        // Only overwrite the saved state once every ship is back, so a failed restore can be retried.
+        self.persist();
+        Ok(restored_count)
+    }
+
+    /// Runs the assigned behaviors until all of them are done, sleeping whenever no ship has anything to do.
+    pub fn run(&mut self) {
+        while let Some(ship_symbol) = self.scheduler.wait_next() {
+            self.tick_ship(&ship_symbol);
+        }
+    }
+
+    /// Advances every ship that is due, without waiting for the others.
+    ///
+    /// Returns the number of ships advanced.
+    pub fn run_due(&mut self) -> usize {
+        let due_ships = self.scheduler.pop_due(Utc::now());
+        for ship_symbol in &due_ships {
+            self.tick_ship(ship_symbol);
+        }
+        due_ships.len()
+    }
+
+    /// Gets when the next ship is due to tick, if any ships are still assigned a behavior.
+    pub fn next_due(&self) -> Option<DateTime<Utc>> {
+        self.scheduler.next_due()
+    }
+
+    /// Gets the number of ships currently assigned a behavior.
+    pub fn assigned_count(&self) -> usize {
+        self.assignments.len()
+    }
+
+    /// Advances the behavior of a single ship by one step and schedules its next one.
+    ///
+    /// * `ship_symbol` - symbol of the ship.
+    fn tick_ship(&mut self, ship_symbol: &str) {
+        let Some(assignment) = self.assignments.get_mut(ship_symbol) else {
+            return;
+        };
+
+        let mut ctx = ShipContext {
+            ship: &mut assignment.ship,
+            api: self.api,
+        };
+        let ship_symbol = ship_symbol.to_string();
+        let behavior = &mut assignment.behavior;
+        let tick_result = ledger::with_behavior(behavior.name(), || behavior.tick(&mut ctx));
+        match tick_result {
+            Ok(Action::Continue) => self.scheduler.schedule(Utc::now(), ship_symbol),
+            Ok(Action::Wait(delay)) => self.scheduler.schedule_in(delay, ship_symbol),
+            Ok(Action::WaitUntil(due)) => self.scheduler.schedule(due, ship_symbol),
+            Ok(Action::Done) => {
+                self.assignments.remove(&ship_symbol);
+            }
+            Ok(Action::Stop(reason)) => {
+                let behavior = assignment.behavior.name();
+                self.assignments.remove(&ship_symbol);
+                (self.event_handler)(RunnerEvent::Stopped {
+                    ship_symbol,
+                    behavior,
+                    reason,
+                });
+            }
+            Err(error) => {
+                (self.event_handler)(RunnerEvent::Failed {
+                    ship_symbol: ship_symbol.clone(),
+                    behavior: assignment.behavior.name(),
+                    error,
+                });
+                self.scheduler.schedule_in(ERROR_RETRY_DELAY, ship_symbol);
+            }
+        }
+        self.persist();
+    }
+
+    fn insert_assignment(&mut self, ship: ShipData, behavior: Box<dyn Behavior>) {
+        let ship_symbol = ship.symbol.clone();
+        self.scheduler
+            .cancel(|scheduled_symbol| *scheduled_symbol == ship_symbol);
+        self.scheduler.schedule(Utc::now(), ship_symbol.clone());
+        self.assignments
+            .insert(ship_symbol, Assignment { ship, behavior });
+    }
+
+    /// Saves the state of every assigned behavior, along with the saved ones that haven't been restored. Failures
+    /// are reported but don't stop the ships.
+    fn persist(&mut self) {
+        let mut saved_behaviors = self.saved.clone();
+        saved_behaviors.extend(self.assignments.iter().map(|(ship_symbol, assignment)| {
+            (
+                ship_symbol.clone(),
+                SavedBehavior {
+                    name: assignment.behavior.name().to_string(),
+                    state: assignment.behavior.save_state(),
+                },
+            )
+        }));
+        if let Err(e) = write_automation_file(&saved_behaviors, &self.state_file_path) {
+            (self.event_handler)(RunnerEvent::SaveFailed(e));
+        }
+    }
+}
+
+/// Docks the ship if it isn't already docked.
+///
+/// * `ctx` - [`ShipContext`] of the ship.
+///
+/// Returns unit on success, or the [`ApiError`](crate::api::client::ApiError) reason for failure.
+pub fn dock(ctx: &mut ShipContext) -> ApiResult<()> {
+    if ctx.ship.nav.status != STATUS_DOCKED {
+        ctx.ship.nav = ctx.api.dock_ship(&ctx.ship.symbol)?;
+    }
+    Ok(())
+}
+
+/// Moves the ship into orbit if it isn't already orbiting.
+///
+/// * `ctx` - [`ShipContext`] of the ship.
+///
+/// Returns unit on success, or the [`ApiError`](crate::api::client::ApiError) reason for failure.
+pub fn orbit(ctx: &mut ShipContext) -> ApiResult<()> {
+    if ctx.ship.nav.status != STATUS_IN_ORBIT {
+        ctx.ship.nav = ctx.api.orbit_ship(&ctx.ship.symbol)?;
+    }
+    Ok(())
+}
+
+/// Sends the ship to a waypoint, unless it is already there.
+///
+/// Waypoints in the ship's system are reached with the fastest route its fuel allows, stopping to refuel at
+/// known fuel stations, while waypoints in other systems are reached through the fewest jump gates. Either way
+/// the ship takes one step per call.
+///
+/// * `ctx` - [`ShipContext`] of the ship.
+/// * `waypoint` - symbol of the destination waypoint.
+///
+/// Returns the [`Action`] waiting for the ship to arrive, [`Option::None`] if it already has, or the
+/// [`ApiError`](crate::api::client::ApiError) reason for failure.
+pub fn travel_to(ctx: &mut ShipContext, waypoint: &str) -> ApiResult<Option<Action>> {
+    if ctx.ship.nav.status == STATUS_IN_TRANSIT {
+        if let Some(arrival) = ctx
+            .ship
+            .nav
+            .route
+            .arrival
+            .filter(|arrival| *arrival > Utc::now())
+        {
+            return Ok(Some(Action::WaitUntil(arrival)));
+        }
+
+        // The ship should be there by now, so refresh its status.
+        *ctx.ship = ctx.api.get_ship(&ctx.ship.symbol)?;
+        if ctx.ship.nav.status == STATUS_IN_TRANSIT {
+            return Ok(Some(Action::Wait(TRANSIT_POLL_DELAY)));
+        }
+    }
+    if ctx.ship.nav.waypoint_symbol == waypoint {
+        return Ok(None);
+    }
+    if system_of(waypoint) != ctx.ship.nav.system_symbol {
+        return jump_towards(ctx, waypoint);
+    }
+
+    // Long trips are split at fuel stations, so only the next leg is flown and the rest is planned on arrival.
+    let waypoints = ctx.api.list_waypoints(&ctx.ship.nav.system_symbol)?;
+    let fuel_stations = nav::fuel_stations(&waypoints, &history::latest_prices());
+    let leg = nav::plan_route(ctx.ship, waypoint, &waypoints, &fuel_stations, false)
+        .and_then(|itinerary| itinerary.legs.into_iter().next())
+        .ok_or_else(|| ApiError::Unreachable(waypoint.to_string()))?;
+    if leg.refuel && ctx.ship.fuel.current < ctx.ship.fuel.capacity {
+        dock(ctx)?;
+        ctx.ship.fuel = ctx.api.refuel_ship(&ctx.ship.symbol, None, false)?.fuel;
+    }
+
+    orbit(ctx)?;
+    if ctx.ship.nav.flight_mode != leg.flight_mode.to_string() {
+        ctx.ship.nav = ctx.api.set_flight_mode(&ctx.ship.symbol, leg.flight_mode)?;
+    }
+    let navigation_data = ctx.api.navigate_ship(&ctx.ship.symbol, &leg.to)?;
+    ctx.ship.nav = navigation_data.nav;
+    ctx.ship.fuel = navigation_data.fuel;
+    match ctx.ship.nav.route.arrival {
+        Some(arrival) => Ok(Some(Action::WaitUntil(arrival))),
+        None => Ok(Some(Action::Wait(travel_duration(ctx.ship)))),
+    }
+}
+
+/// Takes the ship one step towards a waypoint in another system: first to its own system's jump gate, then
+/// through each jump on the way to the destination system.
+fn jump_towards(ctx: &mut ShipContext, waypoint: &str) -> ApiResult<Option<Action>> {
+    // Both the system's waypoints and the gates' connections are cached, so planning doesn't repeat requests.
+    let origin_gate = ctx
+        .api
+        .list_waypoints(&ctx.ship.nav.system_symbol)?
+        .into_iter()
+        .find(|location_data| location_data.r#type == WaypointType::JumpGate)
+        .map(|location_data| location_data.symbol)
+        .ok_or_else(|| ApiError::Unreachable(waypoint.to_string()))?;
+    if ctx.ship.nav.waypoint_symbol != origin_gate {
+        return travel_to(ctx, &origin_gate);
+    }
+
+    let api = ctx.api;
+    let jumps = nav::plan_jumps(&origin_gate, &system_of(waypoint), |gate| {
+        api.get_jump_gate(gate)
+            .map(|jump_gate| jump_gate.connections)
+    })?
+    .ok_or_else(|| ApiError::Unreachable(waypoint.to_string()))?;
+    let Some(next_gate) = jumps.first() else {
+        return Ok(Some(Action::Continue));
+    };
+
+    orbit(ctx)?;
+    let jump_data = ctx.api.jump_ship(&ctx.ship.symbol, &system_of(next_gate))?;
+    ctx.ship.nav = jump_data.nav;
+    if jumps.len() > 1 {
+        // The next jump has to wait for the reactor to cool down.
+        Ok(Some(Action::WaitUntil(scheduler::cooldown_expiration(
+            &jump_data.cooldown,
+        ))))
+    } else {
+        Ok(Some(Action::Continue))
+    }
+}
+
+/// Estimates how long the ship's current trip takes based on its route, flight mode and engine, for when
+/// the API doesn't report an arrival time.
+fn travel_duration(ship: &ShipData) -> Duration {
+    let route = &ship.nav.route;
+    let distance = route.origin.coords.distance_to(&route.destination.coords);
+    let flight_mode =
+        serde_json::from_value(serde_json::Value::from(ship.nav.flight_mode.as_str()))
+            .unwrap_or(FlightMode::Cruise);
+    Duration::from_secs(flight_mode.travel_secs(distance, ship.engine.speed).into())
+}
+
+/// Middleware function for reading saved behaviors from the automation file.
+///
+/// * `automation_file_path` - [`Path`] to the automation file.
+///
+/// Returns each ship's [`SavedBehavior`], which is empty if the file cannot be read or contains no data.
+fn read_automation_file(automation_file_path: &Path) -> HashMap<String, SavedBehavior> {
+    fs::read_to_string(automation_file_path)
+        .ok()
+        .and_then(|automation_str| serde_json::from_str(&automation_str).ok())
+        .unwrap_or_default()
+}
+
+/// Middleware function for writing saved behaviors to the automation file.
+///
+/// * `saved_behaviors` - each ship's [`SavedBehavior`].
+/// * `automation_file_path` - [`Path`] to the automation file.
+///
+/// Returns [`AutomationResult`] containing unit on success, or [`AutomationError::FileWrite`] if the operation fails.
+fn write_automation_file(
+    saved_behaviors: &HashMap<String, SavedBehavior>,
+    automation_file_path: &Path,
+) -> AutomationResult<()> {
+    let automation_file =
+        fs::File::create(automation_file_path).map_err(|_| AutomationError::FileWrite)?;
+    serde_json::to_writer_pretty(automation_file, saved_behaviors)
+        .map_err(|_| AutomationError::FileWrite)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        orbit, read_automation_file, write_automation_file, Action, Behavior, Runner, RunnerEvent,
+        SavedBehavior, ShipContext, STATUS_IN_ORBIT,
+    };
+    use crate::api::{client::ApiResult, data::fixtures, stub::StubApi};
+
+    use chrono::{Duration, Utc};
+    use std::{
+        collections::{HashMap, VecDeque},
+        sync::mpsc,
+    };
+    use tempfile;
+
+    /// Behavior taking a fixed list of actions, orbiting its ship on every tick.
+    struct ScriptedBehavior {
+        actions: VecDeque<Action>,
+    }
+
+    impl Behavior for ScriptedBehavior {
+        fn name(&self) -> &'static str {
+            "scripted"
+        }
+
+        fn tick(&mut self, ctx: &mut ShipContext) -> ApiResult<Action> {
+            orbit(ctx)?;
+            Ok(self.actions.pop_front().unwrap_or(Action::Done))
+        }
+
+        fn save_state(&self) -> serde_json::Value {
+            serde_json::json!({ "actionsLeft": self.actions.len() })
+        }
+    }
+
+    fn scripted(actions: Vec<Action>) -> Box<dyn Behavior> {
+        Box::new(ScriptedBehavior {
+            actions: actions.into(),
+        })
+    }
+
+    fn load_scripted(state: serde_json::Value) -> Option<Box<dyn Behavior>> {
+        let actions_left = state["actionsLeft"].as_u64()?;
+        Some(scripted(vec![
+            Action::Continue;
+            usize::try_from(actions_left).ok()?
+        ]))
+    }
+
+    fn saved_behavior(name: &str, actions_left: usize) -> SavedBehavior {
+        SavedBehavior {
+            name: name.to_string(),
+            state: serde_json::json!({ "actionsLeft": actions_left }),
+        }
+    }
+
+    #[test]
+    fn verify_automation_file_round_trip() {
+        // Setup
+        let tmp_automation_file = tempfile::NamedTempFile::new().unwrap();
+        let saved_behaviors = HashMap::from([(
+            String::from("TEST-1"),
+            SavedBehavior {
+                name: String::from("test"),
+                state: serde_json::json!({ "step": 3 }),
+            },
+        )]);
+
+        // Test
+        write_automation_file(&saved_behaviors, tmp_automation_file.path()).unwrap();
+        let actual_behaviors = read_automation_file(tmp_automation_file.path());
+
+        // Verify
+        assert_eq!(actual_behaviors, saved_behaviors);
+    }
+
+    #[test]
+    fn verify_restore_keeps_unloaded_behaviors() {
+        // Setup
+        let tmp_automation_file = tempfile::NamedTempFile::new().unwrap();
+        let saved_behaviors = HashMap::from([
+            (String::from("TEST-1"), saved_behavior("scripted", 2)),
+            (String::from("TEST-2"), saved_behavior("unknown", 1)),
+        ]);
+        write_automation_file(&saved_behaviors, tmp_automation_file.path()).unwrap();
+        let stub_api = StubApi::new(vec![
+            fixtures::ship_data("TEST-1", "X1-TEST-A1"),
+            fixtures::ship_data("TEST-2", "X1-TEST-A1"),
+        ]);
+        let mut runner =
+            Runner::with_state_file(&stub_api, tmp_automation_file.path().to_path_buf());
+        runner.register("scripted", load_scripted);
+
+        // Test
+        let restored_count = runner.restore().unwrap();
+
+        // Verify
+        assert_eq!(restored_count, 1);
+        assert_eq!(runner.assigned_count(), 1);
+        assert_eq!(
+            read_automation_file(tmp_automation_file.path()),
+            saved_behaviors
+        );
+    }
+
+    #[test]
+    fn verify_assign_keeps_saved_behaviors() {
+        // Setup
+        let tmp_automation_file = tempfile::NamedTempFile::new().unwrap();
+        write_automation_file(
+            &HashMap::from([(String::from("TEST-1"), saved_behavior("scripted", 2))]),
+            tmp_automation_file.path(),
+        )
+        .unwrap();
+        let stub_api = StubApi::new(vec![
+            fixtures::ship_data("TEST-1", "X1-TEST-A1"),
+            fixtures::ship_data("TEST-2", "X1-TEST-A1"),
+        ]);
+        let mut runner =
+            Runner::with_state_file(&stub_api, tmp_automation_file.path().to_path_buf());
+        runner.register("scripted", load_scripted);
+
+        // Test
+        runner.assign(
+            fixtures::ship_data("TEST-2", "X1-TEST-A1"),
+            scripted(vec![Action::Continue]),
+        );
+        let saved_before_restore = read_automation_file(tmp_automation_file.path());
+        let restored_count = runner.restore().unwrap();
+
+        // Verify
+        assert_eq!(
+            saved_before_restore,
+            HashMap::from([
+                (String::from("TEST-1"), saved_behavior("scripted", 2)),
+                (String::from("TEST-2"), saved_behavior("scripted", 1)),
+            ])
+        );
+        assert_eq!(restored_count, 1);
+        assert_eq!(runner.assigned_count(), 2);
+    }
+
+    #[test]
+    fn verify_ticks_are_scheduled_until_done() {
+        // Setup
+        let tmp_automation_file = tempfile::NamedTempFile::new().unwrap();
+        let stub_api = StubApi::new(vec![
+            fixtures::ship_data("TEST-1", "X1-TEST-A1"),
+            fixtures::ship_data("TEST-2", "X1-TEST-A1"),
+        ]);
+        let mut runner =
+            Runner::with_state_file(&stub_api, tmp_automation_file.path().to_path_buf());
+        runner.assign(
+            fixtures::ship_data("TEST-1", "X1-TEST-A1"),
+            scripted(vec![Action::Continue]),
+        );
+        runner.assign(
+            fixtures::ship_data("TEST-2", "X1-TEST-A1"),
+            scripted(vec![Action::Wait(std::time::Duration::from_secs(3600))]),
+        );
+
+        // Test
+        let first_advanced_count = runner.run_due();
+        let second_advanced_count = runner.run_due();
+        let third_advanced_count = runner.run_due();
+
+        // Verify
+        assert_eq!(first_advanced_count, 2);
+        assert_eq!(second_advanced_count, 1);
+        assert_eq!(third_advanced_count, 0);
+        assert_eq!(runner.assigned_count(), 1);
+        assert_eq!(
+            runner.assignments["TEST-2"].ship.nav.status,
+            STATUS_IN_ORBIT
+        );
+        assert!(runner
+            .next_due()
+            .is_some_and(|due| due > Utc::now() + Duration::minutes(59)));
+        assert_eq!(
+            read_automation_file(tmp_automation_file.path()),
+            HashMap::from([(String::from("TEST-2"), saved_behavior("scripted", 0))])
+        );
+    }
+
+    #[test]
+    fn verify_stopped_behaviors_are_reported() {
+        // Setup
+        let tmp_automation_file = tempfile::NamedTempFile::new().unwrap();
+        let stub_api = StubApi::new(vec![fixtures::ship_data("TEST-1", "X1-TEST-A1")]);
+        let (event_sender, event_receiver) = mpsc::channel();
+        let mut runner =
+            Runner::with_state_file(&stub_api, tmp_automation_file.path().to_path_buf());
+        runner.set_event_handler(move |event| event_sender.send(event).unwrap());
+        runner.assign(
+            fixtures::ship_data("TEST-1", "X1-TEST-A1"),
+            scripted(vec![Action::Stop("nothing left to do")]),
+        );
+
+        // Test
+        runner.run_due();
+
+        // Verify
+        assert_eq!(runner.assigned_count(), 0);
+        let events: Vec<RunnerEvent> = event_receiver.try_iter().collect();
+        assert!(matches!(
+            events[..],
+            [RunnerEvent::Stopped {
+                ref ship_symbol,
+                behavior: "scripted",
+                reason: "nothing left to do",
+            }] if ship_symbol == "TEST-1"
+        ));
+        assert_eq!(
+            events[0].to_string(),
+            "TEST-1 (scripted) stopped: nothing left to do"
+        );
+    }
+}
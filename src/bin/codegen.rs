@@ -0,0 +1,21 @@
+use spacetraders_client::codegen;
+
+use std::path::Path;
+
+/// Regenerates the API module from the vendored OpenAPI document.
+///
+/// Usage: codegen [SPEC_PATH] [OUTPUT_PATH]
+fn main() {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    let spec_path = args
+        .first()
+        .map_or(codegen::DEFAULT_SPEC_PATH, String::as_str);
+    let output_path = args
+        .get(1)
+        .map_or(codegen::DEFAULT_OUTPUT_PATH, String::as_str);
+
+    match codegen::generate_file(Path::new(spec_path), Path::new(output_path)) {
+        Ok(()) => println!("Generated {output_path} from {spec_path}"),
+        Err(e) => println!("{e}"),
+    }
+}
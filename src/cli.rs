@@ -0,0 +1,263 @@
+use crate::{
+    api::{
+        client::{system_of, ApiClient, ApiError, ApiResult, TraderApis},
+        token_store::{
+            EnvTokenStore, FileTokenStore, KeyringTokenStore, TokenStore, TOKEN_ENV_VAR,
+        },
+    },
+    automation::{
+        contracts::{ContractBehavior, CONTRACT_BEHAVIOR_NAME},
+        mining::{MiningBehavior, MINING_BEHAVIOR_NAME},
+    },
+    history, ledger,
+    orchestrator::{Orchestrator, DEFAULT_PROFILE},
+    registration, trade, utils,
+};
+
+use std::{collections::HashMap, fs, io, sync::Arc};
+
+const USAGE: &str = "Usage: spacetraders_client [--store <file|keyring|env>] <command> [args]
+
+Options:
+    --store <file|keyring|env>      Keep agent tokens in the config file (default), the OS keyring, or the
+                                    SPACETRADERS_TOKEN environment variable (SPACETRADERS_TOKEN_<PROFILE> for profiles)
+
+Commands:
+    agents [run]                    Show every configured agent in one view, or resume their automation together
+    construction <WAYPOINT_SYMBOL>  Show the materials still needed to finish a construction site
+    ledger <ship|behavior|agent|hour|entries> [FILE]
+                                    Report profit per ship, behavior, agent or hour, or list every credit change, as CSV
+    factions                        List the factions recruiting new agents, with their descriptions and traits
+    register                        Register a new agent, choosing its symbol and faction step by step
+    routes <SHIP_SYMBOL> [COUNT]    List the most profitable trade routes for a ship";
+
+/// Number of trade routes listed when no count is given.
+const DEFAULT_ROUTE_COUNT: usize = 10;
+
+/// Where the CLI keeps agent tokens, chosen with `--store`.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+enum StoreKind {
+    #[default]
+    File,
+    Keyring,
+    Env,
+}
+
+impl StoreKind {
+    /// Gets the [`StoreKind`] named on the command line, or [`Option::None`] if there's no such kind.
+    ///
+    /// * `name` - name of the kind of store.
+    fn from_arg(name: &str) -> Option<Self> {
+        match name {
+            "file" => Some(StoreKind::File),
+            "keyring" => Some(StoreKind::Keyring),
+            "env" => Some(StoreKind::Env),
+            _ => None,
+        }
+    }
+
+    /// Gets the [`TokenStore`] keeping the agent of a profile.
+    ///
+    /// * `profile_name` - name of the profile, or [`DEFAULT_PROFILE`] for the top-level agent.
+    fn token_store(self, profile_name: &str) -> Arc<dyn TokenStore> {
+        let is_default_profile = profile_name == DEFAULT_PROFILE;
+        match self {
+            StoreKind::File if is_default_profile => Arc::new(FileTokenStore::default()),
+            StoreKind::File => Arc::new(FileTokenStore::for_profile(profile_name)),
+            StoreKind::Keyring => Arc::new(KeyringTokenStore::new(profile_name)),
+            StoreKind::Env if is_default_profile => Arc::new(EnvTokenStore::default()),
+            StoreKind::Env => Arc::new(EnvTokenStore::new(&format!(
+                "{TOKEN_ENV_VAR}_{}",
+                profile_name.to_ascii_uppercase()
+            ))),
+        }
+    }
+
+    /// Initializes an [`ApiClient`] for the agent of a profile kept in this kind of store.
+    ///
+    /// * `profile_name` - name of the profile, or [`DEFAULT_PROFILE`] for the top-level agent.
+    fn init_client(self, profile_name: &str) -> ApiResult<ApiClient> {
+        ApiClient::init_with_token_store(self.token_store(profile_name))
+    }
+}
+
+/// Runs a single CLI command.
+///
+/// * `args` - command line arguments, excluding the program name.
+///
+/// Returns unit on success, or the [`ApiError`](crate::api::client::ApiError) reason for failure.
+pub fn run(args: &[String]) -> ApiResult<()> {
+    let args: Vec<&str> = args.iter().map(String::as_str).collect();
+    let (store_kind, args) = match args[..] {
+        ["--store", store_name, ref command @ ..] => match StoreKind::from_arg(store_name) {
+            Some(store_kind) => (store_kind, command),
+            None => {
+                println!("{USAGE}");
+                return Ok(());
+            }
+        },
+        ref command => (StoreKind::default(), command),
+    };
+
+    match *args {
+        ["agents"] => run_agents(store_kind, false),
+        ["agents", "run"] => run_agents(store_kind, true),
+        ["construction", waypoint_symbol] => print_construction(store_kind, waypoint_symbol),
+        ["factions"] => print_factions(),
+        ["ledger", report] => export_ledger(report, None),
+        ["ledger", report, file_path] => export_ledger(report, Some(file_path)),
+        ["register"] => registration::run_wizard(
+            &mut io::stdin().lock(),
+            store_kind.token_store(DEFAULT_PROFILE),
+        )
+        .map(|_| ()),
+        ["routes", ship_symbol] => print_trade_routes(store_kind, ship_symbol, DEFAULT_ROUTE_COUNT),
+        ["routes", ship_symbol, count] => match count.parse() {
+            Ok(count) => print_trade_routes(store_kind, ship_symbol, count),
+            Err(_) => {
+                println!("{USAGE}");
+                Ok(())
+            }
+        },
+        _ => {
+            println!("{USAGE}");
+            Ok(())
+        }
+    }
+}
+
+/// Prints the status of the agent in the config file and of every profile next to it, optionally resuming the
+/// automation each of them saved and running it until it's done.
+///
+/// * `store_kind` - [`StoreKind`] keeping the agents' tokens.
+/// * `run_automation` - whether to run the agents' automation after printing their status.
+///
+/// Returns unit on success, or the [`ApiError`](crate::api::client::ApiError) reason for failure.
+fn run_agents(store_kind: StoreKind, run_automation: bool) -> ApiResult<()> {
+    let config_data = utils::config::read_default_config_file().ok_or(ApiError::MissingToken)?;
+    let mut api_clients = vec![(
+        DEFAULT_PROFILE.to_string(),
+        store_kind.init_client(DEFAULT_PROFILE)?,
+    )];
+    for profile in &config_data.profiles {
+        api_clients.push((profile.name.clone(), store_kind.init_client(&profile.name)?));
+    }
+
+    let mut orchestrator = Orchestrator::new();
+    for (profile_name, api_client) in &api_clients {
+        let runner = orchestrator.add_agent(profile_name, api_client);
+        let event_profile_name = profile_name.clone();
+        runner.set_event_handler(move |event| println!("{event_profile_name}: {event}"));
+        if run_automation {
+            runner.register(CONTRACT_BEHAVIOR_NAME, ContractBehavior::load);
+            runner.register(MINING_BEHAVIOR_NAME, MiningBehavior::load);
+            runner.restore()?;
+        }
+    }
+
+    for agent_status in orchestrator.status()? {
+        println!("{agent_status}");
+    }
+    if run_automation {
+        orchestrator.run();
+    }
+    Ok(())
+}
+
+/// Prints the progress of a construction site and the materials it still needs.
+///
+/// * `store_kind` - [`StoreKind`] keeping the agent's token.
+/// * `waypoint_symbol` - symbol of the construction site's waypoint.
+///
+/// Returns unit on success, or the [`ApiError`](crate::api::client::ApiError) reason for failure.
+fn print_construction(store_kind: StoreKind, waypoint_symbol: &str) -> ApiResult<()> {
+    let api_client = store_kind.init_client(DEFAULT_PROFILE)?;
+    let construction = api_client.get_construction(waypoint_symbol)?;
+    println!("{construction}");
+    if !construction.is_complete {
+        let remaining_units: u32 = construction
+            .remaining_materials()
+            .map(|material| material.remaining())
+            .sum();
+        println!("{remaining_units} units of materials remaining.");
+    }
+    Ok(())
+}
+
+/// Exports a report of the ledger as CSV.
+///
+/// * `report` - which report to export: profit per `ship`, `behavior`, `agent` or `hour`, or every one of the
+///   `entries`.
+/// * `file_path` - file to write the CSV to, or [`Option::None`] to print it.
+///
+/// Returns unit on success, or the [`ApiError`](crate::api::client::ApiError) reason for failure.
+fn export_ledger(report: &str, file_path: Option<&str>) -> ApiResult<()> {
+    let entries = ledger::entries(0..u64::MAX);
+    let csv = match report {
+        "ship" => ledger::profit_csv(&ledger::profit_by_ship(&entries)),
+        "behavior" => ledger::profit_csv(&ledger::profit_by_behavior(&entries)),
+        "agent" => ledger::profit_csv(&ledger::profit_by_agent(&entries)),
+        "hour" => ledger::profit_csv(&ledger::profit_per_hour(&entries)),
+        "entries" => ledger::entries_csv(&entries),
+        _ => {
+            println!("{USAGE}");
+            return Ok(());
+        }
+    };
+
+    match file_path {
+        Some(file_path) => match fs::write(file_path, csv) {
+            Ok(()) => println!("Wrote {} ledger entries to {file_path}.", entries.len()),
+            Err(e) => println!("Error writing {file_path}: {e}"),
+        },
+        None => print!("{csv}"),
+    }
+    Ok(())
+}
+
+/// Prints every faction new agents can register in, so one can be chosen before registering.
+///
+/// Returns unit on success, or the [`ApiError`](crate::api::client::ApiError) reason for failure.
+fn print_factions() -> ApiResult<()> {
+    let factions = ApiClient::unregistered().list_factions()?;
+    for faction in factions.iter().filter(|faction| faction.is_recruiting) {
+        println!("{faction}\n");
+    }
+    Ok(())
+}
+
+/// Prints the best trade routes for a ship based on the recorded market prices.
+///
+/// * `store_kind` - [`StoreKind`] keeping the agent's token.
+/// * `ship_symbol` - symbol of the ship doing the trading.
+/// * `count` - maximum number of routes to print.
+fn print_trade_routes(store_kind: StoreKind, ship_symbol: &str, count: usize) -> ApiResult<()> {
+    let api_client = store_kind.init_client(DEFAULT_PROFILE)?;
+    let ship = api_client.get_ship(ship_symbol)?;
+    let price_records = history::latest_prices();
+
+    // Routes are only planned within the ship's system, so only its markets need locating.
+    let system = system_of(&ship.nav.waypoint_symbol);
+    let mut waypoints: Vec<&str> = price_records
+        .iter()
+        .map(|record| record.waypoint_symbol.as_str())
+        .filter(|waypoint| system_of(waypoint) == system)
+        .chain([ship.nav.waypoint_symbol.as_str()])
+        .collect();
+    waypoints.sort_unstable();
+    waypoints.dedup();
+    let locations: HashMap<String, _> = api_client
+        .get_waypoints_location_data(&waypoints)?
+        .into_iter()
+        .map(|location_data| (location_data.symbol, location_data.coords))
+        .collect();
+
+    let trade_routes = trade::find_trade_routes(&ship, &price_records, &locations);
+    if trade_routes.is_empty() {
+        println!("No profitable trade routes found - visit more markets to record their prices.");
+    }
+    for (rank, trade_route) in trade_routes.iter().take(count).enumerate() {
+        println!("{}. {trade_route}", rank + 1);
+    }
+    Ok(())
+}
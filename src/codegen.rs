@@ -0,0 +1,585 @@
+use serde_json::{Map, Value};
+use std::{
+    collections::BTreeMap,
+    fmt::{Display, Formatter, Result as FmtResult},
+    fs,
+    path::Path,
+    process::Command,
+};
+
+/// Where the vendored SpaceTraders OpenAPI document is read from when no other path is given.
+pub const DEFAULT_SPEC_PATH: &str = "openapi/SpaceTraders.json";
+
+/// Where the generated module is written when no other path is given.
+pub const DEFAULT_OUTPUT_PATH: &str = "src/api/generated.rs";
+
+/// HTTP methods endpoints are generated for, in the order they are listed.
+const HTTP_METHODS: [&str; 5] = ["get", "post", "put", "patch", "delete"];
+
+/// Words that can't be used as field names without being escaped.
+const RUST_KEYWORDS: [&str; 10] = [
+    "as", "enum", "fn", "impl", "match", "mod", "move", "ref", "type", "yield",
+];
+
+pub type CodegenResult<T> = Result<T, CodegenError>;
+
+#[derive(Clone, Debug)]
+pub enum CodegenError {
+    FileRead,
+    FileWrite,
+    InvalidSpec(String),
+}
+impl Display for CodegenError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match self {
+            CodegenError::FileRead => write!(f, "Error reading the OpenAPI document."),
+            CodegenError::FileWrite => write!(f, "Error writing the generated module."),
+            CodegenError::InvalidSpec(reason) => {
+                write!(f, "The OpenAPI document can't be used: {reason}")
+            }
+        }
+    }
+}
+
+/// Generates the API module from an OpenAPI document file.
+///
+/// * `spec_path` - [`Path`] to the OpenAPI document, in JSON.
+/// * `output_path` - [`Path`] the generated Rust module is written to.
+///
+/// Returns unit on success, or the [`CodegenError`] reason for failure.
+pub fn generate_file(spec_path: &Path, output_path: &Path) -> CodegenResult<()> {
+    let spec_str = fs::read_to_string(spec_path).map_err(|_| CodegenError::FileRead)?;
+    let spec: Value = serde_json::from_str(&spec_str)
+        .map_err(|e| CodegenError::InvalidSpec(format!("not valid JSON: {e}")))?;
+    fs::write(output_path, generate_module(&spec)?).map_err(|_| CodegenError::FileWrite)?;
+
+    // Formatting is best-effort, so that generation still works without rustfmt installed.
+    let _ = Command::new("rustfmt")
+        .args(["--edition", "2021"])
+        .arg(output_path)
+        .status();
+    Ok(())
+}
+
+/// Generates Rust models for every schema in an OpenAPI document, plus a path builder for every endpoint.
+///
+/// Models follow the conventions of [`api::data`](crate::api::data): string enums fall back to an `Unknown`
+/// variant and structs keep unknown fields in an `extra` map, so that the generated code keeps working when
+/// the API changes.
+///
+/// * `spec` - the OpenAPI document.
+///
+/// Returns the source of the generated module, or [`CodegenError::InvalidSpec`] if the document has no schemas.
+pub fn generate_module(spec: &Value) -> CodegenResult<String> {
+    let schemas = spec
+        .pointer("/components/schemas")
+        .and_then(Value::as_object)
+        .ok_or_else(|| CodegenError::InvalidSpec(String::from("missing components.schemas")))?;
+
+    let mut generator = Generator::default();
+    for (name, schema) in schemas {
+        generator.generate_schema(name, schema);
+    }
+    while let Some((name, schema)) = generator.pending.pop() {
+        generator.generate_schema(&name, &schema);
+    }
+
+    let mut module = String::from(
+        "//! Models and endpoint paths generated from the SpaceTraders OpenAPI document.\n\
+         //!\n\
+         //! Regenerate with `cargo run --bin codegen` instead of editing by hand.\n\n",
+    );
+    module.push_str("use super::data::UnknownFields;\n\n");
+    if generator.uses_date_time {
+        module.push_str("use chrono::{DateTime, Utc};\n");
+    }
+    module.push_str("use serde::{Deserialize, Serialize};\n");
+    if generator.uses_hash_map {
+        module.push_str("use std::collections::HashMap;\n");
+    }
+    for item in generator.items.values() {
+        module.push('\n');
+        module.push_str(item);
+    }
+    if let Some(paths) = spec.get("paths").and_then(Value::as_object) {
+        module.push('\n');
+        module.push_str(&generate_endpoints(paths));
+    }
+    Ok(module)
+}
+
+/// Builds a value that fills in every property of a schema, e.g. to check hand-written models against the document.
+///
+/// Enums take their first value and arrays hold a single item, so the sample never names a symbol the schema
+/// doesn't know about.
+///
+/// * `spec` - the OpenAPI document, which `$ref`s are looked up in.
+/// * `schema` - the schema to build a value for.
+///
+/// Returns the sample value, or [`Value::Null`] for references the document doesn't define.
+pub fn sample_value(spec: &Value, schema: &Value) -> Value {
+    if let Some(reference) = schema.get("$ref").and_then(Value::as_str) {
+        return spec
+            .pointer(reference.trim_start_matches('#'))
+            .map_or(Value::Null, |schema| sample_value(spec, schema));
+    }
+    if let Some([single_schema]) = schema
+        .get("allOf")
+        .and_then(Value::as_array)
+        .map(Vec::as_slice)
+    {
+        return sample_value(spec, single_schema);
+    }
+    if let Some(first_value) = schema
+        .get("enum")
+        .and_then(Value::as_array)
+        .and_then(|values| values.first())
+    {
+        return first_value.clone();
+    }
+
+    match schema.get("type").and_then(Value::as_str) {
+        Some("string") if schema.get("format").and_then(Value::as_str) == Some("date-time") => {
+            Value::from("2023-05-20T00:00:00Z")
+        }
+        Some("string") => Value::from("SAMPLE"),
+        Some("integer") | Some("number") => Value::from(1),
+        Some("boolean") => Value::from(true),
+        Some("array") => Value::Array(vec![sample_value(
+            spec,
+            schema.get("items").unwrap_or(&Value::Null),
+        )]),
+        Some("object") => Value::Object(
+            schema
+                .get("properties")
+                .and_then(Value::as_object)
+                .into_iter()
+                .flatten()
+                .map(|(property, property_schema)| {
+                    (property.clone(), sample_value(spec, property_schema))
+                })
+                .collect(),
+        ),
+        _ => Value::Null,
+    }
+}
+
+/// Collects the generated items while walking the schemas of an OpenAPI document.
+#[derive(Default)]
+struct Generator {
+    /// Source of each generated type, by name.
+    items: BTreeMap<String, String>,
+    /// Inline schemas that still need a type of their own, by the name chosen for it.
+    pending: Vec<(String, Value)>,
+    uses_date_time: bool,
+    uses_hash_map: bool,
+}
+
+impl Generator {
+    fn generate_schema(&mut self, name: &str, schema: &Value) {
+        if self.items.contains_key(name) {
+            return;
+        }
+        let item = if let Some(values) = schema.get("enum").and_then(Value::as_array) {
+            generate_enum(name, schema, values)
+        } else if schema.get("properties").is_some() {
+            self.generate_struct(name, schema)
+        } else {
+            format!(
+                "{}pub type {name} = {};\n",
+                doc_comment(schema, ""),
+                self.rust_type(schema, name, "")
+            )
+        };
+        self.items.insert(name.to_string(), item);
+    }
+
+    fn generate_struct(&mut self, name: &str, schema: &Value) -> String {
+        let required: Vec<&str> = schema
+            .get("required")
+            .and_then(Value::as_array)
+            .map(|required| required.iter().filter_map(Value::as_str).collect())
+            .unwrap_or_default();
+
+        let mut item = doc_comment(schema, "");
+        item.push_str("#[derive(Clone, Debug, Serialize, Deserialize)]\n");
+        item.push_str("#[serde(rename_all = \"camelCase\")]\n");
+        item.push_str(&format!("pub struct {name} {{\n"));
+        let properties = schema.get("properties").and_then(Value::as_object);
+        for (property, property_schema) in properties.into_iter().flatten() {
+            let field_name = snake_case(property);
+            let mut field_type = self.rust_type(property_schema, name, property);
+            if !required.contains(&property.as_str()) {
+                field_type = format!("Option<{field_type}>");
+            }
+
+            item.push_str(&doc_comment(property_schema, "    "));
+            if camel_case(&field_name) != *property {
+                item.push_str(&format!("    #[serde(rename = \"{property}\")]\n"));
+            }
+            let field_name = if RUST_KEYWORDS.contains(&field_name.as_str()) {
+                format!("r#{field_name}")
+            } else {
+                field_name
+            };
+            item.push_str(&format!("    pub {field_name}: {field_type},\n"));
+        }
+        item.push_str("    #[serde(flatten)]\n    pub extra: UnknownFields,\n}\n");
+        item
+    }
+
+    /// Gets the Rust type of a schema, queueing a new type for inline enums and objects.
+    ///
+    /// * `schema` - the schema.
+    /// * `parent` - name of the type the schema is used in, to derive names for inline types from.
+    /// * `property` - name of the property the schema describes, if any.
+    fn rust_type(&mut self, schema: &Value, parent: &str, property: &str) -> String {
+        if let Some(reference) = schema.get("$ref").and_then(Value::as_str) {
+            return reference
+                .rsplit('/')
+                .next()
+                .unwrap_or(reference)
+                .to_string();
+        }
+        if let Some([single_schema]) = schema
+            .get("allOf")
+            .and_then(Value::as_array)
+            .map(Vec::as_slice)
+        {
+            return self.rust_type(single_schema, parent, property);
+        }
+
+        let inline_name = format!("{parent}{}", pascal_case(property));
+        match schema.get("type").and_then(Value::as_str) {
+            Some("string") if schema.get("enum").is_some() => {
+                self.pending.push((inline_name.clone(), schema.clone()));
+                inline_name
+            }
+            Some("string") if schema.get("format").and_then(Value::as_str) == Some("date-time") => {
+                self.uses_date_time = true;
+                String::from("DateTime<Utc>")
+            }
+            Some("string") => String::from("String"),
+            Some("integer") if schema.get("format").and_then(Value::as_str) == Some("int32") => {
+                String::from("i32")
+            }
+            Some("integer") => String::from("i64"),
+            Some("number") => String::from("f64"),
+            Some("boolean") => String::from("bool"),
+            Some("array") => {
+                let items = schema.get("items").cloned().unwrap_or_default();
+                format!("Vec<{}>", self.rust_type(&items, parent, property))
+            }
+            Some("object") if schema.get("properties").is_some() => {
+                self.pending.push((inline_name.clone(), schema.clone()));
+                inline_name
+            }
+            Some("object") => {
+                self.uses_hash_map = true;
+                String::from("HashMap<String, serde_json::Value>")
+            }
+            _ => String::from("serde_json::Value"),
+        }
+    }
+}
+
+/// Generates a string enum with the same `Unknown` fallback and schema drift reporting as the enums in
+/// [`api::data`](crate::api::data).
+fn generate_enum(name: &str, schema: &Value, values: &[Value]) -> String {
+    let symbols: Vec<&str> = values.iter().filter_map(Value::as_str).collect();
+    let variants: Vec<String> = symbols.iter().map(|symbol| pascal_case(symbol)).collect();
+
+    let mut item = doc_comment(schema, "");
+    item.push_str("#[derive(Clone, Debug, Eq, PartialEq, Hash)]\n");
+    item.push_str(&format!("pub enum {name} {{\n"));
+    for variant in &variants {
+        item.push_str(&format!("    {variant},\n"));
+    }
+    item.push_str(
+        "    /// A symbol this client doesn't know about yet.\n    Unknown(String),\n}\n",
+    );
+
+    let as_str_arms: String = variants
+        .iter()
+        .zip(&symbols)
+        .map(|(variant, symbol)| format!("            {name}::{variant} => \"{symbol}\",\n"))
+        .collect();
+    let from_arms: String = variants
+        .iter()
+        .zip(&symbols)
+        .map(|(variant, symbol)| format!("            \"{symbol}\" => {name}::{variant},\n"))
+        .collect();
+    item.push_str(&format!(
+        "impl {name} {{
+    /// Symbol the API uses for this value.
+    pub fn as_str(&self) -> &str {{
+        match self {{
+{as_str_arms}            {name}::Unknown(symbol) => symbol,
+        }}
+    }}
+}}
+impl From<&str> for {name} {{
+    fn from(symbol: &str) -> Self {{
+        match symbol {{
+{from_arms}            _ => {name}::Unknown(symbol.to_string()),
+        }}
+    }}
+}}
+impl std::fmt::Display for {name} {{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {{
+        write!(f, \"{{}}\", self.as_str())
+    }}
+}}
+impl Serialize for {name} {{
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {{
+        serializer.serialize_str(self.as_str())
+    }}
+}}
+impl<'de> Deserialize<'de> for {name} {{
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {{
+        let symbol = Self::from(String::deserialize(deserializer)?.as_str());
+        if let {name}::Unknown(ref unknown_symbol) = symbol {{
+            super::data::record_schema_drift(format!(\"unknown {name} `{{unknown_symbol}}`\"));
+        }}
+        Ok(symbol)
+    }}
+}}
+"
+    ));
+    item
+}
+
+/// Generates an `endpoints` module with a function building the path of every operation.
+fn generate_endpoints(paths: &Map<String, Value>) -> String {
+    let mut module = String::from(
+        "/// Paths of the API's endpoints, relative to the root URL.\npub mod endpoints {\n",
+    );
+    let mut is_first = true;
+    for (path, operations) in paths {
+        for method in HTTP_METHODS {
+            let Some(operation) = operations.get(method) else {
+                continue;
+            };
+            let Some(operation_id) = operation.get("operationId").and_then(Value::as_str) else {
+                continue;
+            };
+
+            let parameters: Vec<&str> = path
+                .split('{')
+                .skip(1)
+                .filter_map(|segment| segment.split('}').next())
+                .collect();
+            let mut formatted_path = path.clone();
+            for parameter in &parameters {
+                formatted_path = formatted_path.replace(
+                    &format!("{{{parameter}}}"),
+                    &format!("{{{}}}", snake_case(parameter)),
+                );
+            }
+            let arguments: Vec<String> = parameters
+                .iter()
+                .map(|parameter| format!("{}: &str", snake_case(parameter)))
+                .collect();
+
+            if !is_first {
+                module.push('\n');
+            }
+            is_first = false;
+            if let Some(summary) = operation.get("summary").and_then(Value::as_str) {
+                module.push_str(&format!("    /// {summary}.\n    ///\n"));
+            }
+            module.push_str(&format!("    /// `{} {path}`\n", method.to_uppercase()));
+            module.push_str(&format!(
+                "    pub fn {}({}) -> String {{\n",
+                snake_case(operation_id),
+                arguments.join(", ")
+            ));
+            if parameters.is_empty() {
+                module.push_str(&format!(
+                    "        String::from(\"{formatted_path}\")\n    }}\n"
+                ));
+            } else {
+                module.push_str(&format!("        format!(\"{formatted_path}\")\n    }}\n"));
+            }
+        }
+    }
+    module.push_str("}\n");
+    module
+}
+
+/// Turns the first paragraph of a schema's description into a doc comment.
+///
+/// * `schema` - the schema.
+/// * `indent` - whitespace to put before each line.
+fn doc_comment(schema: &Value, indent: &str) -> String {
+    let Some(description) = schema.get("description").and_then(Value::as_str) else {
+        return String::new();
+    };
+    description
+        .split("\n\n")
+        .next()
+        .unwrap_or_default()
+        .lines()
+        .map(|line| format!("{indent}/// {}\n", line.trim()))
+        .collect()
+}
+
+/// Converts a camelCase, PascalCase or kebab-case name to snake_case.
+fn snake_case(name: &str) -> String {
+    let mut snake = String::new();
+    let mut previous: Option<char> = None;
+    for c in name.chars() {
+        if c == '-' || c == ' ' {
+            snake.push('_');
+        } else if c.is_ascii_uppercase() {
+            if previous.is_some_and(|p| p.is_ascii_lowercase() || p.is_ascii_digit()) {
+                snake.push('_');
+            }
+            snake.push(c.to_ascii_lowercase());
+        } else {
+            snake.push(c);
+        }
+        previous = Some(c);
+    }
+    snake
+}
+
+/// Converts a snake_case name to the camelCase used for JSON fields.
+fn camel_case(name: &str) -> String {
+    let pascal = pascal_case(name);
+    let mut chars = pascal.chars();
+    match chars.next() {
+        Some(first) => first.to_ascii_lowercase().to_string() + chars.as_str(),
+        None => pascal,
+    }
+}
+
+/// Converts a camelCase, snake_case or SCREAMING_SNAKE_CASE name to PascalCase.
+///
+/// Roman numerals, as in "MOUNT_SURVEYOR_II", are kept uppercase.
+fn pascal_case(name: &str) -> String {
+    snake_case(name)
+        .split('_')
+        .map(|word| {
+            if ["i", "ii", "iii"].contains(&word.to_ascii_lowercase().as_str()) {
+                return word.to_ascii_uppercase();
+            }
+            let mut chars = word.chars();
+            match chars.next() {
+                Some(first) => {
+                    first.to_ascii_uppercase().to_string() + &chars.as_str().to_ascii_lowercase()
+                }
+                None => String::new(),
+            }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{generate_module, sample_value};
+
+    #[test]
+    fn verify_generate_module() {
+        // Setup
+        let spec = serde_json::json!({
+            "paths": {
+                "/my/ships/{shipSymbol}/orbit": {
+                    "post": { "operationId": "orbit-ship", "summary": "Orbit Ship" }
+                }
+            },
+            "components": {
+                "schemas": {
+                    "ShipNav": {
+                        "description": "The navigation information of the ship.",
+                        "type": "object",
+                        "required": ["systemSymbol", "status"],
+                        "properties": {
+                            "systemSymbol": { "type": "string" },
+                            "status": { "$ref": "#/components/schemas/ShipNavStatus" },
+                            "arrival": { "type": "string", "format": "date-time" },
+                            "type": { "type": "string", "enum": ["FAST", "SLOW"] }
+                        }
+                    },
+                    "ShipNavStatus": {
+                        "type": "string",
+                        "enum": ["IN_TRANSIT", "IN_ORBIT", "DOCKED"]
+                    }
+                }
+            }
+        });
+
+        // Test
+        let module = generate_module(&spec).unwrap();
+
+        // Verify
+        assert!(module.contains("use chrono::{DateTime, Utc};"));
+        assert!(module.contains(
+            "/// The navigation information of the ship.\n\
+             #[derive(Clone, Debug, Serialize, Deserialize)]\n\
+             #[serde(rename_all = \"camelCase\")]\n\
+             pub struct ShipNav {\n    \
+                 pub arrival: Option<DateTime<Utc>>,\n    \
+                 pub status: ShipNavStatus,\n    \
+                 pub system_symbol: String,\n    \
+                 pub r#type: Option<ShipNavType>,\n    \
+                 #[serde(flatten)]\n    \
+                 pub extra: UnknownFields,\n\
+             }"
+        ));
+        assert!(module.contains("pub enum ShipNavType {\n    Fast,\n    Slow,\n"));
+        assert!(module.contains("\"IN_TRANSIT\" => ShipNavStatus::InTransit,"));
+        assert!(module.contains(
+            "    pub fn orbit_ship(ship_symbol: &str) -> String {\n        \
+                 format!(\"/my/ships/{ship_symbol}/orbit\")\n    }"
+        ));
+    }
+
+    #[test]
+    fn verify_sample_value() {
+        // Setup
+        let spec = serde_json::json!({
+            "components": {
+                "schemas": {
+                    "Cooldown": {
+                        "type": "object",
+                        "properties": {
+                            "shipSymbol": { "type": "string" },
+                            "totalSeconds": { "type": "integer" },
+                            "expiration": { "type": "string", "format": "date-time" }
+                        }
+                    },
+                    "ShipNavStatus": {
+                        "type": "string",
+                        "enum": ["IN_TRANSIT", "IN_ORBIT", "DOCKED"]
+                    }
+                }
+            }
+        });
+        let schema = serde_json::json!({
+            "type": "object",
+            "properties": {
+                "cooldowns": { "type": "array", "items": { "$ref": "#/components/schemas/Cooldown" } },
+                "status": { "allOf": [{ "$ref": "#/components/schemas/ShipNavStatus" }] },
+                "missing": { "$ref": "#/components/schemas/Missing" }
+            }
+        });
+
+        // Test
+        let sample = sample_value(&spec, &schema);
+
+        // Verify
+        assert_eq!(
+            sample,
+            serde_json::json!({
+                "cooldowns": [{
+                    "shipSymbol": "SAMPLE",
+                    "totalSeconds": 1,
+                    "expiration": "2023-05-20T00:00:00Z"
+                }],
+                "status": "IN_TRANSIT",
+                "missing": null
+            })
+        );
+    }
+}
@@ -0,0 +1,582 @@
+use crate::{
+    api::{
+        client::{ApiResult, TraderApis},
+        data::{
+            AgentData, CargoInfo, ChartData, Construction, ConstructionSupplyData, ContractData,
+            ContractUpdateData, Cooldown, DeliveryData, ExtractionData, FactionData,
+            FactionReputation, Factions, FlightMode, JumpData, JumpGate, LocationData, MarketData,
+            ModulesData, MountsData, NavInfo, NavigationData, RefuelData, RegistrationData,
+            RepairData, ScrapData, ShipData, ShipModuleSymbol, ShipMountSymbol, ShipPurchaseData,
+            ShipScanData, ShipServiceTransaction, ShipSymbol, ShipType, SiphonData, StatusData,
+            Survey, SurveyData, SystemScanData, TradeData, TradeSymbol, TransferData,
+            WaypointScanData,
+        },
+    },
+    automation::STATUS_IN_TRANSIT,
+    logistics, scheduler,
+};
+
+use chrono::Utc;
+use std::{
+    collections::HashMap,
+    sync::{Mutex, MutexGuard},
+};
+
+/// In-memory mirror of every ship in the fleet, kept in sync with the responses of the requests made through it.
+///
+/// [`Fleet`] wraps another [`TraderApis`] and implements it too, so it can be handed to anything that makes
+/// requests, such as automation behaviors, and the mirror stays current without any stitching by the caller.
+pub struct Fleet<'a> {
+    api: &'a dyn TraderApis,
+    ships: Mutex<HashMap<ShipSymbol, ShipData>>,
+    cooldowns: Mutex<HashMap<ShipSymbol, Cooldown>>,
+}
+
+impl<'a> Fleet<'a> {
+    /// Creates a [`Fleet`] that isn't mirroring any ships yet.
+    ///
+    /// * `api` - client the requests are made with.
+    pub fn new(api: &'a dyn TraderApis) -> Self {
+        Self {
+            api,
+            ships: Mutex::default(),
+            cooldowns: Mutex::default(),
+        }
+    }
+
+    /// Creates a [`Fleet`] mirroring every ship the agent owns.
+    ///
+    /// * `api` - client the requests are made with.
+    ///
+    /// Returns the loaded [`Fleet`], or the [`ApiError`](crate::api::client::ApiError) reason for failure.
+    pub fn load(api: &'a dyn TraderApis) -> ApiResult<Self> {
+        let fleet = Self::new(api);
+        fleet.list_ships()?;
+        Ok(fleet)
+    }
+
+    /// Starts mirroring a ship, replacing any previous state of it.
+    ///
+    /// * `ship` - latest [`ShipData`] of the ship.
+    pub fn track(&self, ship: ShipData) {
+        self.lock_ships().insert(ship.symbol.clone(), ship);
+    }
+
+    /// Gets the mirrored state of a ship.
+    ///
+    /// * `ship` - symbol of the ship.
+    pub fn ship(&self, ship: &str) -> Option<ShipData> {
+        self.lock_ships().get(ship).cloned()
+    }
+
+    /// Gets the mirrored state of every ship.
+    pub fn ships(&self) -> Vec<ShipData> {
+        self.filter_ships(|_| true)
+    }
+
+    /// Gets the latest cooldown reported for a ship, if it is still active.
+    ///
+    /// * `ship` - symbol of the ship.
+    pub fn active_cooldown(&self, ship: &str) -> Option<Cooldown> {
+        self.lock_cooldowns()
+            .get(ship)
+            .filter(|cooldown| scheduler::cooldown_expiration(cooldown) > Utc::now())
+            .cloned()
+    }
+
+    /// Gets the ships that are neither travelling nor waiting on a cooldown.
+    pub fn idle_ships(&self) -> Vec<ShipData> {
+        let now = Utc::now();
+        self.filter_ships(|ship| {
+            let in_transit = ship.nav.status == STATUS_IN_TRANSIT
+                && ship.nav.route.arrival.is_none_or(|arrival| arrival > now);
+            !in_transit && self.active_cooldown(&ship.symbol).is_none()
+        })
+    }
+
+    /// Gets the ships at a waypoint, including those still travelling there.
+    ///
+    /// * `waypoint` - symbol of the waypoint.
+    pub fn ships_at(&self, waypoint: &str) -> Vec<ShipData> {
+        self.filter_ships(|ship| ship.nav.waypoint_symbol == waypoint)
+    }
+
+    /// Gets the ships with nothing in their cargo hold.
+    pub fn ships_with_empty_cargo(&self) -> Vec<ShipData> {
+        self.filter_ships(|ship| ship.cargo.units == 0)
+    }
+
+    /// Gets the mirrored ships matching a predicate, ordered by symbol.
+    fn filter_ships(&self, predicate: impl Fn(&ShipData) -> bool) -> Vec<ShipData> {
+        let mut ships: Vec<ShipData> = self
+            .lock_ships()
+            .values()
+            .filter(|ship| predicate(ship))
+            .cloned()
+            .collect();
+        ships.sort_by(|a, b| a.symbol.cmp(&b.symbol));
+        ships
+    }
+
+    /// Locks the mirrored ships. A panic while they were locked leaves at worst a stale ship, so poisoning is ignored.
+    fn lock_ships(&self) -> MutexGuard<'_, HashMap<ShipSymbol, ShipData>> {
+        self.ships
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+
+    /// Locks the latest cooldowns, ignoring poisoning like [`lock_ships`](Self::lock_ships).
+    fn lock_cooldowns(&self) -> MutexGuard<'_, HashMap<ShipSymbol, Cooldown>> {
+        self.cooldowns
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+
+    /// Applies part of a response to a mirrored ship. Ships that aren't mirrored are left alone.
+    fn update(&self, ship: &str, apply: impl FnOnce(&mut ShipData)) {
+        if let Some(ship_data) = self.lock_ships().get_mut(ship) {
+            apply(ship_data);
+        }
+    }
+
+    /// Records the latest cooldown of a ship.
+    fn update_cooldown(&self, cooldown: &Cooldown) {
+        self.lock_cooldowns()
+            .insert(cooldown.ship_symbol.clone(), cooldown.clone());
+    }
+}
+
+impl TraderApis for Fleet<'_> {
+    fn get_status(&self) -> ApiResult<StatusData> {
+        self.api.get_status()
+    }
+
+    fn register_new_agent(
+        &self,
+        agent_name: &str,
+        faction_name: Factions,
+    ) -> ApiResult<RegistrationData> {
+        let registration_data = self.api.register_new_agent(agent_name, faction_name)?;
+        self.track(registration_data.ship.clone());
+        Ok(registration_data)
+    }
+
+    fn get_agent_data(&self) -> ApiResult<AgentData> {
+        self.api.get_agent_data()
+    }
+
+    fn list_reputations(&self) -> ApiResult<Vec<FactionReputation>> {
+        self.api.list_reputations()
+    }
+
+    fn list_factions(&self) -> ApiResult<Vec<FactionData>> {
+        self.api.list_factions()
+    }
+
+    fn get_faction(&self, faction: &Factions) -> ApiResult<FactionData> {
+        self.api.get_faction(faction)
+    }
+
+    fn get_waypoint_location_data(&self, waypoint: &str) -> ApiResult<LocationData> {
+        self.api.get_waypoint_location_data(waypoint)
+    }
+
+    fn get_waypoints_location_data(&self, waypoints: &[&str]) -> ApiResult<Vec<LocationData>> {
+        self.api.get_waypoints_location_data(waypoints)
+    }
+
+    fn get_market(&self, waypoint: &str) -> ApiResult<MarketData> {
+        self.api.get_market(waypoint)
+    }
+
+    fn list_ships(&self) -> ApiResult<Vec<ShipData>> {
+        let ships = self.api.list_ships()?;
+        *self.lock_ships() = ships
+            .iter()
+            .map(|ship| (ship.symbol.clone(), ship.clone()))
+            .collect();
+        Ok(ships)
+    }
+
+    fn get_ship(&self, ship: &str) -> ApiResult<ShipData> {
+        let ship_data = self.api.get_ship(ship)?;
+        self.track(ship_data.clone());
+        Ok(ship_data)
+    }
+
+    fn list_waypoints(&self, system: &str) -> ApiResult<Vec<LocationData>> {
+        self.api.list_waypoints(system)
+    }
+
+    fn get_construction(&self, waypoint: &str) -> ApiResult<Construction> {
+        self.api.get_construction(waypoint)
+    }
+
+    fn supply_construction(
+        &self,
+        ship: &str,
+        trade_symbol: &TradeSymbol,
+        units: u32,
+    ) -> ApiResult<ConstructionSupplyData> {
+        let supply_data = self.api.supply_construction(ship, trade_symbol, units)?;
+        self.update(ship, |ship_data| {
+            ship_data.cargo = supply_data.cargo.clone()
+        });
+        Ok(supply_data)
+    }
+
+    fn get_jump_gate(&self, waypoint: &str) -> ApiResult<JumpGate> {
+        self.api.get_jump_gate(waypoint)
+    }
+
+    fn orbit_ship(&self, ship: &str) -> ApiResult<NavInfo> {
+        let nav = self.api.orbit_ship(ship)?;
+        self.update(ship, |ship_data| ship_data.nav = nav.clone());
+        Ok(nav)
+    }
+
+    fn dock_ship(&self, ship: &str) -> ApiResult<NavInfo> {
+        let nav = self.api.dock_ship(ship)?;
+        self.update(ship, |ship_data| ship_data.nav = nav.clone());
+        Ok(nav)
+    }
+
+    fn navigate_ship(&self, ship: &str, waypoint: &str) -> ApiResult<NavigationData> {
+        let navigation_data = self.api.navigate_ship(ship, waypoint)?;
+        self.update(ship, |ship_data| {
+            ship_data.nav = navigation_data.nav.clone();
+            ship_data.fuel = navigation_data.fuel.clone();
+        });
+        Ok(navigation_data)
+    }
+
+    fn jump_ship(&self, ship: &str, system: &str) -> ApiResult<JumpData> {
+        let jump_data = self.api.jump_ship(ship, system)?;
+        self.update(ship, |ship_data| ship_data.nav = jump_data.nav.clone());
+        self.update_cooldown(&jump_data.cooldown);
+        Ok(jump_data)
+    }
+
+    fn warp_ship(&self, ship: &str, waypoint: &str) -> ApiResult<NavigationData> {
+        let navigation_data = self.api.warp_ship(ship, waypoint)?;
+        self.update(ship, |ship_data| {
+            ship_data.nav = navigation_data.nav.clone();
+            ship_data.fuel = navigation_data.fuel.clone();
+        });
+        Ok(navigation_data)
+    }
+
+    fn set_flight_mode(&self, ship: &str, flight_mode: FlightMode) -> ApiResult<NavInfo> {
+        let nav = self.api.set_flight_mode(ship, flight_mode)?;
+        self.update(ship, |ship_data| ship_data.nav = nav.clone());
+        Ok(nav)
+    }
+
+    fn create_chart(&self, ship: &str) -> ApiResult<ChartData> {
+        self.api.create_chart(ship)
+    }
+
+    fn create_survey(&self, ship: &str) -> ApiResult<SurveyData> {
+        let survey_data = self.api.create_survey(ship)?;
+        self.update_cooldown(&survey_data.cooldown);
+        Ok(survey_data)
+    }
+
+    fn scan_systems(&self, ship: &str) -> ApiResult<SystemScanData> {
+        let system_scan_data = self.api.scan_systems(ship)?;
+        self.update_cooldown(&system_scan_data.cooldown);
+        Ok(system_scan_data)
+    }
+
+    fn scan_waypoints(&self, ship: &str) -> ApiResult<WaypointScanData> {
+        let waypoint_scan_data = self.api.scan_waypoints(ship)?;
+        self.update_cooldown(&waypoint_scan_data.cooldown);
+        Ok(waypoint_scan_data)
+    }
+
+    fn scan_ships(&self, ship: &str) -> ApiResult<ShipScanData> {
+        let ship_scan_data = self.api.scan_ships(ship)?;
+        self.update_cooldown(&ship_scan_data.cooldown);
+        Ok(ship_scan_data)
+    }
+
+    fn siphon_resources(&self, ship: &str) -> ApiResult<SiphonData> {
+        let siphon_data = self.api.siphon_resources(ship)?;
+        self.update(ship, |ship_data| {
+            ship_data.cargo = siphon_data.cargo.clone()
+        });
+        self.update_cooldown(&siphon_data.cooldown);
+        Ok(siphon_data)
+    }
+
+    fn extract_resources(&self, ship: &str, survey: Option<&Survey>) -> ApiResult<ExtractionData> {
+        let extraction_data = self.api.extract_resources(ship, survey)?;
+        self.update(ship, |ship_data| {
+            ship_data.cargo = extraction_data.cargo.clone()
+        });
+        self.update_cooldown(&extraction_data.cooldown);
+        Ok(extraction_data)
+    }
+
+    fn jettison_cargo(
+        &self,
+        ship: &str,
+        trade_symbol: &TradeSymbol,
+        units: u32,
+    ) -> ApiResult<CargoInfo> {
+        let cargo = self.api.jettison_cargo(ship, trade_symbol, units)?;
+        self.update(ship, |ship_data| ship_data.cargo = cargo.clone());
+        Ok(cargo)
+    }
+
+    fn transfer_cargo(
+        &self,
+        from_ship: &str,
+        to_ship: &str,
+        trade_symbol: &TradeSymbol,
+        units: u32,
+    ) -> ApiResult<TransferData> {
+        let transfer_data = self
+            .api
+            .transfer_cargo(from_ship, to_ship, trade_symbol, units)?;
+        self.update(from_ship, |ship_data| {
+            ship_data.cargo = transfer_data.cargo.clone()
+        });
+        self.update(to_ship, |ship_data| match &transfer_data.target_cargo {
+            Some(target_cargo) => ship_data.cargo = target_cargo.clone(),
+            None => logistics::add_cargo(&mut ship_data.cargo, trade_symbol, units),
+        });
+        Ok(transfer_data)
+    }
+
+    fn sell_cargo(
+        &self,
+        ship: &str,
+        trade_symbol: &TradeSymbol,
+        units: u32,
+    ) -> ApiResult<TradeData> {
+        let trade_data = self.api.sell_cargo(ship, trade_symbol, units)?;
+        self.update(ship, |ship_data| ship_data.cargo = trade_data.cargo.clone());
+        Ok(trade_data)
+    }
+
+    fn refuel_ship(
+        &self,
+        ship: &str,
+        units: Option<u32>,
+        from_cargo: bool,
+    ) -> ApiResult<RefuelData> {
+        let refuel_data = self.api.refuel_ship(ship, units, from_cargo)?;
+        self.update(ship, |ship_data| {
+            ship_data.fuel = refuel_data.fuel.clone();
+            if let Some(cargo) = &refuel_data.cargo {
+                ship_data.cargo = cargo.clone();
+            }
+        });
+        Ok(refuel_data)
+    }
+
+    fn get_repair_ship(&self, ship: &str) -> ApiResult<ShipServiceTransaction> {
+        self.api.get_repair_ship(ship)
+    }
+
+    fn repair_ship(&self, ship: &str) -> ApiResult<RepairData> {
+        let repair_data = self.api.repair_ship(ship)?;
+        self.track(repair_data.ship.clone());
+        Ok(repair_data)
+    }
+
+    fn get_scrap_ship(&self, ship: &str) -> ApiResult<ShipServiceTransaction> {
+        self.api.get_scrap_ship(ship)
+    }
+
+    fn scrap_ship(&self, ship: &str) -> ApiResult<ScrapData> {
+        let scrap_data = self.api.scrap_ship(ship)?;
+        self.lock_ships().remove(ship);
+        self.lock_cooldowns().remove(ship);
+        Ok(scrap_data)
+    }
+
+    fn purchase_ship(&self, ship_type: &ShipType, waypoint: &str) -> ApiResult<ShipPurchaseData> {
+        let ship_purchase_data = self.api.purchase_ship(ship_type, waypoint)?;
+        self.track(ship_purchase_data.ship.clone());
+        Ok(ship_purchase_data)
+    }
+
+    fn install_mount(&self, ship: &str, mount: &ShipMountSymbol) -> ApiResult<MountsData> {
+        let mounts_data = self.api.install_mount(ship, mount)?;
+        self.update(ship, |ship_data| {
+            ship_data.mounts = mounts_data.mounts.clone();
+            ship_data.cargo = mounts_data.cargo.clone();
+        });
+        Ok(mounts_data)
+    }
+
+    fn remove_mount(&self, ship: &str, mount: &ShipMountSymbol) -> ApiResult<MountsData> {
+        let mounts_data = self.api.remove_mount(ship, mount)?;
+        self.update(ship, |ship_data| {
+            ship_data.mounts = mounts_data.mounts.clone();
+            ship_data.cargo = mounts_data.cargo.clone();
+        });
+        Ok(mounts_data)
+    }
+
+    fn install_module(&self, ship: &str, module: &ShipModuleSymbol) -> ApiResult<ModulesData> {
+        let modules_data = self.api.install_module(ship, module)?;
+        self.update(ship, |ship_data| {
+            ship_data.modules = modules_data.modules.clone();
+            ship_data.cargo = modules_data.cargo.clone();
+        });
+        Ok(modules_data)
+    }
+
+    fn remove_module(&self, ship: &str, module: &ShipModuleSymbol) -> ApiResult<ModulesData> {
+        let modules_data = self.api.remove_module(ship, module)?;
+        self.update(ship, |ship_data| {
+            ship_data.modules = modules_data.modules.clone();
+            ship_data.cargo = modules_data.cargo.clone();
+        });
+        Ok(modules_data)
+    }
+
+    fn purchase_cargo(
+        &self,
+        ship: &str,
+        trade_symbol: &TradeSymbol,
+        units: u32,
+    ) -> ApiResult<TradeData> {
+        let trade_data = self.api.purchase_cargo(ship, trade_symbol, units)?;
+        self.update(ship, |ship_data| ship_data.cargo = trade_data.cargo.clone());
+        Ok(trade_data)
+    }
+
+    fn list_contracts(&self) -> ApiResult<Vec<ContractData>> {
+        self.api.list_contracts()
+    }
+
+    fn accept_contract(&self, contract_id: &str) -> ApiResult<ContractUpdateData> {
+        self.api.accept_contract(contract_id)
+    }
+
+    fn deliver_contract(
+        &self,
+        contract_id: &str,
+        ship: &str,
+        trade_symbol: &TradeSymbol,
+        units: u32,
+    ) -> ApiResult<DeliveryData> {
+        let delivery_data = self
+            .api
+            .deliver_contract(contract_id, ship, trade_symbol, units)?;
+        self.update(ship, |ship_data| {
+            ship_data.cargo = delivery_data.cargo.clone()
+        });
+        Ok(delivery_data)
+    }
+
+    fn fulfill_contract(&self, contract_id: &str) -> ApiResult<ContractUpdateData> {
+        self.api.fulfill_contract(contract_id)
+    }
+
+    fn negotiate_contract(&self, ship: &str) -> ApiResult<ContractData> {
+        self.api.negotiate_contract(ship)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Fleet;
+    use crate::{
+        api::{
+            client::{ApiClient, TraderApis},
+            data::{fixtures, Cooldown, ShipData, TradeSymbol, UnknownFields},
+            stub::{StubApi, EXTRACTION_UNITS, TRIP_FUEL},
+        },
+        automation::STATUS_IN_TRANSIT,
+    };
+
+    use chrono::{Duration, Utc};
+
+    fn symbols(ships: Vec<ShipData>) -> Vec<String> {
+        ships.into_iter().map(|ship| ship.symbol).collect()
+    }
+
+    #[test]
+    fn verify_fleet_queries() {
+        // Setup
+        let api_client = ApiClient::unregistered();
+        let fleet = Fleet::new(&api_client);
+        let mut travelling = fixtures::ship_data("TEST-1", "X1-TEST-B2");
+        travelling.nav.status = String::from(STATUS_IN_TRANSIT);
+        travelling.nav.route.arrival = Some(Utc::now() + Duration::minutes(5));
+        let mut loaded = fixtures::ship_data("TEST-2", "X1-TEST-A1");
+        loaded.cargo.units = 10;
+        fleet.track(travelling);
+        fleet.track(loaded);
+        fleet.track(fixtures::ship_data("TEST-3", "X1-TEST-A1"));
+        fleet.track(fixtures::ship_data("TEST-4", "X1-TEST-A1"));
+        fleet.update_cooldown(&Cooldown {
+            ship_symbol: String::from("TEST-4"),
+            total_seconds: 60,
+            remaining_seconds: 60,
+            expiration: Some(Utc::now() + Duration::minutes(1)),
+            extra: UnknownFields::default(),
+        });
+
+        // Test
+        fleet.update("TEST-3", |ship| ship.cargo.units = 5);
+
+        // Verify
+        assert_eq!(symbols(fleet.idle_ships()), vec!["TEST-2", "TEST-3"]);
+        assert_eq!(
+            symbols(fleet.ships_at("X1-TEST-A1")),
+            vec!["TEST-2", "TEST-3", "TEST-4"]
+        );
+        assert_eq!(
+            symbols(fleet.ships_with_empty_cargo()),
+            vec!["TEST-1", "TEST-4"]
+        );
+        assert!(fleet.active_cooldown("TEST-4").is_some());
+    }
+
+    #[test]
+    fn verify_fleet_mirrors_requests() {
+        // Setup
+        let stub_api = StubApi::new(vec![
+            fixtures::ship_data("TEST-1", "X1-TEST-A1"),
+            fixtures::ship_data("TEST-2", "X1-TEST-A1"),
+            fixtures::ship_data("TEST-3", "X1-TEST-A1"),
+            fixtures::ship_data("TEST-4", "X1-TEST-A1"),
+        ]);
+        let fleet = Fleet::load(&stub_api).unwrap();
+        let fuel_before_trip = fleet.ship("TEST-1").unwrap().fuel.current;
+
+        // Test
+        fleet.navigate_ship("TEST-1", "X1-TEST-B2").unwrap();
+        fleet.extract_resources("TEST-2", None).unwrap();
+        fleet
+            .transfer_cargo("TEST-2", "TEST-3", &TradeSymbol::IronOre, 2)
+            .unwrap();
+        fleet.extract_resources("TEST-4", None).unwrap();
+        fleet.scrap_ship("TEST-4").unwrap();
+
+        // Verify
+        let travelling = fleet.ship("TEST-1").unwrap();
+        assert_eq!(travelling.nav.waypoint_symbol, "X1-TEST-B2");
+        assert_eq!(travelling.nav.status, STATUS_IN_TRANSIT);
+        assert_eq!(travelling.fuel.current, fuel_before_trip - TRIP_FUEL);
+        assert_eq!(
+            fleet.ship("TEST-2").unwrap().cargo.units,
+            EXTRACTION_UNITS - 2
+        );
+        assert_eq!(fleet.ship("TEST-3").unwrap().cargo.units, 2);
+        assert!(fleet.active_cooldown("TEST-2").is_some());
+        assert!(fleet.ship("TEST-4").is_none());
+        assert!(fleet.active_cooldown("TEST-4").is_none());
+        assert_eq!(symbols(fleet.idle_ships()), vec!["TEST-3"]);
+        assert_eq!(
+            symbols(stub_api.list_ships().unwrap()),
+            symbols(fleet.ships())
+        );
+    }
+}
@@ -0,0 +1,264 @@
+use crate::{
+    api::data::{without_schema_drift, MarketData, TradeSymbol, WaypointSymbol},
+    ledger, utils,
+};
+
+use serde::{Deserialize, Serialize};
+use std::{
+    fs,
+    io::Write,
+    ops::Range,
+    path::{Path, PathBuf},
+    sync::Mutex,
+};
+
+const HISTORY_FILE_NAME: &str = "price_history.jsonl";
+
+/// Held while a history file is read or appended to.
+static HISTORY_FILE_LOCK: Mutex<()> = Mutex::new(());
+
+pub type HistoryResult<T> = Result<T, HistoryError>;
+
+#[derive(Clone, Copy, Debug)]
+pub enum HistoryError {
+    FileWrite,
+}
+
+/// A single observation of a trade good's market conditions.
+#[derive(Clone, Debug, Eq, PartialEq, Deserialize, Serialize)]
+pub struct PriceRecord {
+    /// Seconds since the Unix epoch at which the market was observed.
+    pub timestamp: u64,
+    pub waypoint_symbol: WaypointSymbol,
+    pub trade_symbol: TradeSymbol,
+    pub purchase_price: u32,
+    pub sell_price: u32,
+    pub supply: String,
+    pub trade_volume: u32,
+    /// Profile of the agent that observed the market, when several agents are run from one process. Prices are
+    /// shared between the agents all the same, since they trade in the same universe.
+    #[serde(default)]
+    pub agent: Option<String>,
+}
+
+fn get_default_history_file_path() -> PathBuf {
+    utils::get_app_file_path(HISTORY_FILE_NAME)
+}
+
+/// Middleware function for appending every priced good of a market to the history file.
+///
+/// * `market_data` - [`MarketData`] to be recorded.
+/// * `timestamp` - seconds since the Unix epoch at which the market was observed.
+/// * `history_file_path` - [`Path`] to the history file.
+///
+/// Returns [`HistoryResult`] containing the number of records written, or [`HistoryError::FileWrite`] if the
+/// operation fails.
+fn append_market(
+    market_data: &MarketData,
+    timestamp: u64,
+    history_file_path: &Path,
+) -> HistoryResult<usize> {
+    let Some(ref trade_goods) = market_data.trade_goods else {
+        return Ok(0);
+    };
+
+    let _history_file_lock = utils::lock_app_file(&HISTORY_FILE_LOCK);
+    let mut history_file = fs::OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(history_file_path)
+        .map_err(|_| HistoryError::FileWrite)?;
+
+    // Records are stored one per line so that new observations can be appended without rewriting the file.
+    let mut history_lines = String::new();
+    for trade_good in trade_goods {
+        let price_record = PriceRecord {
+            timestamp,
+            waypoint_symbol: market_data.symbol.clone(),
+            trade_symbol: trade_good.symbol.clone(),
+            purchase_price: trade_good.purchase_price,
+            sell_price: trade_good.sell_price,
+            supply: trade_good.supply.clone(),
+            trade_volume: trade_good.trade_volume,
+            agent: ledger::current_agent(),
+        };
+        let record_str =
+            serde_json::to_string(&price_record).map_err(|_| HistoryError::FileWrite)?;
+        history_lines.push_str(&record_str);
+        history_lines.push('\n');
+    }
+
+    history_file
+        .write_all(history_lines.as_bytes())
+        .map_err(|_| HistoryError::FileWrite)?;
+    Ok(trade_goods.len())
+}
+
+/// Middleware function for reading every record in the history file. Malformed lines are skipped.
+///
+/// * `history_file_path` - [`Path`] to the history file.
+///
+/// Returns all [`PriceRecord`]s in the order they were recorded, which is empty if the file cannot be read.
+fn read_history(history_file_path: &Path) -> Vec<PriceRecord> {
+    let _history_file_lock = utils::lock_app_file(&HISTORY_FILE_LOCK);
+    fs::read_to_string(history_file_path)
+        .map(|history_str| {
+            history_str
+                .lines()
+                .filter_map(|line| without_schema_drift(|| serde_json::from_str(line)).ok())
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+/// Records the current prices of a market into the default history file.
+///
+/// Markets fetched without a ship present carry no prices and record nothing.
+///
+/// * `market_data` - [`MarketData`] to be recorded.
+///
+/// Returns [`HistoryResult`] containing the number of records written, or [`HistoryError::FileWrite`] if the
+/// operation fails.
+pub fn record_market(market_data: &MarketData) -> HistoryResult<usize> {
+    append_market(
+        market_data,
+        utils::now_secs(),
+        &get_default_history_file_path(),
+    )
+}
+
+/// Gets the recorded prices of a good at a market.
+///
+/// * `waypoint` - symbol of the market's waypoint.
+/// * `good` - symbol of the trade good.
+/// * `range` - timestamps, in seconds since the Unix epoch, to include.
+///
+/// Returns the matching [`PriceRecord`]s, oldest first.
+pub fn prices(waypoint: &str, good: &TradeSymbol, range: Range<u64>) -> Vec<PriceRecord> {
+    read_history(&get_default_history_file_path())
+        .into_iter()
+        .filter(|record| {
+            record.waypoint_symbol == waypoint
+                && record.trade_symbol == *good
+                && range.contains(&record.timestamp)
+        })
+        .collect()
+}
+
+/// Gets the most recent recorded price of every good at every market.
+///
+/// Returns one [`PriceRecord`] per market and good, in no particular order.
+pub fn latest_prices() -> Vec<PriceRecord> {
+    latest_of(read_history(&get_default_history_file_path()))
+}
+
+fn latest_of(price_records: Vec<PriceRecord>) -> Vec<PriceRecord> {
+    let mut latest_records: Vec<PriceRecord> = Vec::new();
+    for record in price_records {
+        match latest_records.iter_mut().find(|latest| {
+            latest.waypoint_symbol == record.waypoint_symbol
+                && latest.trade_symbol == record.trade_symbol
+        }) {
+            Some(latest) if latest.timestamp <= record.timestamp => *latest = record,
+            Some(_) => {}
+            None => latest_records.push(record),
+        }
+    }
+    latest_records
+}
+
+/// Estimates how quickly the sell price of a good is changing using a least-squares fit.
+///
+/// * `price_records` - [`PriceRecord`]s of a single good at a single market, such as those returned by [`prices`].
+///
+/// Returns the trend in credits per hour, or [`Option::None`] if there are too few distinct observations.
+pub fn sell_price_trend(price_records: &[PriceRecord]) -> Option<f64> {
+    let count = price_records.len() as f64;
+    let mean_hours = price_records
+        .iter()
+        .map(|record| record.timestamp as f64 / 3600.0)
+        .sum::<f64>()
+        / count;
+    let mean_price = price_records
+        .iter()
+        .map(|record| record.sell_price as f64)
+        .sum::<f64>()
+        / count;
+
+    let (covariance, variance) =
+        price_records
+            .iter()
+            .fold((0.0, 0.0), |(covariance, variance), record| {
+                let hours_delta = record.timestamp as f64 / 3600.0 - mean_hours;
+                let price_delta = record.sell_price as f64 - mean_price;
+                (
+                    covariance + hours_delta * price_delta,
+                    variance + hours_delta * hours_delta,
+                )
+            });
+
+    (variance > 0.0).then(|| covariance / variance)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{append_market, latest_of, read_history, sell_price_trend, PriceRecord};
+    use crate::api::data::{MarketData, MarketTradeGood, TradeSymbol, UnknownFields};
+
+    use tempfile;
+
+    fn test_market_data(sell_price: u32) -> MarketData {
+        MarketData {
+            symbol: String::from("X1-TEST-A1"),
+            exports: vec![],
+            imports: vec![],
+            exchange: vec![],
+            transactions: None,
+            trade_goods: Some(vec![MarketTradeGood {
+                symbol: TradeSymbol::IronOre,
+                trade_volume: 100,
+                supply: String::from("MODERATE"),
+                purchase_price: sell_price + 2,
+                sell_price,
+                extra: UnknownFields::default(),
+            }]),
+            extra: UnknownFields::default(),
+        }
+    }
+
+    #[test]
+    fn verify_append_market() {
+        // Setup
+        let tmp_history_file = tempfile::NamedTempFile::new().unwrap();
+
+        // Test
+        append_market(&test_market_data(10), 3600, tmp_history_file.path()).unwrap();
+        append_market(&test_market_data(12), 7200, tmp_history_file.path()).unwrap();
+
+        // Verify
+        let history = read_history(tmp_history_file.path());
+        assert_eq!(history.len(), 2);
+        assert_eq!(history[1].trade_symbol, TradeSymbol::IronOre);
+        assert_eq!(history[1].sell_price, 12);
+        assert_eq!(history[1].purchase_price, 14);
+    }
+
+    #[test]
+    fn verify_latest_and_trend() {
+        // Setup
+        let tmp_history_file = tempfile::NamedTempFile::new().unwrap();
+        append_market(&test_market_data(10), 3600, tmp_history_file.path()).unwrap();
+        append_market(&test_market_data(14), 10800, tmp_history_file.path()).unwrap();
+        let history: Vec<PriceRecord> = read_history(tmp_history_file.path());
+
+        // Test
+        let latest = latest_of(history.clone());
+        let trend = sell_price_trend(&history);
+
+        // Verify
+        assert_eq!(latest.len(), 1);
+        assert_eq!(latest[0].sell_price, 14);
+        assert_eq!(trend, Some(2.0));
+        assert_eq!(sell_price_trend(&history[..1]), None);
+    }
+}
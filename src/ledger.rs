@@ -0,0 +1,522 @@
+use crate::{
+    api::data::{
+        without_schema_drift, ContractData, MarketTransaction, ShipModificationTransaction,
+        ShipPurchaseTransaction, ShipServiceTransaction, ShipSymbol,
+    },
+    utils,
+};
+
+use chrono::DateTime;
+use serde::{Deserialize, Serialize};
+use std::{
+    cell::RefCell,
+    collections::BTreeMap,
+    fmt::{Display, Formatter, Result as FmtResult},
+    fs,
+    io::Write,
+    ops::Range,
+    path::{Path, PathBuf},
+    sync::Mutex,
+};
+
+const LEDGER_FILE_NAME: &str = "ledger.jsonl";
+
+/// Held while a ledger file is read or appended to.
+static LEDGER_FILE_LOCK: Mutex<()> = Mutex::new(());
+
+/// Group used in reports for entries that don't belong to a ship or behavior, such as contract payments.
+const UNATTRIBUTED: &str = "-";
+
+pub type LedgerResult<T> = Result<T, LedgerError>;
+
+#[derive(Clone, Copy, Debug)]
+pub enum LedgerError {
+    FileWrite,
+}
+
+thread_local! {
+    /// Name of the automation behavior whose requests are being made on this thread, if any.
+    static CURRENT_BEHAVIOR: RefCell<Option<String>> = const { RefCell::new(None) };
+    /// Profile of the agent whose requests are being made on this thread, when several agents are run at once.
+    static CURRENT_AGENT: RefCell<Option<String>> = const { RefCell::new(None) };
+}
+
+/// What the credits of a [`LedgerEntry`] were earned or spent on.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Deserialize, Serialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum EntryKind {
+    Purchase,
+    Sale,
+    ContractPayment,
+    ShipPurchase,
+    Refuel,
+    Repair,
+    Scrap,
+    ShipModification,
+}
+impl Display for EntryKind {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        let kind_value = serde_json::to_value(self).map_err(|_| std::fmt::Error)?;
+        write!(f, "{}", kind_value.as_str().unwrap_or_default())
+    }
+}
+
+/// A single change to the agent's credits.
+#[derive(Clone, Debug, Eq, PartialEq, Deserialize, Serialize)]
+pub struct LedgerEntry {
+    /// Seconds since the Unix epoch at which the credits changed hands.
+    pub timestamp: u64,
+    pub kind: EntryKind,
+    /// Credits earned, or a negative amount for credits spent.
+    pub amount: i64,
+    /// Ship that made the transaction, if any.
+    pub ship_symbol: Option<ShipSymbol>,
+    /// Automation behavior the ship was following at the time, if any.
+    pub behavior: Option<String>,
+    /// Profile of the agent that made the transaction, when several agents are run from one process.
+    #[serde(default)]
+    pub agent: Option<String>,
+    pub description: String,
+}
+impl LedgerEntry {
+    /// Creates a [`LedgerEntry`] attributed to the agent and behavior currently running on this thread, if any.
+    ///
+    /// * `timestamp` - seconds since the Unix epoch at which the credits changed hands.
+    /// * `kind` - [`EntryKind`] of the entry.
+    /// * `amount` - credits earned, or a negative amount for credits spent.
+    /// * `ship_symbol` - ship that made the transaction, if any.
+    /// * `description` - human-readable summary of the transaction.
+    pub fn new(
+        timestamp: u64,
+        kind: EntryKind,
+        amount: i64,
+        ship_symbol: Option<&str>,
+        description: String,
+    ) -> Self {
+        Self {
+            timestamp,
+            kind,
+            amount,
+            ship_symbol: ship_symbol.map(str::to_string),
+            behavior: CURRENT_BEHAVIOR.with(|behavior| behavior.borrow().clone()),
+            agent: current_agent(),
+            description,
+        }
+    }
+}
+
+/// Profit of a group of ledger entries, such as those of a single ship.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ProfitRow {
+    pub group: String,
+    pub income: i64,
+    pub expenses: i64,
+}
+impl ProfitRow {
+    /// Credits earned minus credits spent.
+    pub fn profit(&self) -> i64 {
+        self.income - self.expenses
+    }
+}
+
+/// Attributes every entry recorded while running a function to an automation behavior.
+///
+/// * `behavior` - name of the behavior.
+/// * `run` - the function, which makes requests on behalf of the behavior.
+///
+/// Returns whatever the function returns.
+pub fn with_behavior<T>(behavior: &str, run: impl FnOnce() -> T) -> T {
+    let previous_behavior =
+        CURRENT_BEHAVIOR.with(|current| current.replace(Some(behavior.to_string())));
+    let result = run();
+    CURRENT_BEHAVIOR.with(|current| current.replace(previous_behavior));
+    result
+}
+
+/// Attributes every entry and price recorded while running a function to an agent, so agents run from the same
+/// process can be told apart.
+///
+/// * `agent` - name of the agent's profile.
+/// * `run` - the function, which makes requests on behalf of the agent.
+///
+/// Returns whatever the function returns.
+pub fn with_agent<T>(agent: &str, run: impl FnOnce() -> T) -> T {
+    let previous_agent = CURRENT_AGENT.with(|current| current.replace(Some(agent.to_string())));
+    let result = run();
+    CURRENT_AGENT.with(|current| current.replace(previous_agent));
+    result
+}
+
+/// Gets the profile of the agent whose requests are being made on this thread, if set by [`with_agent`].
+pub fn current_agent() -> Option<String> {
+    CURRENT_AGENT.with(|agent| agent.borrow().clone())
+}
+
+fn get_default_ledger_file_path() -> PathBuf {
+    utils::get_app_file_path(LEDGER_FILE_NAME)
+}
+
+/// Middleware function for appending an entry to the ledger file.
+///
+/// * `entry` - [`LedgerEntry`] to be recorded.
+/// * `ledger_file_path` - [`Path`] to the ledger file.
+///
+/// Returns [`LedgerResult`] containing unit on success, or [`LedgerError::FileWrite`] if the operation fails.
+fn append_entry(entry: &LedgerEntry, ledger_file_path: &Path) -> LedgerResult<()> {
+    let _ledger_file_lock = utils::lock_app_file(&LEDGER_FILE_LOCK);
+    let mut ledger_file = fs::OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(ledger_file_path)
+        .map_err(|_| LedgerError::FileWrite)?;
+
+    let mut entry_line = serde_json::to_string(entry).map_err(|_| LedgerError::FileWrite)?;
+    entry_line.push('\n');
+    ledger_file
+        .write_all(entry_line.as_bytes())
+        .map_err(|_| LedgerError::FileWrite)
+}
+
+/// Middleware function for reading every entry in the ledger file. Malformed lines are skipped.
+///
+/// * `ledger_file_path` - [`Path`] to the ledger file.
+///
+/// Returns all [`LedgerEntry`]s in the order they were recorded, which is empty if the file cannot be read.
+fn read_ledger(ledger_file_path: &Path) -> Vec<LedgerEntry> {
+    let _ledger_file_lock = utils::lock_app_file(&LEDGER_FILE_LOCK);
+    fs::read_to_string(ledger_file_path)
+        .map(|ledger_str| {
+            ledger_str
+                .lines()
+                .filter_map(|line| without_schema_drift(|| serde_json::from_str(line)).ok())
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+/// Records an entry into the default ledger file.
+///
+/// * `entry` - [`LedgerEntry`] to be recorded.
+///
+/// Returns [`LedgerResult`] containing unit on success, or [`LedgerError::FileWrite`] if the operation fails.
+pub fn record(entry: &LedgerEntry) -> LedgerResult<()> {
+    append_entry(entry, &get_default_ledger_file_path())
+}
+
+/// Records a purchase or sale at a market, including refuels, into the default ledger file.
+///
+/// * `kind` - [`EntryKind`] matching the request that made the transaction: [`EntryKind::Sale`], which earns
+///   credits, or [`EntryKind::Purchase`] or [`EntryKind::Refuel`], which cost them.
+/// * `transaction` - the [`MarketTransaction`].
+///
+/// Returns [`LedgerResult`] containing unit on success, or [`LedgerError::FileWrite`] if the operation fails.
+pub fn record_market_transaction(
+    kind: EntryKind,
+    transaction: &MarketTransaction,
+) -> LedgerResult<()> {
+    let total_price = i64::from(transaction.total_price);
+    let amount = match kind {
+        EntryKind::Sale => total_price,
+        _ => -total_price,
+    };
+    record(&LedgerEntry::new(
+        transaction.timestamp.timestamp().max(0) as u64,
+        kind,
+        amount,
+        Some(&transaction.ship_symbol),
+        format!(
+            "{} {} {} at {}",
+            transaction.r#type,
+            transaction.units,
+            transaction.trade_symbol,
+            transaction.waypoint_symbol
+        ),
+    ))
+}
+
+/// Records a repair or scrapping of a ship into the default ledger file.
+///
+/// * `kind` - either [`EntryKind::Repair`], which costs credits, or [`EntryKind::Scrap`], which earns them.
+/// * `transaction` - the [`ShipServiceTransaction`].
+///
+/// Returns [`LedgerResult`] containing unit on success, or [`LedgerError::FileWrite`] if the operation fails.
+pub fn record_ship_service(
+    kind: EntryKind,
+    transaction: &ShipServiceTransaction,
+) -> LedgerResult<()> {
+    let amount = match kind {
+        EntryKind::Scrap => transaction.total_price,
+        _ => -transaction.total_price,
+    };
+    record(&LedgerEntry::new(
+        transaction.timestamp.timestamp().max(0) as u64,
+        kind,
+        amount,
+        Some(&transaction.ship_symbol),
+        format!("{kind} at {}", transaction.waypoint_symbol),
+    ))
+}
+
+/// Records the purchase of a new ship into the default ledger file.
+///
+/// * `transaction` - the [`ShipPurchaseTransaction`].
+///
+/// Returns [`LedgerResult`] containing unit on success, or [`LedgerError::FileWrite`] if the operation fails.
+pub fn record_ship_purchase(transaction: &ShipPurchaseTransaction) -> LedgerResult<()> {
+    record(&LedgerEntry::new(
+        transaction.timestamp.timestamp().max(0) as u64,
+        EntryKind::ShipPurchase,
+        -transaction.price,
+        Some(&transaction.ship_symbol),
+        format!(
+            "{} at {}",
+            transaction.ship_type, transaction.waypoint_symbol
+        ),
+    ))
+}
+
+/// Records the installation or removal of a mount or module into the default ledger file.
+///
+/// * `transaction` - the [`ShipModificationTransaction`].
+///
+/// Returns [`LedgerResult`] containing unit on success, or [`LedgerError::FileWrite`] if the operation fails.
+pub fn record_ship_modification(transaction: &ShipModificationTransaction) -> LedgerResult<()> {
+    record(&LedgerEntry::new(
+        transaction.timestamp.timestamp().max(0) as u64,
+        EntryKind::ShipModification,
+        -transaction.total_price,
+        Some(&transaction.ship_symbol),
+        format!(
+            "{} at {}",
+            transaction.trade_symbol, transaction.waypoint_symbol
+        ),
+    ))
+}
+
+/// Records a payment received for a contract into the default ledger file.
+///
+/// * `contract` - [`ContractData`] of the contract.
+/// * `amount` - credits received.
+///
+/// Returns [`LedgerResult`] containing unit on success, or [`LedgerError::FileWrite`] if the operation fails.
+pub fn record_contract_payment(contract: &ContractData, amount: i64) -> LedgerResult<()> {
+    record(&LedgerEntry::new(
+        utils::now_secs(),
+        EntryKind::ContractPayment,
+        amount,
+        None,
+        format!("contract {} for {}", contract.id, contract.faction_symbol),
+    ))
+}
+
+/// Gets the recorded ledger entries.
+///
+/// * `range` - timestamps, in seconds since the Unix epoch, to include.
+///
+/// Returns the matching [`LedgerEntry`]s, oldest first.
+pub fn entries(range: Range<u64>) -> Vec<LedgerEntry> {
+    read_ledger(&get_default_ledger_file_path())
+        .into_iter()
+        .filter(|entry| range.contains(&entry.timestamp))
+        .collect()
+}
+
+/// Totals the income and expenses of ledger entries in groups.
+///
+/// * `entries` - the [`LedgerEntry`]s.
+/// * `group_of` - gets the group an entry belongs to.
+///
+/// Returns a [`ProfitRow`] per group, ordered by group.
+pub fn profit_by(
+    entries: &[LedgerEntry],
+    group_of: impl Fn(&LedgerEntry) -> String,
+) -> Vec<ProfitRow> {
+    let mut totals: BTreeMap<String, (i64, i64)> = BTreeMap::new();
+    for entry in entries {
+        let (income, expenses) = totals.entry(group_of(entry)).or_default();
+        if entry.amount >= 0 {
+            *income += entry.amount;
+        } else {
+            *expenses -= entry.amount;
+        }
+    }
+    totals
+        .into_iter()
+        .map(|(group, (income, expenses))| ProfitRow {
+            group,
+            income,
+            expenses,
+        })
+        .collect()
+}
+
+/// Totals the profit of ledger entries per ship. Ships of agents run alongside others are grouped under their
+/// agent's profile, e.g. `trader/TEST-1`.
+///
+/// * `entries` - the [`LedgerEntry`]s.
+pub fn profit_by_ship(entries: &[LedgerEntry]) -> Vec<ProfitRow> {
+    profit_by(entries, |entry| {
+        let ship_symbol = entry.ship_symbol.as_deref().unwrap_or(UNATTRIBUTED);
+        match entry.agent {
+            Some(ref agent) => format!("{agent}/{ship_symbol}"),
+            None => ship_symbol.to_string(),
+        }
+    })
+}
+
+/// Totals the profit of ledger entries per agent profile.
+///
+/// * `entries` - the [`LedgerEntry`]s.
+pub fn profit_by_agent(entries: &[LedgerEntry]) -> Vec<ProfitRow> {
+    profit_by(entries, |entry| {
+        entry
+            .agent
+            .clone()
+            .unwrap_or_else(|| UNATTRIBUTED.to_string())
+    })
+}
+
+/// Totals the profit of ledger entries per automation behavior.
+///
+/// * `entries` - the [`LedgerEntry`]s.
+pub fn profit_by_behavior(entries: &[LedgerEntry]) -> Vec<ProfitRow> {
+    profit_by(entries, |entry| {
+        entry
+            .behavior
+            .clone()
+            .unwrap_or_else(|| UNATTRIBUTED.to_string())
+    })
+}
+
+/// Totals the profit of ledger entries per hour, grouped by the UTC start of each hour.
+///
+/// * `entries` - the [`LedgerEntry`]s.
+pub fn profit_per_hour(entries: &[LedgerEntry]) -> Vec<ProfitRow> {
+    profit_by(entries, |entry| {
+        let hour_start = entry.timestamp - entry.timestamp % 3600;
+        DateTime::from_timestamp(hour_start as i64, 0)
+            .map(|hour| hour.format("%Y-%m-%dT%H:00Z").to_string())
+            .unwrap_or_default()
+    })
+}
+
+/// Formats a profit report as CSV.
+///
+/// * `rows` - the [`ProfitRow`]s of the report.
+pub fn profit_csv(rows: &[ProfitRow]) -> String {
+    let mut csv = String::from("group,income,expenses,profit\n");
+    for row in rows {
+        csv.push_str(&format!(
+            "{},{},{},{}\n",
+            csv_field(&row.group),
+            row.income,
+            row.expenses,
+            row.profit()
+        ));
+    }
+    csv
+}
+
+/// Formats ledger entries as CSV.
+///
+/// * `entries` - the [`LedgerEntry`]s.
+pub fn entries_csv(entries: &[LedgerEntry]) -> String {
+    let mut csv = String::from("timestamp,kind,amount,agent,ship,behavior,description\n");
+    for entry in entries {
+        csv.push_str(&format!(
+            "{},{},{},{},{},{},{}\n",
+            entry.timestamp,
+            entry.kind,
+            entry.amount,
+            csv_field(entry.agent.as_deref().unwrap_or_default()),
+            csv_field(entry.ship_symbol.as_deref().unwrap_or_default()),
+            csv_field(entry.behavior.as_deref().unwrap_or_default()),
+            csv_field(&entry.description)
+        ));
+    }
+    csv
+}
+
+/// Quotes a CSV field if it contains anything that would break the row apart.
+fn csv_field(field: &str) -> String {
+    if field.contains([',', '"', '\n']) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        append_entry, entries_csv, profit_by_agent, profit_by_behavior, profit_by_ship, profit_csv,
+        profit_per_hour, read_ledger, with_agent, with_behavior, EntryKind, LedgerEntry,
+    };
+
+    use tempfile;
+
+    #[test]
+    fn verify_profit_reports() {
+        // Setup
+        let tmp_ledger_file = tempfile::NamedTempFile::new().unwrap();
+        let entries = [
+            with_behavior("mining", || {
+                LedgerEntry::new(
+                    3600,
+                    EntryKind::Sale,
+                    500,
+                    Some("TEST-1"),
+                    String::from("SELL 10 IRON_ORE, at X1-TEST-A1"),
+                )
+            }),
+            LedgerEntry::new(
+                5400,
+                EntryKind::Refuel,
+                -120,
+                Some("TEST-1"),
+                String::from("fuel"),
+            ),
+            LedgerEntry::new(
+                7200,
+                EntryKind::ContractPayment,
+                1000,
+                None,
+                String::from("contract"),
+            ),
+            with_agent("trader", || {
+                LedgerEntry::new(
+                    9000,
+                    EntryKind::Purchase,
+                    -200,
+                    Some("TEST-1"),
+                    String::from("PURCHASE 10 FUEL at X1-TEST-B2"),
+                )
+            }),
+        ];
+        for entry in &entries {
+            append_entry(entry, tmp_ledger_file.path()).unwrap();
+        }
+
+        // Test
+        let ledger = read_ledger(tmp_ledger_file.path());
+        let by_ship = profit_by_ship(&ledger);
+        let by_behavior = profit_by_behavior(&ledger);
+        let by_agent = profit_by_agent(&ledger);
+        let per_hour = profit_per_hour(&ledger);
+
+        // Verify
+        assert_eq!(ledger, entries);
+        assert_eq!(
+            profit_csv(&by_ship),
+            "group,income,expenses,profit\n-,1000,0,1000\nTEST-1,500,120,380\ntrader/TEST-1,0,200,-200\n"
+        );
+        assert_eq!(by_agent[1].group, "trader");
+        assert_eq!(by_agent[1].profit(), -200);
+        assert_eq!(by_behavior[1].group, "mining");
+        assert_eq!(by_behavior[1].profit(), 500);
+        assert_eq!(per_hour[0].group, "1970-01-01T01:00Z");
+        assert_eq!(per_hour[0].profit(), 380);
+        assert!(entries_csv(&ledger)
+            .contains("3600,SALE,500,,TEST-1,mining,\"SELL 10 IRON_ORE, at X1-TEST-A1\"\n"));
+    }
+}
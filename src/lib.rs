@@ -1,5 +1,29 @@
-mod api;
-mod utils;
+pub mod api;
+/// Framework for automating ships with pluggable behaviors.
+pub mod automation;
+/// Command line interface for the client.
+pub mod cli;
+/// Generation of API models and endpoint paths from the SpaceTraders OpenAPI document.
+pub mod codegen;
+/// In-memory mirror of the fleet, kept in sync with every request made through it.
+pub mod fleet;
+/// Local time-series store of observed market prices.
+pub mod history;
+/// Local record of every credit earned and spent, with profit reports.
+pub mod ledger;
+/// Moving cargo between ships, so haulers can collect from mining fleets.
+pub mod logistics;
+/// Fuel-aware route planning between waypoints.
+pub mod nav;
+/// Running the automation of several agents from one process.
+pub mod orchestrator;
+/// Guided registration of new agents.
+pub mod registration;
+/// Waking up for ship arrivals, cooldowns and contract deadlines.
+pub mod scheduler;
+/// Finding profitable trades between known markets.
+pub mod trade;
+pub mod utils;
 
 /// Used for quick & dirty prototyping and validation; won't be kept around long-term.
 pub mod proto {
@@ -36,6 +60,18 @@ pub mod proto {
         println!("{agent_data}");
     }
 
+    pub fn test_server_status(api_client: &ApiClient) {
+        println!("Getting server status:");
+        let status_data = api_client
+            .get_status()
+            .expect("Error getting server status!");
+        println!("{status_data}");
+        println!(
+            "Server reset since registration: {:?}",
+            api_client.has_server_reset()
+        );
+    }
+
     pub fn test_location_data(api_client: &ApiClient) {
         println!("Getting location data:");
         let location_data = api_client
@@ -56,6 +92,7 @@ pub mod proto {
         println!("Writing token {token}");
         utils::config::write_default_config_file(ConfigData {
             token: token.to_string(),
+            ..Default::default()
         })
         .unwrap();
 
@@ -0,0 +1,165 @@
+use crate::api::{
+    client::{ApiResult, TraderApis},
+    data::{CargoInfo, CargoItem, ShipData, TradeSymbol},
+};
+
+/// A single move of goods from one ship's cargo hold into a hauler's.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Transfer {
+    pub from_ship: String,
+    pub trade_symbol: TradeSymbol,
+    pub units: u32,
+}
+
+/// Whether two ships can transfer cargo between each other, which needs them at the same waypoint and either both
+/// docked or both in orbit.
+///
+/// * `ship` - [`ShipData`] of one ship.
+/// * `other` - [`ShipData`] of the other ship.
+pub fn is_colocated(ship: &ShipData, other: &ShipData) -> bool {
+    ship.symbol != other.symbol
+        && ship.nav.waypoint_symbol == other.nav.waypoint_symbol
+        && ship.nav.status == other.nav.status
+}
+
+/// Plans the transfers that fill a hauler with the cargo of the ships next to it.
+///
+/// Ships are emptied in the order given. Goods are moved whole while there's room, and the last good that fits
+/// is split so the hauler leaves full.
+///
+/// * `hauler` - [`ShipData`] of the ship collecting the cargo.
+/// * `ships` - [`ShipData`] of the candidate ships, of which only those co-located with the hauler are used.
+///
+/// Returns each [`Transfer`] to make, in order, none of which overfill the hauler.
+pub fn plan_consolidation(hauler: &ShipData, ships: &[ShipData]) -> Vec<Transfer> {
+    let mut free_space = hauler.cargo.capacity.saturating_sub(hauler.cargo.units);
+    let mut transfers = Vec::new();
+    for ship in ships.iter().filter(|ship| is_colocated(hauler, ship)) {
+        for cargo_item in &ship.cargo.inventory {
+            if free_space == 0 {
+                return transfers;
+            }
+            let units = u32::from(cargo_item.units).min(free_space);
+            if units == 0 {
+                continue;
+            }
+            free_space -= units;
+            transfers.push(Transfer {
+                from_ship: ship.symbol.clone(),
+                trade_symbol: cargo_item.symbol.clone(),
+                units,
+            });
+        }
+    }
+    transfers
+}
+
+/// Consolidates the cargo of the ships next to a hauler into its cargo hold, up to its capacity.
+///
+/// The cargo of every ship involved is updated with the results, so mining ships can get back to work and only
+/// the hauler has to fly to market.
+///
+/// * `api` - client used to make the transfers.
+/// * `hauler` - [`ShipData`] of the ship collecting the cargo.
+/// * `ships` - [`ShipData`] of the candidate ships, of which only those co-located with the hauler are used.
+///
+/// Returns the total units moved, or the [`ApiError`](crate::api::client::ApiError) reason for failure.
+pub fn consolidate_cargo(
+    api: &dyn TraderApis,
+    hauler: &mut ShipData,
+    ships: &mut [ShipData],
+) -> ApiResult<u32> {
+    let mut units_moved = 0;
+    for transfer in plan_consolidation(hauler, ships) {
+        let transfer_data = api.transfer_cargo(
+            &transfer.from_ship,
+            &hauler.symbol,
+            &transfer.trade_symbol,
+            transfer.units,
+        )?;
+
+        if let Some(ship) = ships
+            .iter_mut()
+            .find(|ship| ship.symbol == transfer.from_ship)
+        {
+            ship.cargo = transfer_data.cargo;
+        }
+        match transfer_data.target_cargo {
+            Some(target_cargo) => hauler.cargo = target_cargo,
+            None => add_cargo(&mut hauler.cargo, &transfer.trade_symbol, transfer.units),
+        }
+        units_moved += transfer.units;
+    }
+    Ok(units_moved)
+}
+
+/// Adds goods to a cargo hold, for when the API doesn't report the updated hold.
+pub(crate) fn add_cargo(cargo: &mut CargoInfo, trade_symbol: &TradeSymbol, units: u32) {
+    cargo.units += units;
+    let units = u16::try_from(units).unwrap_or(u16::MAX);
+    match cargo
+        .inventory
+        .iter_mut()
+        .find(|cargo_item| cargo_item.symbol == *trade_symbol)
+    {
+        Some(cargo_item) => cargo_item.units = cargo_item.units.saturating_add(units),
+        None => cargo.inventory.push(CargoItem {
+            symbol: trade_symbol.clone(),
+            name: trade_symbol.to_string(),
+            description: String::new(),
+            units,
+            extra: Default::default(),
+        }),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{plan_consolidation, Transfer};
+    use crate::api::data::{fixtures, CargoItem, ShipData, TradeSymbol, UnknownFields};
+
+    fn miner(symbol: &str, waypoint: &str, ore_units: u16) -> ShipData {
+        let mut ship = fixtures::ship_data(symbol, waypoint);
+        ship.cargo.units = ore_units.into();
+        ship.cargo.inventory = vec![CargoItem {
+            symbol: TradeSymbol::IronOre,
+            name: String::from("Iron Ore"),
+            description: String::new(),
+            units: ore_units,
+            extra: UnknownFields::default(),
+        }];
+        ship
+    }
+
+    #[test]
+    fn verify_consolidation_plan() {
+        // Setup
+        let mut hauler = fixtures::ship_data("HAULER-1", "X1-TEST-A1");
+        hauler.cargo.capacity = 40;
+        let ships = vec![
+            miner("MINER-1", "X1-TEST-A1", 15),
+            miner("MINER-2", "X1-TEST-B2", 30),
+            miner("MINER-3", "X1-TEST-A1", 30),
+        ];
+
+        // Test
+        let transfers = plan_consolidation(&hauler, &ships);
+
+        // Verify
+        assert_eq!(
+            transfers,
+            vec![
+                Transfer {
+                    from_ship: String::from("MINER-1"),
+                    trade_symbol: TradeSymbol::IronOre,
+                    units: 15,
+                },
+                Transfer {
+                    from_ship: String::from("MINER-3"),
+                    trade_symbol: TradeSymbol::IronOre,
+                    units: 25,
+                },
+            ]
+        );
+    }
+}
@@ -1,8 +1,16 @@
 // mod api;
 // mod utils;
 
-use spacetraders_client::proto;
+use spacetraders_client::{cli, proto};
 fn main() {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    if !args.is_empty() {
+        if let Err(e) = cli::run(&args) {
+            println!("{e}");
+        }
+        return;
+    }
+
     proto::test_create_api_client();
     proto::test_read_config();
     proto::test_write_config("TEST_TOKEN");
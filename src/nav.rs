@@ -0,0 +1,471 @@
+use crate::{
+    api::data::{
+        FlightMode, LocationData, ShipData, TradeSymbol, WaypointSymbol, WaypointTraitSymbol,
+    },
+    history::PriceRecord,
+};
+
+use std::{
+    cmp::Reverse,
+    collections::{BinaryHeap, HashMap, HashSet, VecDeque},
+    fmt::{Display, Formatter, Result as FmtResult},
+};
+
+/// A single trip between two waypoints.
+#[derive(Clone, Debug)]
+pub struct Leg {
+    pub from: WaypointSymbol,
+    pub to: WaypointSymbol,
+    pub flight_mode: FlightMode,
+    pub distance: f64,
+    /// Whether the ship should fill its tank at `from` before departing.
+    pub refuel: bool,
+    pub fuel_used: u32,
+    pub duration_secs: u32,
+}
+
+/// A planned route made up of one or more [`Leg`]s.
+#[derive(Clone, Debug, Default)]
+pub struct Itinerary {
+    pub legs: Vec<Leg>,
+    pub total_fuel: u32,
+    pub total_secs: u32,
+}
+impl Display for Itinerary {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        for leg in &self.legs {
+            let refuel = if leg.refuel { " (refuel first)" } else { "" };
+            writeln!(
+                f,
+                "{} -> {} [{}]{refuel}: {:.1} units, {} fuel, {}s",
+                leg.from, leg.to, leg.flight_mode, leg.distance, leg.fuel_used, leg.duration_secs
+            )?;
+        }
+        write!(f, "Total: {} fuel, {}s", self.total_fuel, self.total_secs)
+    }
+}
+
+/// Gets the waypoints at which ships can buy fuel.
+///
+/// Not every marketplace sells fuel, so only those recorded trading it count. Markets that haven't been
+/// visited yet are left out rather than risking a stop where the ship can't refuel.
+///
+/// * `waypoints` - [`LocationData`] of the candidate waypoints.
+/// * `price_records` - [`PriceRecord`]s of the markets observed so far, e.g. from
+///   [`latest_prices`](crate::history::latest_prices).
+///
+/// Returns the symbols of every marketplace known to sell fuel.
+pub fn fuel_stations(
+    waypoints: &[LocationData],
+    price_records: &[PriceRecord],
+) -> HashSet<WaypointSymbol> {
+    let fuel_markets: HashSet<&str> = price_records
+        .iter()
+        .filter(|record| record.trade_symbol == TradeSymbol::Fuel)
+        .map(|record| record.waypoint_symbol.as_str())
+        .collect();
+    waypoints
+        .iter()
+        .filter(|location_data| {
+            location_data.has_trait(&WaypointTraitSymbol::Marketplace)
+                && fuel_markets.contains(location_data.symbol.as_str())
+        })
+        .map(|location_data| location_data.symbol.clone())
+        .collect()
+}
+
+/// Picks the fastest [`FlightMode`] able to cover a distance with the given fuel.
+///
+/// Returns the [`FlightMode`] and the fuel it uses, or [`Option::None`] if not even drifting is possible.
+fn fastest_mode(distance: f64, available_fuel: u32, allow_burn: bool) -> Option<(FlightMode, u32)> {
+    let flight_modes = if allow_burn {
+        [FlightMode::Burn, FlightMode::Cruise, FlightMode::Drift].as_slice()
+    } else {
+        [FlightMode::Cruise, FlightMode::Drift].as_slice()
+    };
+    flight_modes
+        .iter()
+        .map(|flight_mode| (*flight_mode, flight_mode.fuel_cost(distance)))
+        .find(|(_, fuel_cost)| *fuel_cost <= available_fuel)
+}
+
+/// Plans the fastest route for a ship to a waypoint in its current system.
+///
+/// Legs only stop at fuel stations, where the ship fills its tank, and each leg uses the fastest flight mode
+/// the fuel on board allows. Ships without a fuel tank, such as probes, always cruise directly.
+///
+/// * `ship` - [`ShipData`] of the travelling ship.
+/// * `destination` - symbol of the waypoint to reach.
+/// * `waypoints` - [`LocationData`] of the waypoints in the system, including the ship's current one.
+/// * `fuel_stations` - symbols of the waypoints where fuel can be bought, e.g. from [`fuel_stations`].
+/// * `allow_burn` - whether [`FlightMode::Burn`] may be used, which is faster but uses twice the fuel.
+///
+/// Returns the fastest [`Itinerary`], or [`Option::None`] if the destination can't be reached.
+pub fn plan_route(
+    ship: &ShipData,
+    destination: &str,
+    waypoints: &[LocationData],
+    fuel_stations: &HashSet<WaypointSymbol>,
+    allow_burn: bool,
+) -> Option<Itinerary> {
+    let origin = ship.nav.waypoint_symbol.as_str();
+    if origin == destination {
+        return Some(Itinerary::default());
+    }
+
+    let locations: HashMap<&str, &LocationData> = waypoints
+        .iter()
+        .map(|location_data| (location_data.symbol.as_str(), location_data))
+        .collect();
+    let (origin_location, destination_location) =
+        (locations.get(origin)?, locations.get(destination)?);
+
+    if ship.fuel.capacity == 0 {
+        let distance = origin_location
+            .coords
+            .distance_to(&destination_location.coords);
+        let duration_secs = FlightMode::Cruise.travel_secs(distance, ship.engine.speed);
+        return Some(Itinerary {
+            legs: vec![Leg {
+                from: origin.to_string(),
+                to: destination.to_string(),
+                flight_mode: FlightMode::Cruise,
+                distance,
+                refuel: false,
+                fuel_used: 0,
+                duration_secs,
+            }],
+            total_fuel: 0,
+            total_secs: duration_secs,
+        });
+    }
+
+    // Only fuel stations are worth stopping at on the way, since travel costs grow with straight-line distance.
+    let stops: Vec<&str> = locations
+        .keys()
+        .copied()
+        .filter(|symbol| {
+            *symbol == origin || *symbol == destination || fuel_stations.contains(*symbol)
+        })
+        .collect();
+
+    // Dijkstra's algorithm over the stops, weighted by travel time.
+    let mut fastest_secs: HashMap<&str, u32> = HashMap::from([(origin, 0)]);
+    let mut previous_legs: HashMap<&str, Leg> = HashMap::new();
+    let mut queue = BinaryHeap::from([Reverse((0, origin))]);
+    while let Some(Reverse((elapsed_secs, current))) = queue.pop() {
+        if current == destination {
+            break;
+        }
+        if fastest_secs
+            .get(current)
+            .is_some_and(|secs| elapsed_secs > *secs)
+        {
+            continue;
+        }
+
+        let can_refuel = fuel_stations.contains(current);
+        let available_fuel = if can_refuel {
+            ship.fuel.capacity
+        } else if current == origin {
+            ship.fuel.current
+        } else {
+            0
+        };
+        for next in stops.iter().copied().filter(|symbol| *symbol != current) {
+            let distance = locations[current]
+                .coords
+                .distance_to(&locations[next].coords);
+            let Some((flight_mode, fuel_used)) = fastest_mode(distance, available_fuel, allow_burn)
+            else {
+                continue;
+            };
+
+            let duration_secs = flight_mode.travel_secs(distance, ship.engine.speed);
+            let next_secs = elapsed_secs + duration_secs;
+            if fastest_secs
+                .get(next)
+                .is_some_and(|secs| next_secs >= *secs)
+            {
+                continue;
+            }
+
+            let refuel = can_refuel && (current != origin || fuel_used > ship.fuel.current);
+            fastest_secs.insert(next, next_secs);
+            previous_legs.insert(
+                next,
+                Leg {
+                    from: current.to_string(),
+                    to: next.to_string(),
+                    flight_mode,
+                    distance,
+                    refuel,
+                    fuel_used,
+                    duration_secs,
+                },
+            );
+            queue.push(Reverse((next_secs, next)));
+        }
+    }
+
+    let mut legs = Vec::new();
+    let mut current = destination;
+    while current != origin {
+        let leg = previous_legs.remove(current)?;
+        current = locations.get(leg.from.as_str())?.symbol.as_str();
+        legs.push(leg);
+    }
+    legs.reverse();
+
+    Some(Itinerary {
+        total_fuel: legs.iter().map(|leg| leg.fuel_used).sum(),
+        total_secs: legs.iter().map(|leg| leg.duration_secs).sum(),
+        legs,
+    })
+}
+
+/// Plans the fewest jumps from a jump gate to any gate in another system, across the galaxy's jump gate network.
+///
+/// Gates are explored breadth-first, so connections are only looked up for gates closer than the destination.
+///
+/// * `origin_gate` - symbol of the jump gate in the ship's current system.
+/// * `destination_system` - symbol of the system to reach.
+/// * `connections` - gets the symbols of the gates connected to a gate, or the reason they couldn't be looked up.
+///
+/// Returns the symbols of the gates to jump to in order, [`Option::None`] if the system can't be reached, or the
+/// first error from looking up connections.
+pub fn plan_jumps<E>(
+    origin_gate: &str,
+    destination_system: &str,
+    mut connections: impl FnMut(&str) -> Result<Vec<WaypointSymbol>, E>,
+) -> Result<Option<Vec<WaypointSymbol>>, E> {
+    let in_destination = |gate: &str| gate.starts_with(&format!("{destination_system}-"));
+    if in_destination(origin_gate) {
+        return Ok(Some(Vec::new()));
+    }
+
+    let mut previous_gates: HashMap<WaypointSymbol, WaypointSymbol> = HashMap::new();
+    let mut queue = VecDeque::from([origin_gate.to_string()]);
+    while let Some(current) = queue.pop_front() {
+        for next in connections(&current)? {
+            if next == origin_gate || previous_gates.contains_key(&next) {
+                continue;
+            }
+            previous_gates.insert(next.clone(), current.clone());
+            if in_destination(&next) {
+                let mut jumps = vec![next];
+                while let Some(previous) = jumps.last().and_then(|gate| previous_gates.get(gate)) {
+                    if previous == origin_gate {
+                        break;
+                    }
+                    jumps.push(previous.clone());
+                }
+                jumps.reverse();
+                return Ok(Some(jumps));
+            }
+            queue.push_back(next);
+        }
+    }
+    Ok(None)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{fuel_stations, plan_jumps, plan_route};
+    use crate::{
+        api::data::{
+            fixtures, Coords, FlightMode, LocationData, TradeSymbol, TraitData, UnknownFields,
+            WaypointTraitSymbol, WaypointType,
+        },
+        history::PriceRecord,
+    };
+    use std::collections::HashMap;
+
+    fn location_data(symbol: &str, x: i32, marketplace: bool) -> LocationData {
+        LocationData {
+            system_symbol: String::from("X1-TEST"),
+            symbol: String::from(symbol),
+            r#type: WaypointType::Planet,
+            coords: Coords { x, y: 0 },
+            orbitals: None,
+            traits: marketplace.then(|| {
+                vec![TraitData {
+                    symbol: WaypointTraitSymbol::Marketplace,
+                    name: String::from("Marketplace"),
+                    description: String::new(),
+                    extra: UnknownFields::default(),
+                }]
+            }),
+            chart: None,
+            faction: None,
+            extra: UnknownFields::default(),
+        }
+    }
+
+    fn price_record(waypoint: &str, good: TradeSymbol) -> PriceRecord {
+        PriceRecord {
+            timestamp: 0,
+            waypoint_symbol: String::from(waypoint),
+            trade_symbol: good,
+            purchase_price: 72,
+            sell_price: 68,
+            supply: String::from("MODERATE"),
+            trade_volume: 100,
+            agent: None,
+        }
+    }
+
+    #[test]
+    fn verify_direct_route() {
+        // Setup
+        let ship = fixtures::ship_data("TEST-1", "X1-TEST-A1");
+        let waypoints = vec![
+            location_data("X1-TEST-A1", 0, false),
+            location_data("X1-TEST-B2", 40, false),
+        ];
+
+        // Test
+        let itinerary = plan_route(
+            &ship,
+            "X1-TEST-B2",
+            &waypoints,
+            &fuel_stations(&waypoints, &[]),
+            true,
+        )
+        .unwrap();
+
+        // Verify
+        assert_eq!(itinerary.legs.len(), 1);
+        assert_eq!(itinerary.legs[0].flight_mode, FlightMode::Burn);
+        assert!(!itinerary.legs[0].refuel);
+        assert_eq!(itinerary.total_fuel, 80);
+    }
+
+    #[test]
+    fn verify_route_with_refuel_stop() {
+        // Setup
+        let ship = fixtures::ship_data("TEST-1", "X1-TEST-A1");
+        let waypoints = vec![
+            location_data("X1-TEST-A1", 0, false),
+            location_data("X1-TEST-M1", 90, true),
+            location_data("X1-TEST-B2", 180, false),
+        ];
+        let price_records = vec![price_record("X1-TEST-M1", TradeSymbol::Fuel)];
+
+        // Test
+        let itinerary = plan_route(
+            &ship,
+            "X1-TEST-B2",
+            &waypoints,
+            &fuel_stations(&waypoints, &price_records),
+            false,
+        )
+        .unwrap();
+
+        // Verify
+        assert_eq!(itinerary.legs.len(), 2);
+        assert_eq!(itinerary.legs[0].to, "X1-TEST-M1");
+        assert_eq!(itinerary.legs[0].flight_mode, FlightMode::Cruise);
+        assert!(itinerary.legs[1].refuel);
+        assert_eq!(itinerary.legs[1].flight_mode, FlightMode::Cruise);
+        assert_eq!(itinerary.total_fuel, 180);
+    }
+
+    #[test]
+    fn verify_fuel_stations_sell_fuel() {
+        // Setup
+        let waypoints = vec![
+            location_data("X1-TEST-A1", 0, false),
+            location_data("X1-TEST-M1", 90, true),
+            location_data("X1-TEST-M2", 120, true),
+            location_data("X1-TEST-M3", 150, true),
+        ];
+        let price_records = vec![
+            price_record("X1-TEST-A1", TradeSymbol::Fuel),
+            price_record("X1-TEST-M1", TradeSymbol::Fuel),
+            price_record("X1-TEST-M2", TradeSymbol::IronOre),
+        ];
+
+        // Test
+        let stations = fuel_stations(&waypoints, &price_records);
+
+        // Verify
+        assert_eq!(stations.len(), 1);
+        assert!(stations.contains("X1-TEST-M1"));
+    }
+
+    #[test]
+    fn verify_drift_without_fuel_stations() {
+        // Setup
+        let ship = fixtures::ship_data("TEST-1", "X1-TEST-A1");
+        let waypoints = vec![
+            location_data("X1-TEST-A1", 0, false),
+            location_data("X1-TEST-B2", 500, false),
+        ];
+
+        // Test
+        let itinerary = plan_route(
+            &ship,
+            "X1-TEST-B2",
+            &waypoints,
+            &fuel_stations(&waypoints, &[]),
+            true,
+        )
+        .unwrap();
+
+        // Verify
+        assert_eq!(itinerary.legs.len(), 1);
+        assert_eq!(itinerary.legs[0].flight_mode, FlightMode::Drift);
+        assert_eq!(itinerary.total_fuel, 1);
+        assert!(plan_route(
+            &ship,
+            "X1-TEST-C3",
+            &waypoints,
+            &fuel_stations(&waypoints, &[]),
+            true
+        )
+        .is_none());
+    }
+
+    #[test]
+    fn verify_fewest_jumps() {
+        // Setup
+        let jump_gates = HashMap::from([
+            ("X1-AA-G1", vec!["X1-BB-G1", "X1-CC-G1"]),
+            ("X1-BB-G1", vec!["X1-AA-G1", "X1-DD-G1"]),
+            ("X1-CC-G1", vec!["X1-AA-G1", "X1-EE-G1"]),
+            ("X1-EE-G1", vec!["X1-CC-G1", "X1-DD-G1"]),
+            ("X1-DD-G1", vec!["X1-BB-G1", "X1-EE-G1"]),
+        ]);
+        let connections = |gate: &str| {
+            jump_gates
+                .get(gate)
+                .map(|gates| gates.iter().map(|gate| gate.to_string()).collect())
+                .ok_or_else(|| format!("{gate} is uncharted"))
+        };
+
+        // Test
+        let jumps = plan_jumps("X1-AA-G1", "X1-DD", connections);
+
+        // Verify
+        assert_eq!(
+            jumps,
+            Ok(Some(vec![
+                String::from("X1-BB-G1"),
+                String::from("X1-DD-G1")
+            ]))
+        );
+        assert_eq!(
+            plan_jumps("X1-AA-G1", "X1-AA", connections),
+            Ok(Some(Vec::new()))
+        );
+        assert_eq!(
+            plan_jumps("X1-AA-G1", "X1-ZZ", connections),
+            Ok::<_, String>(None)
+        );
+        assert_eq!(
+            plan_jumps("X1-YY-G1", "X1-DD", connections),
+            Err(String::from("X1-YY-G1 is uncharted"))
+        );
+    }
+}
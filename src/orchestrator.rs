@@ -0,0 +1,138 @@
+use crate::{
+    api::client::{ApiResult, TraderApis},
+    automation::Runner,
+    ledger,
+};
+
+use chrono::{DateTime, Utc};
+use std::{fmt, thread};
+
+/// Name of the profile for the agent configured at the top level of the config file.
+pub const DEFAULT_PROFILE: &str = "default";
+
+/// One agent run by an [`Orchestrator`].
+struct Agent<'a> {
+    profile_name: String,
+    api: &'a dyn TraderApis,
+    runner: Runner<'a>,
+}
+
+/// Summary of one agent, for showing every agent run from this process in one view.
+#[derive(Clone, Debug)]
+pub struct AgentStatus {
+    pub profile_name: String,
+    pub symbol: String,
+    pub credits: i64,
+    pub ship_count: usize,
+    /// Number of ships currently assigned a behavior.
+    pub automated_count: usize,
+    /// When the agent's next ship is due to tick, if any are automated.
+    pub next_due: Option<DateTime<Utc>>,
+}
+
+impl fmt::Display for AgentStatus {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{:<12} {:<16} {:>12} credits  {:>3} ships  {:>3} automated",
+            self.profile_name, self.symbol, self.credits, self.ship_count, self.automated_count
+        )?;
+        if let Some(next_due) = self.next_due {
+            write!(f, "  next tick {}", next_due.format("%H:%M:%S"))?;
+        }
+        Ok(())
+    }
+}
+
+/// Runs the automation of several agents from one process, each with its own client, and therefore its own token
+/// and rate limiter.
+#[derive(Default)]
+pub struct Orchestrator<'a> {
+    agents: Vec<Agent<'a>>,
+}
+
+impl<'a> Orchestrator<'a> {
+    /// Creates an [`Orchestrator`] with no agents.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds an agent, replacing any agent with the same profile name.
+    ///
+    /// * `profile_name` - name of the agent's profile, or [`DEFAULT_PROFILE`] for the top-level agent.
+    /// * `api` - client for the agent.
+    ///
+    /// Returns the agent's [`Runner`], to register behavior loaders and assign ships with.
+    pub fn add_agent(&mut self, profile_name: &str, api: &'a dyn TraderApis) -> &mut Runner<'a> {
+        let runner = if profile_name == DEFAULT_PROFILE {
+            Runner::new(api)
+        } else {
+            Runner::for_profile(api, profile_name)
+        };
+        self.agents
+            .retain(|agent| agent.profile_name != profile_name);
+        self.agents.push(Agent {
+            profile_name: profile_name.to_string(),
+            api,
+            runner,
+        });
+        &mut self
+            .agents
+            .last_mut()
+            .expect("an agent was just added")
+            .runner
+    }
+
+    /// Gets the [`Runner`] of an agent.
+    ///
+    /// * `profile_name` - name of the agent's profile.
+    pub fn runner(&mut self, profile_name: &str) -> Option<&mut Runner<'a>> {
+        self.agents
+            .iter_mut()
+            .find(|agent| agent.profile_name == profile_name)
+            .map(|agent| &mut agent.runner)
+    }
+
+    /// Gets when the next ship of any agent is due to tick.
+    pub fn next_due(&self) -> Option<DateTime<Utc>> {
+        self.agents
+            .iter()
+            .filter_map(|agent| agent.runner.next_due())
+            .min()
+    }
+
+    /// Runs the behaviors of every agent until all of them are done.
+    ///
+    /// Each agent runs on a thread of its own and only waits on its own rate limiter, so a busy agent doesn't hold
+    /// up the others. Ledger entries and prices recorded by an agent are attributed to its profile.
+    pub fn run(&mut self) {
+        thread::scope(|scope| {
+            for agent in &mut self.agents {
+                let profile_name = agent.profile_name.as_str();
+                let runner = &mut agent.runner;
+                scope.spawn(move || ledger::with_agent(profile_name, || runner.run()));
+            }
+        });
+    }
+
+    /// Gets a summary of every agent.
+    ///
+    /// Returns an [`AgentStatus`] per agent, in the order they were added, or the
+    /// [`ApiError`](crate::api::client::ApiError) reason one of them couldn't be fetched.
+    pub fn status(&self) -> ApiResult<Vec<AgentStatus>> {
+        self.agents
+            .iter()
+            .map(|agent| {
+                let agent_data = agent.api.get_agent_data()?;
+                Ok(AgentStatus {
+                    profile_name: agent.profile_name.clone(),
+                    symbol: agent_data.symbol,
+                    credits: agent_data.credits,
+                    ship_count: agent.api.list_ships()?.len(),
+                    automated_count: agent.runner.assigned_count(),
+                    next_due: agent.runner.next_due(),
+                })
+            })
+            .collect()
+    }
+}
@@ -0,0 +1,564 @@
+use crate::api::{
+    client::{ApiClient, ApiError, ApiResult, TraderApis},
+    data::{ErrorResponse, FactionData, Factions},
+    token_store::{StoredAgent, TokenStore},
+};
+
+use chrono::Utc;
+
+use std::{fmt, io::BufRead, sync::Arc};
+
+/// Shortest agent symbol the API accepts.
+pub const MIN_SYMBOL_LENGTH: usize = 3;
+
+/// Longest agent symbol the API accepts.
+pub const MAX_SYMBOL_LENGTH: usize = 14;
+
+/// Error code the API responds with when another agent already has the requested symbol.
+pub const SYMBOL_TAKEN_ERROR_CODE: i32 = 4111;
+
+/// Reason an agent symbol would be rejected by the API.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum SymbolError {
+    TooShort,
+    TooLong,
+    InvalidCharacter(char),
+}
+
+impl fmt::Display for SymbolError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SymbolError::TooShort => write!(
+                f,
+                "Agent symbols need at least {MIN_SYMBOL_LENGTH} characters."
+            ),
+            SymbolError::TooLong => write!(
+                f,
+                "Agent symbols can't have more than {MAX_SYMBOL_LENGTH} characters."
+            ),
+            SymbolError::InvalidCharacter(c) => write!(
+                f,
+                "Agent symbols can only contain letters, digits, '-' and '_', not '{c}'."
+            ),
+        }
+    }
+}
+
+/// Checks an agent symbol against the API's rules before registering it.
+///
+/// * `symbol` - the desired symbol. Surrounding whitespace is ignored.
+///
+/// Returns the symbol in upper case, the way the API stores it, or the [`SymbolError`] reason it would be rejected.
+pub fn validate_agent_symbol(symbol: &str) -> Result<String, SymbolError> {
+    let symbol = symbol.trim();
+    if let Some(c) = symbol
+        .chars()
+        .find(|c| !(c.is_ascii_alphanumeric() || *c == '-' || *c == '_'))
+    {
+        return Err(SymbolError::InvalidCharacter(c));
+    }
+    match symbol.len() {
+        length if length < MIN_SYMBOL_LENGTH => Err(SymbolError::TooShort),
+        length if length > MAX_SYMBOL_LENGTH => Err(SymbolError::TooLong),
+        _ => Ok(symbol.to_ascii_uppercase()),
+    }
+}
+
+/// Explains why the API refused to register an agent.
+///
+/// * `agent_symbol` - symbol the agent was to be registered with.
+/// * `error_response` - the [`ErrorResponse`] from the API.
+pub fn describe_registration_error(agent_symbol: &str, error_response: &ErrorResponse) -> String {
+    match error_response.code {
+        SYMBOL_TAKEN_ERROR_CODE => {
+            format!("The symbol {agent_symbol} is already taken - please choose another one.")
+        }
+        401 => String::from(
+            "The server needs a valid account token to register agents - create one on the SpaceTraders website.",
+        ),
+        code => format!(
+            "The server refused to register {agent_symbol}: {} (error {code})",
+            error_response.message
+        ),
+    }
+}
+
+/// Guides the user through registering a new agent, keeping it in a [`TokenStore`].
+///
+/// The user is asked to confirm before an existing agent is replaced, in which case the old agent is archived
+/// once the new agent is registered, for stores that keep copies. Symbols are checked before anything is sent, and a taken symbol can be
+/// replaced without starting over.
+///
+/// * `input` - where the user's answers are read from, e.g. standard input.
+/// * `token_store` - the [`TokenStore`] the new agent is kept in, e.g. a [`FileTokenStore`](crate::api::token_store::FileTokenStore) for the config file.
+///
+/// Returns the [`ApiClient`] for the new agent, [`Option::None`] if the user backed out or the server refused the
+/// registration, or the [`ApiError`] reason for failure.
+pub fn run_wizard(
+    input: &mut impl BufRead,
+    token_store: Arc<dyn TokenStore>,
+) -> ApiResult<Option<ApiClient>> {
+    let registered_agent = guide_registration(
+        input,
+        token_store.as_ref(),
+        || ApiClient::unregistered().list_factions(),
+        |account_token, agent_symbol, faction| {
+            let api_client = if account_token.is_empty() {
+                ApiClient::unregistered()
+            } else {
+                ApiClient::with_account_token(account_token)
+            };
+            api_client.register_agent(agent_symbol, faction)
+        },
+    )?;
+
+    match registered_agent {
+        Some(_) => ApiClient::init_with_token_store(token_store).map(Some),
+        None => Ok(None),
+    }
+}
+
+/// Asks the user everything needed to register an agent and keeps the agent once it's registered, leaving the
+/// requests to the caller.
+///
+/// * `input` - where the user's answers are read from.
+/// * `token_store` - the [`TokenStore`] the new agent is kept in, archived first if it already holds one.
+/// * `list_factions` - gets the [`FactionData`] of every faction.
+/// * `register` - registers an agent given the account token, agent symbol and [`Faction`](`Factions`).
+///
+/// Returns the [`StoredAgent`] that was registered, [`Option::None`] if the user backed out or the server refused
+/// the registration, or the [`ApiError`] reason for failure.
+fn guide_registration(
+    input: &mut impl BufRead,
+    token_store: &dyn TokenStore,
+    list_factions: impl FnOnce() -> ApiResult<Vec<FactionData>>,
+    mut register: impl FnMut(&str, &str, Factions) -> ApiResult<StoredAgent>,
+) -> ApiResult<Option<StoredAgent>> {
+    let replaced_agent = token_store.load().filter(|agent| !agent.token.is_empty());
+    if let Some(ref replaced_agent) = replaced_agent {
+        let agent_symbol = replaced_agent
+            .symbol
+            .as_deref()
+            .unwrap_or("an unnamed agent");
+        println!("The token store already holds {agent_symbol}.");
+        if !confirm(input, "Replace it with a new agent?") {
+            return Ok(None);
+        }
+    }
+
+    let factions: Vec<FactionData> = list_factions()?
+        .into_iter()
+        .filter(|faction| faction.is_recruiting)
+        .collect();
+    let Some(faction) = choose_faction(input, &factions) else {
+        return Ok(None);
+    };
+
+    let Some(account_token) = prompt(
+        input,
+        "Account token (leave empty if the server doesn't ask for one): ",
+    ) else {
+        return Ok(None);
+    };
+
+    let registered_agent = loop {
+        let Some(agent_symbol) = prompt_agent_symbol(input) else {
+            return Ok(None);
+        };
+
+        match register(&account_token, &agent_symbol, faction.symbol.clone()) {
+            Ok(registered_agent) => {
+                println!("Registered {agent_symbol} in the {} faction.", faction.name);
+                break registered_agent;
+            }
+            Err(ApiError::BadRequest(error_response)) => {
+                println!(
+                    "{}",
+                    describe_registration_error(&agent_symbol, &error_response)
+                );
+                if error_response.code != SYMBOL_TAKEN_ERROR_CODE {
+                    return Ok(None);
+                }
+            }
+            Err(e) => return Err(e),
+        }
+    };
+
+    // The old agent is only archived once it's really being replaced, so backing out leaves nothing behind.
+    if let Some(replaced_agent) = replaced_agent {
+        // Timestamped so replacing an agent again doesn't overwrite the earlier archive.
+        let archive_label = format!(
+            "{}.{}",
+            replaced_agent.symbol.as_deref().unwrap_or("replaced"),
+            Utc::now().format("%Y%m%dT%H%M%S")
+        );
+        if let Some(archive_location) = token_store
+            .archive(&archive_label)
+            .map_err(ApiError::TokenStore)?
+        {
+            println!("Saved the old agent to {archive_location}.");
+        }
+    }
+    token_store
+        .save(&registered_agent)
+        .map_err(ApiError::TokenStore)?;
+    Ok(Some(registered_agent))
+}
+
+/// Asks the user for a line of input.
+///
+/// * `input` - where the answer is read from.
+/// * `question` - text shown before the answer.
+///
+/// Returns the trimmed answer, or [`Option::None`] if there's no more input.
+fn prompt(input: &mut impl BufRead, question: &str) -> Option<String> {
+    print!("{question}");
+    // The question is still shown if flushing fails, just possibly after the answer.
+    let _ = std::io::Write::flush(&mut std::io::stdout());
+
+    let mut answer = String::new();
+    match input.read_line(&mut answer) {
+        Ok(0) | Err(_) => None,
+        Ok(_) => Some(answer.trim().to_string()),
+    }
+}
+
+/// Asks the user a yes or no question, defaulting to no.
+///
+/// * `input` - where the answer is read from.
+/// * `question` - the question.
+fn confirm(input: &mut impl BufRead, question: &str) -> bool {
+    prompt(input, &format!("{question} [y/N] ")).is_some_and(|answer| {
+        answer.eq_ignore_ascii_case("y") || answer.eq_ignore_ascii_case("yes")
+    })
+}
+
+/// Asks the user for an agent symbol until they give a valid one.
+///
+/// * `input` - where the answer is read from.
+///
+/// Returns the validated symbol, or [`Option::None`] if there's no more input.
+fn prompt_agent_symbol(input: &mut impl BufRead) -> Option<String> {
+    loop {
+        let answer = prompt(
+            input,
+            &format!("Agent symbol ({MIN_SYMBOL_LENGTH}-{MAX_SYMBOL_LENGTH} letters, digits, '-' or '_'): "),
+        )?;
+        match validate_agent_symbol(&answer) {
+            Ok(agent_symbol) => return Some(agent_symbol),
+            Err(e) => println!("{e}"),
+        }
+    }
+}
+
+/// Lists the factions and asks the user to pick one, showing its details before they commit to it.
+///
+/// * `input` - where the answers are read from.
+/// * `factions` - [`FactionData`] of the factions to choose from.
+///
+/// Returns the chosen faction, or [`Option::None`] if there's no more input.
+fn choose_faction<'a>(
+    input: &mut impl BufRead,
+    factions: &'a [FactionData],
+) -> Option<&'a FactionData> {
+    loop {
+        for (number, faction) in factions.iter().enumerate() {
+            println!("{:>2}. {} ({})", number + 1, faction.name, faction.symbol);
+        }
+        let answer = prompt(input, "Faction number or symbol: ")?;
+        let chosen_faction = answer
+            .parse::<usize>()
+            .ok()
+            .and_then(|number| factions.get(number.checked_sub(1)?))
+            .or_else(|| {
+                factions
+                    .iter()
+                    .find(|faction| faction.symbol.as_str().eq_ignore_ascii_case(&answer))
+            });
+
+        match chosen_faction {
+            Some(faction) => {
+                println!("\n{faction}\n");
+                if confirm(input, &format!("Join {}?", faction.name)) {
+                    return Some(faction);
+                }
+            }
+            None => println!("There's no recruiting faction called {answer}."),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{guide_registration, validate_agent_symbol, SymbolError, SYMBOL_TAKEN_ERROR_CODE};
+    use crate::{
+        api::{
+            client::{ApiError, ApiResult},
+            data::{ErrorResponse, FactionData, Factions},
+            token_store::{FileTokenStore, MemoryTokenStore, StoredAgent, TokenStore},
+        },
+        utils::config::{self, ConfigData},
+    };
+
+    use serde_json::json;
+    use tempfile;
+
+    use std::{fs, path::Path};
+
+    fn factions() -> ApiResult<Vec<FactionData>> {
+        Ok(vec![
+            serde_json::from_value(json!({
+                "symbol": "COSMIC",
+                "name": "Cosmic Engineers",
+                "description": "Test description",
+                "headquarters": "X1-TEST-A1",
+                "traits": [],
+                "isRecruiting": true,
+            }))
+            .unwrap(),
+            serde_json::from_value(json!({
+                "symbol": "VOID",
+                "name": "Voidfarers",
+                "description": "Test description",
+                "headquarters": "X1-TEST-B1",
+                "traits": [],
+                "isRecruiting": false,
+            }))
+            .unwrap(),
+        ])
+    }
+
+    fn stored_agent() -> StoredAgent {
+        StoredAgent {
+            token: String::from("TEST_OLD_TOKEN"),
+            symbol: Some(String::from("OLD_AGENT")),
+            faction: Some(Factions::Cosmic),
+            reset_date: None,
+        }
+    }
+
+    fn registered_agent(agent_symbol: &str) -> StoredAgent {
+        StoredAgent {
+            token: String::from("TEST_NEW_TOKEN"),
+            symbol: Some(agent_symbol.to_string()),
+            faction: Some(Factions::Cosmic),
+            reset_date: Some(String::from("2023-05-20")),
+        }
+    }
+
+    fn config_file_names(config_dir: &Path) -> Vec<String> {
+        let mut file_names: Vec<String> = fs::read_dir(config_dir)
+            .unwrap()
+            .map(|entry| entry.unwrap().file_name().to_string_lossy().into_owned())
+            .collect();
+        file_names.sort();
+        file_names
+    }
+
+    fn write_old_config(config_file_path: &Path) {
+        config::write_config_file(
+            ConfigData {
+                token: String::from("TEST_OLD_TOKEN"),
+                symbol: Some(String::from("OLD_AGENT")),
+                ..Default::default()
+            },
+            config_file_path.to_path_buf(),
+        )
+        .unwrap();
+    }
+
+    #[test]
+    fn verify_agent_symbol_validation() {
+        // Setup
+        let symbols = [
+            "  test_agent-1 ",
+            "AB",
+            "A_VERY_LONG_AGENT",
+            "BAD AGENT",
+            "ÄGENT",
+        ];
+
+        // Test
+        let results: Vec<Result<String, SymbolError>> = symbols
+            .iter()
+            .map(|symbol| validate_agent_symbol(symbol))
+            .collect();
+
+        // Verify
+        assert_eq!(
+            results,
+            vec![
+                Ok(String::from("TEST_AGENT-1")),
+                Err(SymbolError::TooShort),
+                Err(SymbolError::TooLong),
+                Err(SymbolError::InvalidCharacter(' ')),
+                Err(SymbolError::InvalidCharacter('Ä')),
+            ]
+        );
+    }
+
+    #[test]
+    fn verify_wizard_keeps_declined_agent() {
+        // Setup
+        let token_store = MemoryTokenStore::new();
+        token_store.save(&stored_agent()).unwrap();
+        let mut registrations = 0;
+
+        // Test
+        let result = guide_registration(
+            &mut "n\n".as_bytes(),
+            &token_store,
+            factions,
+            |_, symbol, _| {
+                registrations += 1;
+                Ok(registered_agent(symbol))
+            },
+        );
+
+        // Verify
+        assert!(matches!(result, Ok(None)));
+        assert_eq!(registrations, 0);
+        assert_eq!(token_store.load(), Some(stored_agent()));
+    }
+
+    #[test]
+    fn verify_wizard_retries_taken_symbol() {
+        // Setup
+        let token_store = MemoryTokenStore::new();
+        let input = "VOID\n1\ny\nTEST_ACCOUNT_TOKEN\ntaken\nfree\n";
+        let mut registrations = Vec::new();
+
+        // Test
+        let result = guide_registration(
+            &mut input.as_bytes(),
+            &token_store,
+            factions,
+            |account_token, agent_symbol, faction| {
+                registrations.push((account_token.to_string(), agent_symbol.to_string(), faction));
+                match agent_symbol {
+                    "TAKEN" => Err(ApiError::BadRequest(ErrorResponse {
+                        message: String::from("Symbol taken"),
+                        code: SYMBOL_TAKEN_ERROR_CODE,
+                        data: None,
+                    })),
+                    _ => Ok(registered_agent(agent_symbol)),
+                }
+            },
+        );
+
+        // Verify
+        assert_eq!(result.unwrap(), Some(registered_agent("FREE")));
+        assert_eq!(token_store.load(), Some(registered_agent("FREE")));
+        assert_eq!(
+            registrations,
+            vec![
+                (
+                    String::from("TEST_ACCOUNT_TOKEN"),
+                    String::from("TAKEN"),
+                    Factions::Cosmic
+                ),
+                (
+                    String::from("TEST_ACCOUNT_TOKEN"),
+                    String::from("FREE"),
+                    Factions::Cosmic
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn verify_wizard_stops_at_end_of_input() {
+        // Setup
+        let answers = ["y", "1", "y", "", "TEST_AGENT"];
+
+        for answered in 0..answers.len() {
+            let token_store = MemoryTokenStore::new();
+            token_store.save(&stored_agent()).unwrap();
+            let input: String = answers[..answered]
+                .iter()
+                .map(|answer| format!("{answer}\n"))
+                .collect();
+            let mut registrations = 0;
+
+            // Test
+            let result = guide_registration(
+                &mut input.as_bytes(),
+                &token_store,
+                factions,
+                |_, symbol, _| {
+                    registrations += 1;
+                    Ok(registered_agent(symbol))
+                },
+            );
+
+            // Verify
+            assert!(matches!(result, Ok(None)), "after {answered} answers");
+            assert_eq!(registrations, 0, "after {answered} answers");
+            assert_eq!(token_store.load(), Some(stored_agent()));
+        }
+    }
+
+    #[test]
+    fn verify_wizard_backs_out_without_archiving() {
+        // Setup
+        let inputs = ["y\n", "y\n1\nn\n", "y\n1\ny\n\nREFUSED\n"];
+
+        for input in inputs {
+            let tmp_config_dir = tempfile::tempdir().unwrap();
+            let config_file_path = tmp_config_dir.path().join("config.json");
+            write_old_config(&config_file_path);
+            let token_store = FileTokenStore::new(config_file_path);
+
+            // Test
+            let result =
+                guide_registration(&mut input.as_bytes(), &token_store, factions, |_, _, _| {
+                    Err(ApiError::BadRequest(ErrorResponse {
+                        message: String::from("Registration is closed"),
+                        code: 400,
+                        data: None,
+                    }))
+                });
+
+            // Verify
+            assert!(matches!(result, Ok(None)), "{input:?}");
+            assert_eq!(
+                config_file_names(tmp_config_dir.path()),
+                vec!["config.json"],
+                "{input:?}"
+            );
+            assert_eq!(token_store.token(), Some(String::from("TEST_OLD_TOKEN")));
+        }
+    }
+
+    #[test]
+    fn verify_wizard_archives_replaced_agent() {
+        // Setup
+        let tmp_config_dir = tempfile::tempdir().unwrap();
+        let config_file_path = tmp_config_dir.path().join("config.json");
+        write_old_config(&config_file_path);
+        let token_store = FileTokenStore::new(config_file_path);
+
+        // Test
+        let result = guide_registration(
+            &mut "y\n1\ny\n\nNEW_AGENT\n".as_bytes(),
+            &token_store,
+            factions,
+            |_, symbol, _| Ok(registered_agent(symbol)),
+        );
+
+        // Verify
+        assert_eq!(result.unwrap(), Some(registered_agent("NEW_AGENT")));
+        let file_names = config_file_names(tmp_config_dir.path());
+        assert_eq!(file_names.len(), 2);
+        assert!(file_names[0].starts_with("config.OLD_AGENT.") && file_names[0].ends_with(".json"));
+        assert_eq!(
+            file_names[0].len(),
+            "config.OLD_AGENT.20230520T000000.json".len()
+        );
+        assert_eq!(file_names[1], "config.json");
+        let archived_config =
+            config::read_config_file(tmp_config_dir.path().join(&file_names[0])).unwrap();
+        assert_eq!(archived_config.token, "TEST_OLD_TOKEN");
+        assert_eq!(token_store.token(), Some(String::from("TEST_NEW_TOKEN")));
+    }
+}
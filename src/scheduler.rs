@@ -0,0 +1,225 @@
+use crate::api::data::{ContractData, Cooldown, NavInfo};
+
+use chrono::{DateTime, Duration as ChronoDuration, Utc};
+use std::{cmp::Reverse, collections::BinaryHeap, thread, time::Duration};
+
+/// Something worth waking up for.
+#[derive(Clone, Debug, Eq, PartialEq, Ord, PartialOrd)]
+pub enum Event {
+    /// The ship with the given symbol reaches its destination.
+    ShipArrival(String),
+    /// The reactor cooldown of the ship with the given symbol expires.
+    CooldownExpired(String),
+    /// The contract with the given ID is close to its deadline.
+    ContractDeadline(String),
+}
+
+/// Gets when a cooldown expires, falling back to the remaining seconds if it has no expiration.
+///
+/// * `cooldown` - the [`Cooldown`].
+pub fn cooldown_expiration(cooldown: &Cooldown) -> DateTime<Utc> {
+    cooldown
+        .expiration
+        .unwrap_or_else(|| Utc::now() + ChronoDuration::seconds(cooldown.remaining_seconds.into()))
+}
+
+/// Tasks ordered by the time they are due, so callers can sleep until exactly the next one instead of polling.
+#[derive(Clone, Debug)]
+pub struct Scheduler<T: Ord> {
+    queue: BinaryHeap<Reverse<(DateTime<Utc>, T)>>,
+}
+
+impl<T: Ord> Default for Scheduler<T> {
+    fn default() -> Self {
+        Self {
+            queue: BinaryHeap::new(),
+        }
+    }
+}
+
+impl<T: Ord> Scheduler<T> {
+    /// Creates an empty [`Scheduler`].
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Schedules a task for a given time.
+    ///
+    /// * `due` - when the task is due. Times in the past are due immediately.
+    /// * `task` - the task.
+    pub fn schedule(&mut self, due: DateTime<Utc>, task: T) {
+        self.queue.push(Reverse((due, task)));
+    }
+
+    /// Schedules a task for some time from now.
+    ///
+    /// * `delay` - how long from now the task is due.
+    /// * `task` - the task.
+    pub fn schedule_in(&mut self, delay: Duration, task: T) {
+        let delay = ChronoDuration::from_std(delay).unwrap_or(ChronoDuration::MAX);
+        let due = Utc::now()
+            .checked_add_signed(delay)
+            .unwrap_or(DateTime::<Utc>::MAX_UTC);
+        self.schedule(due, task);
+    }
+
+    /// Removes every scheduled task matching a predicate.
+    ///
+    /// * `is_cancelled` - whether a task should be removed.
+    pub fn cancel(&mut self, is_cancelled: impl Fn(&T) -> bool) {
+        self.queue.retain(|Reverse((_, task))| !is_cancelled(task));
+    }
+
+    /// Gets when the next task is due, if any are scheduled.
+    pub fn next_due(&self) -> Option<DateTime<Utc>> {
+        self.queue.peek().map(|Reverse((due, _))| *due)
+    }
+
+    /// Whether no tasks are scheduled.
+    pub fn is_empty(&self) -> bool {
+        self.queue.is_empty()
+    }
+
+    /// Removes and returns every task due at or before a given time, earliest first.
+    ///
+    /// * `now` - the current time.
+    pub fn pop_due(&mut self, now: DateTime<Utc>) -> Vec<T> {
+        let mut due_tasks = Vec::new();
+        while self.next_due().is_some_and(|due| due <= now) {
+            if let Some(Reverse((_, task))) = self.queue.pop() {
+                due_tasks.push(task);
+            }
+        }
+        due_tasks
+    }
+
+    /// Sleeps until the next task is due, then removes and returns it.
+    ///
+    /// Returns the task, or [`Option::None`] right away if nothing is scheduled.
+    pub fn wait_next(&mut self) -> Option<T> {
+        let due = self.next_due()?;
+        if let Ok(delay) = (due - Utc::now()).to_std() {
+            thread::sleep(delay);
+        }
+        self.queue.pop().map(|Reverse((_, task))| task)
+    }
+}
+
+impl Scheduler<Event> {
+    /// Schedules the arrival of a ship, if it is travelling.
+    ///
+    /// * `ship` - symbol of the ship.
+    /// * `nav` - [`NavInfo`] of the ship.
+    pub fn schedule_arrival(&mut self, ship: &str, nav: &NavInfo) {
+        if let Some(arrival) = nav.route.arrival {
+            self.schedule(arrival, Event::ShipArrival(ship.to_string()));
+        }
+    }
+
+    /// Schedules the expiration of a ship's cooldown.
+    ///
+    /// * `cooldown` - the [`Cooldown`].
+    pub fn schedule_cooldown(&mut self, cooldown: &Cooldown) {
+        self.schedule(
+            cooldown_expiration(cooldown),
+            Event::CooldownExpired(cooldown.ship_symbol.clone()),
+        );
+    }
+
+    /// Schedules a reminder ahead of a contract's deadline.
+    ///
+    /// * `contract` - [`ContractData`] of the contract.
+    /// * `warning` - how long before the deadline to wake up.
+    pub fn schedule_contract_deadline(&mut self, contract: &ContractData, warning: Duration) {
+        let warning = ChronoDuration::from_std(warning).unwrap_or(ChronoDuration::zero());
+        self.schedule(
+            contract.terms.deadline - warning,
+            Event::ContractDeadline(contract.id.clone()),
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{cooldown_expiration, Event, Scheduler};
+    use crate::api::data::Cooldown;
+
+    use chrono::{DateTime, Duration as ChronoDuration, TimeZone, Utc};
+    use std::time::Duration;
+
+    #[test]
+    fn verify_cooldown_expiration() {
+        // Setup
+        let cooldown: Cooldown = serde_json::from_str(
+            r#"{
+                "shipSymbol": "TEST-1",
+                "totalSeconds": 70,
+                "remainingSeconds": 69,
+                "expiration": "2023-05-20T18:21:57.000Z"
+            }"#,
+        )
+        .unwrap();
+        let unknown_expiration = Cooldown {
+            expiration: None,
+            ..cooldown.clone()
+        };
+
+        // Test
+        let expiration = cooldown_expiration(&cooldown);
+        let estimated_expiration = cooldown_expiration(&unknown_expiration);
+
+        // Verify
+        assert_eq!(
+            expiration,
+            Utc.with_ymd_and_hms(2023, 5, 20, 18, 21, 57).unwrap()
+        );
+        assert!(estimated_expiration > Utc::now() + ChronoDuration::seconds(60));
+    }
+
+    #[test]
+    fn verify_pop_due_order() {
+        // Setup
+        let now = Utc::now();
+        let mut scheduler = Scheduler::new();
+        scheduler.schedule(
+            now + ChronoDuration::hours(1),
+            Event::ContractDeadline(String::from("C1")),
+        );
+        scheduler.schedule(
+            now - ChronoDuration::seconds(5),
+            Event::CooldownExpired(String::from("S1")),
+        );
+        scheduler.schedule(
+            now - ChronoDuration::seconds(10),
+            Event::ShipArrival(String::from("S2")),
+        );
+        scheduler.schedule(now, Event::ShipArrival(String::from("S3")));
+        scheduler.cancel(|event| *event == Event::ShipArrival(String::from("S3")));
+
+        // Test
+        let due_events = scheduler.pop_due(now);
+
+        // Verify
+        assert_eq!(
+            due_events,
+            vec![
+                Event::ShipArrival(String::from("S2")),
+                Event::CooldownExpired(String::from("S1")),
+            ]
+        );
+        assert_eq!(scheduler.next_due(), Some(now + ChronoDuration::hours(1)));
+    }
+
+    #[test]
+    fn verify_huge_delay_is_clamped() {
+        // Setup
+        let mut scheduler = Scheduler::new();
+
+        // Test
+        scheduler.schedule_in(Duration::MAX, "never");
+
+        // Verify
+        assert_eq!(scheduler.next_due(), Some(DateTime::<Utc>::MAX_UTC));
+        assert!(scheduler.pop_due(Utc::now()).is_empty());
+    }
+}
@@ -0,0 +1,229 @@
+use crate::{
+    api::{
+        client::system_of,
+        data::{Coords, FlightMode, ShipData, TradeSymbol, WaypointSymbol},
+    },
+    history::PriceRecord,
+};
+
+use std::{
+    collections::HashMap,
+    fmt::{Display, Formatter, Result as FmtResult},
+};
+
+/// Amount of fuel in a single unit of [`TradeSymbol::Fuel`] bought at a market.
+pub const FUEL_PER_UNIT: u32 = 100;
+
+/// A candidate buy-here-sell-there trade for a single ship.
+#[derive(Clone, Debug)]
+pub struct TradeRoute {
+    pub trade_symbol: TradeSymbol,
+    pub buy_waypoint: WaypointSymbol,
+    pub sell_waypoint: WaypointSymbol,
+    pub purchase_price: u32,
+    pub sell_price: u32,
+    /// Units traded, limited by the ship's cargo capacity and the markets' trade volumes.
+    pub units: u32,
+    /// Fuel used to reach the buy market and then the sell market.
+    pub fuel_used: u32,
+    /// Seconds spent travelling to the buy market and then the sell market.
+    pub travel_secs: u32,
+    /// Credits earned after paying for the goods and the fuel.
+    pub profit: i64,
+    pub profit_per_hour: f64,
+}
+impl Display for TradeRoute {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        write!(
+            f,
+            "{} x{}: buy at {} for {}, sell at {} for {} | profit {} ({:.0}/h) | {}s, {} fuel",
+            self.trade_symbol,
+            self.units,
+            self.buy_waypoint,
+            self.purchase_price,
+            self.sell_waypoint,
+            self.sell_price,
+            self.profit,
+            self.profit_per_hour,
+            self.travel_secs,
+            self.fuel_used
+        )
+    }
+}
+
+/// Gets the cheapest price of fuel across the known markets of a system.
+///
+/// * `price_records` - latest known prices of goods at markets.
+/// * `system` - symbol of the system the fuel is bought in.
+///
+/// Returns the price of one unit of [`TradeSymbol::Fuel`], or [`Option::None`] if no known market in the system
+/// sells fuel.
+pub fn cheapest_fuel_price(price_records: &[PriceRecord], system: &str) -> Option<u32> {
+    price_records
+        .iter()
+        .filter(|record| {
+            record.trade_symbol == TradeSymbol::Fuel && system_of(&record.waypoint_symbol) == system
+        })
+        .map(|record| record.purchase_price)
+        .min()
+}
+
+/// Finds the most profitable trades a ship can make between known markets in its system.
+///
+/// Waypoint coordinates are local to their system, so markets in other systems are left out. Travel is assumed to happen in [`FlightMode::Cruise`], first from the ship's current waypoint to the buy
+/// market and then on to the sell market. Trades with a leg longer than the ship's fuel capacity are skipped.
+///
+/// * `ship` - [`ShipData`] of the ship doing the trading.
+/// * `price_records` - latest known prices of goods at markets, e.g. from [`history::latest_prices`](crate::history::latest_prices).
+/// * `locations` - [`Coords`] of the ship's current waypoint and of every market in its system.
+///
+/// Returns the profitable [`TradeRoute`]s, best profit per hour first.
+pub fn find_trade_routes(
+    ship: &ShipData,
+    price_records: &[PriceRecord],
+    locations: &HashMap<WaypointSymbol, Coords>,
+) -> Vec<TradeRoute> {
+    let Some(ship_coords) = locations.get(&ship.nav.waypoint_symbol) else {
+        return Vec::new();
+    };
+    let system = system_of(&ship.nav.waypoint_symbol);
+    let fuel_price = cheapest_fuel_price(price_records, &system).unwrap_or_default();
+    let free_capacity = ship.cargo.capacity.saturating_sub(ship.cargo.units);
+    let price_records: Vec<&PriceRecord> = price_records
+        .iter()
+        .filter(|record| system_of(&record.waypoint_symbol) == system)
+        .collect();
+
+    let mut trade_routes = Vec::new();
+    for buy_record in &price_records {
+        let Some(buy_coords) = locations.get(&buy_record.waypoint_symbol) else {
+            continue;
+        };
+        let sell_records = price_records.iter().filter(|record| {
+            record.trade_symbol == buy_record.trade_symbol
+                && record.waypoint_symbol != buy_record.waypoint_symbol
+                && record.sell_price > buy_record.purchase_price
+        });
+
+        for sell_record in sell_records {
+            let Some(sell_coords) = locations.get(&sell_record.waypoint_symbol) else {
+                continue;
+            };
+
+            let leg_distances = [
+                ship_coords.distance_to(buy_coords),
+                buy_coords.distance_to(sell_coords),
+            ];
+            let leg_fuel = leg_distances.map(|distance| FlightMode::Cruise.fuel_cost(distance));
+            // Ships without a fuel tank, such as probes, travel for free.
+            if ship.fuel.capacity > 0 && leg_fuel.iter().any(|fuel| *fuel > ship.fuel.capacity) {
+                continue;
+            }
+
+            let units = free_capacity
+                .min(buy_record.trade_volume)
+                .min(sell_record.trade_volume);
+            if units == 0 {
+                continue;
+            }
+
+            let fuel_used = if ship.fuel.capacity > 0 {
+                leg_fuel.iter().sum()
+            } else {
+                0
+            };
+            let travel_secs = leg_distances
+                .iter()
+                .map(|distance| FlightMode::Cruise.travel_secs(*distance, ship.engine.speed))
+                .sum::<u32>();
+            let fuel_cost = fuel_used.div_ceil(FUEL_PER_UNIT) * fuel_price;
+            let profit = i64::from(units)
+                * (i64::from(sell_record.sell_price) - i64::from(buy_record.purchase_price))
+                - i64::from(fuel_cost);
+            if profit <= 0 {
+                continue;
+            }
+
+            trade_routes.push(TradeRoute {
+                trade_symbol: buy_record.trade_symbol.clone(),
+                buy_waypoint: buy_record.waypoint_symbol.clone(),
+                sell_waypoint: sell_record.waypoint_symbol.clone(),
+                purchase_price: buy_record.purchase_price,
+                sell_price: sell_record.sell_price,
+                units,
+                fuel_used,
+                travel_secs,
+                profit,
+                profit_per_hour: profit as f64 * 3600.0 / f64::from(travel_secs.max(1)),
+            });
+        }
+    }
+
+    trade_routes.sort_by(|a, b| b.profit_per_hour.total_cmp(&a.profit_per_hour));
+    trade_routes
+}
+
+#[cfg(test)]
+mod tests {
+    use super::find_trade_routes;
+    use crate::{
+        api::data::{fixtures, Coords, TradeSymbol},
+        history::PriceRecord,
+    };
+
+    use std::collections::HashMap;
+
+    fn price_record(waypoint: &str, good: &str, purchase: u32, sell: u32) -> PriceRecord {
+        PriceRecord {
+            timestamp: 0,
+            waypoint_symbol: String::from(waypoint),
+            trade_symbol: TradeSymbol::from(good),
+            purchase_price: purchase,
+            sell_price: sell,
+            supply: String::from("MODERATE"),
+            trade_volume: 20,
+            agent: None,
+        }
+    }
+
+    #[test]
+    fn verify_find_trade_routes() {
+        // Setup
+        let ship = fixtures::ship_data("TEST-1", "X1-TEST-A1");
+        let price_records = vec![
+            price_record("X1-TEST-A1", "IRON_ORE", 10, 8),
+            price_record("X1-TEST-A1", "FUEL", 70, 60),
+            price_record("X1-TEST-B2", "IRON_ORE", 30, 25),
+            price_record("X1-TEST-C3", "IRON_ORE", 35, 40),
+            price_record("X1-TEST-FAR", "IRON_ORE", 100, 90),
+            price_record("X1-OTHER-D4", "IRON_ORE", 100, 200),
+            price_record("X1-OTHER-D4", "FUEL", 1, 1),
+        ];
+        let locations = HashMap::from([
+            (String::from("X1-TEST-A1"), Coords { x: 0, y: 0 }),
+            (String::from("X1-TEST-B2"), Coords { x: 3, y: 4 }),
+            (String::from("X1-TEST-C3"), Coords { x: 30, y: 40 }),
+            (String::from("X1-TEST-FAR"), Coords { x: 300, y: 400 }),
+            (String::from("X1-OTHER-D4"), Coords { x: 3, y: 4 }),
+        ]);
+
+        // Test
+        let trade_routes = find_trade_routes(&ship, &price_records, &locations);
+
+        // Verify
+        assert_eq!(trade_routes.len(), 3);
+        assert!(trade_routes
+            .iter()
+            .all(|route| route.sell_waypoint != "X1-TEST-FAR"));
+        assert!(trade_routes
+            .iter()
+            .all(|route| route.sell_waypoint != "X1-OTHER-D4"));
+        let best_route = &trade_routes[0];
+        assert_eq!(best_route.buy_waypoint, "X1-TEST-A1");
+        assert_eq!(best_route.sell_waypoint, "X1-TEST-B2");
+        assert_eq!(best_route.units, 20);
+        assert_eq!(best_route.fuel_used, 5);
+        assert_eq!(best_route.profit, 20 * 15 - 70);
+        assert!(trade_routes[0].profit_per_hour >= trade_routes[1].profit_per_hour);
+    }
+}
@@ -1,5 +1,41 @@
+use std::{
+    path::PathBuf,
+    sync::{Mutex, MutexGuard, PoisonError},
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+/// Get the path of an application data file based on the application root directory.
+///
+/// * `file_name` - name of the data file.
+///
+/// Returns the [`PathBuf`] path to the data file.
+pub(crate) fn get_app_file_path(file_name: &str) -> PathBuf {
+    let mut app_file_path = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
+    app_file_path.push(file_name);
+    app_file_path
+}
+
+/// Locks one of the application's data files for as long as the returned guard is held, so agents running on
+/// other threads don't read it halfway through a write or overwrite each other's changes.
+///
+/// * `file_lock` - the process-wide lock belonging to the file.
+pub(crate) fn lock_app_file(file_lock: &Mutex<()>) -> MutexGuard<'_, ()> {
+    // A thread panicking while holding the lock leaves nothing in memory to clean up.
+    file_lock.lock().unwrap_or_else(PoisonError::into_inner)
+}
+
+/// Get the current time as seconds since the Unix epoch, used to timestamp locally stored data.
+pub(crate) fn now_secs() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|duration| duration.as_secs())
+        .unwrap_or_default()
+}
+
 /// Utilities related to the application's configuration.
 pub mod config {
+    use crate::api::data::{without_schema_drift, Factions};
+
     use serde::{Deserialize, Serialize};
     use std::{fs, path::PathBuf};
 
@@ -10,22 +46,65 @@ pub mod config {
     #[derive(Clone, Copy, Debug)]
     pub enum ConfigError {
         FileWrite,
+        FileRead,
     }
 
     /// The shape of the data contained in the config file.
-    #[derive(Clone, Debug, Eq, PartialEq, Deserialize, Serialize)]
+    #[derive(Clone, Debug, Default, Eq, PartialEq, Deserialize, Serialize)]
     pub struct ConfigData {
         /// User's auth token for the SpaceTraders API.
         pub token: String,
+        /// Symbol the agent was registered with.
+        #[serde(default)]
+        pub symbol: Option<String>,
+        /// [`Faction`](`Factions`) the agent was registered in.
+        #[serde(default)]
+        pub faction: Option<Factions>,
+        /// Server reset date at the time the agent was registered, used to detect universe wipes.
+        #[serde(default)]
+        pub reset_date: Option<String>,
+        /// Whether to warn about API responses that don't match the schema this client knows.
+        #[serde(default)]
+        pub strict_mode: bool,
+        /// Other agents run from the same process, each with its own token.
+        #[serde(default)]
+        pub profiles: Vec<ProfileData>,
+    }
+
+    impl ConfigData {
+        /// Gets a profile by name.
+        ///
+        /// * `name` - name of the profile.
+        ///
+        /// Returns the [`ProfileData`], or [`Option::None`] if there's no profile with that name.
+        pub fn profile(&self, name: &str) -> Option<&ProfileData> {
+            self.profiles.iter().find(|profile| profile.name == name)
+        }
+    }
+
+    /// An agent configured alongside the main one, so a team can run several agents from one process.
+    #[derive(Clone, Debug, Default, Eq, PartialEq, Deserialize, Serialize)]
+    pub struct ProfileData {
+        /// Name the profile is referred to by, e.g. on the command line.
+        pub name: String,
+        /// Auth token of the profile's agent.
+        pub token: String,
+        /// Symbol the agent was registered with.
+        #[serde(default)]
+        pub symbol: Option<String>,
+        /// [`Faction`](`Factions`) the agent was registered in.
+        #[serde(default)]
+        pub faction: Option<Factions>,
+        /// Server reset date at the time the agent was registered, used to detect universe wipes.
+        #[serde(default)]
+        pub reset_date: Option<String>,
     }
 
     /// Get the default config file path based on the application root directory and default config file name.
     ///
     /// Returns the [`PathBuf`] default path to the config file.
-    fn get_default_config_file_path() -> PathBuf {
-        let mut config_file_path = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
-        config_file_path.push(CONFIG_FILE_NAME);
-        config_file_path
+    pub(crate) fn get_default_config_file_path() -> PathBuf {
+        super::get_app_file_path(CONFIG_FILE_NAME)
     }
 
     /// Middleware function for reading config data from the config file.
@@ -33,9 +112,9 @@ pub mod config {
     /// * `config_file_path` - [`PathBuf`] path to the config file.
     ///
     /// Returns client [`ConfigData`], or [`Option::None`] if the file cannot be read or contains no data.
-    fn read_config_file(config_file_path: PathBuf) -> Option<ConfigData> {
+    pub(crate) fn read_config_file(config_file_path: PathBuf) -> Option<ConfigData> {
         let config_data_str = fs::read_to_string(config_file_path).ok()?;
-        serde_json::from_str::<ConfigData>(&config_data_str).ok()
+        without_schema_drift(|| serde_json::from_str::<ConfigData>(&config_data_str)).ok()
     }
 
     /// Public convenience wrapper for [`read_config_file`] using the default config file path.
@@ -51,7 +130,10 @@ pub mod config {
     /// * `config_file_path` - [`PathBuf`] path to the config file.
     ///
     /// Returns [`ConfigResult`] containing unit on success, or [`ConfigError::FileWrite`] if the operation fails.
-    fn write_config_file(config_data: ConfigData, config_file_path: PathBuf) -> ConfigResult<()> {
+    pub(crate) fn write_config_file(
+        config_data: ConfigData,
+        config_file_path: PathBuf,
+    ) -> ConfigResult<()> {
         let config_file = fs::File::create(config_file_path).map_err(|_| ConfigError::FileWrite)?;
         serde_json::to_writer_pretty(config_file, &config_data).map_err(|_| ConfigError::FileWrite)
     }
@@ -65,9 +147,42 @@ pub mod config {
         write_config_file(config_data, get_default_config_file_path())
     }
 
+    /// Middleware function for archiving the config file so that it can be replaced without losing old data.
+    ///
+    /// * `config_file_path` - [`PathBuf`] path to the config file.
+    /// * `archive_suffix` - label inserted into the archived file name, e.g. the reset date it belonged to.
+    ///
+    /// Returns [`ConfigResult`] containing the [`PathBuf`] of the archived copy on success, or
+    /// [`ConfigError::FileRead`]/[`ConfigError::FileWrite`] if the operation fails.
+    pub(crate) fn archive_config_file(
+        config_file_path: PathBuf,
+        archive_suffix: &str,
+    ) -> ConfigResult<PathBuf> {
+        let file_stem = config_file_path
+            .file_stem()
+            .and_then(|stem| stem.to_str())
+            .ok_or(ConfigError::FileRead)?;
+        let archive_file_path =
+            config_file_path.with_file_name(format!("{file_stem}.{archive_suffix}.json"));
+        fs::copy(&config_file_path, &archive_file_path).map_err(|_| ConfigError::FileWrite)?;
+        Ok(archive_file_path)
+    }
+
+    /// Public convenience wrapper for [`archive_config_file`] using the default config file path.
+    ///
+    /// * `archive_suffix` - label inserted into the archived file name, e.g. the reset date it belonged to.
+    ///
+    /// Returns [`ConfigResult`] containing the [`PathBuf`] of the archived copy on success, or
+    /// [`ConfigError::FileRead`]/[`ConfigError::FileWrite`] if the operation fails.
+    pub fn archive_default_config_file(archive_suffix: &str) -> ConfigResult<PathBuf> {
+        archive_config_file(get_default_config_file_path(), archive_suffix)
+    }
+
     #[cfg(test)]
     mod tests {
-        use super::{read_config_file, write_config_file, ConfigData};
+        use super::{
+            archive_config_file, read_config_file, write_config_file, ConfigData, ProfileData,
+        };
 
         use tempfile;
 
@@ -76,6 +191,7 @@ pub mod config {
             // Setup
             let expected_config_data = ConfigData {
                 token: String::from("TEST_READ_TOKEN"),
+                ..Default::default()
             };
 
             let tmp_config_file = tempfile::NamedTempFile::new().unwrap();
@@ -95,6 +211,7 @@ pub mod config {
             let tmp_config_file = tempfile::NamedTempFile::new().unwrap();
             let test_config_data = ConfigData {
                 token: String::from("TEST_WRITE_TOKEN"),
+                ..Default::default()
             };
 
             // Test
@@ -109,5 +226,629 @@ pub mod config {
             assert!(final_cfg.is_some());
             assert_eq!(test_config_data, final_cfg.unwrap());
         }
+
+        #[test]
+        fn verify_archive_config_file() {
+            // Setup
+            let tmp_config_dir = tempfile::tempdir().unwrap();
+            let config_file_path = tmp_config_dir.path().join("config.json");
+            let test_config_data = ConfigData {
+                token: String::from("TEST_ARCHIVE_TOKEN"),
+                reset_date: Some(String::from("2023-05-20")),
+                ..Default::default()
+            };
+            write_config_file(test_config_data.clone(), config_file_path.clone()).unwrap();
+
+            // Test
+            let archive_file_path = archive_config_file(config_file_path, "2023-05-20").unwrap();
+
+            // Verify
+            assert_eq!(
+                archive_file_path,
+                tmp_config_dir.path().join("config.2023-05-20.json")
+            );
+            assert_eq!(read_config_file(archive_file_path), Some(test_config_data));
+        }
+
+        #[test]
+        fn verify_read_legacy_config_file() {
+            // Setup
+            let tmp_config_file = tempfile::NamedTempFile::new().unwrap();
+            std::fs::write(
+                tmp_config_file.path(),
+                r#"{ "token": "TEST_LEGACY_TOKEN" }"#,
+            )
+            .unwrap();
+
+            // Test
+            let actual_config_data = read_config_file(tmp_config_file.path().to_path_buf());
+
+            // Verify
+            assert_eq!(
+                actual_config_data,
+                Some(ConfigData {
+                    token: String::from("TEST_LEGACY_TOKEN"),
+                    ..Default::default()
+                })
+            );
+        }
+
+        #[test]
+        fn verify_read_config_profiles() {
+            // Setup
+            let tmp_config_file = tempfile::NamedTempFile::new().unwrap();
+            std::fs::write(
+                tmp_config_file.path(),
+                r#"{
+                    "token": "TEST_MAIN_TOKEN",
+                    "profiles": [
+                        { "name": "miner", "token": "TEST_MINER_TOKEN" },
+                        { "name": "trader", "token": "TEST_TRADER_TOKEN", "symbol": "TEST_TRADER" }
+                    ]
+                }"#,
+            )
+            .unwrap();
+
+            // Test
+            let config_data = read_config_file(tmp_config_file.path().to_path_buf()).unwrap();
+
+            // Verify
+            assert_eq!(
+                config_data.profile("trader"),
+                Some(&ProfileData {
+                    name: String::from("trader"),
+                    token: String::from("TEST_TRADER_TOKEN"),
+                    symbol: Some(String::from("TEST_TRADER")),
+                    faction: None,
+                    reset_date: None,
+                })
+            );
+            assert_eq!(config_data.profiles.len(), 2);
+            assert!(config_data.profile("hauler").is_none());
+        }
+    }
+}
+
+/// Utilities for the local cache of static universe data.
+///
+/// Systems, waypoints and their traits rarely change between server resets, so they are kept on disk
+/// to avoid repeating the same requests against the SpaceTraders API.
+pub mod cache {
+    use super::{lock_app_file, now_secs};
+    use crate::api::data::{without_schema_drift, JumpGate, LocationData, WaypointSymbol};
+
+    use serde::{Deserialize, Serialize};
+    use std::{collections::HashMap, fs, path::PathBuf, sync::Mutex, time::Duration};
+
+    const CACHE_FILE_NAME: &str = "cache.json";
+
+    /// Held while the default cache file is read or updated.
+    static CACHE_FILE_LOCK: Mutex<()> = Mutex::new(());
+
+    pub type CacheResult<T> = Result<T, CacheError>;
+
+    #[derive(Clone, Copy, Debug)]
+    pub enum CacheError {
+        FileWrite,
+    }
+
+    /// Kinds of universe data held in the cache.
+    #[derive(Clone, Copy, Debug, Eq, PartialEq)]
+    pub enum CacheEntity {
+        Waypoint,
+        JumpGate,
+        /// The full list of waypoints in a system.
+        System,
+    }
+    impl CacheEntity {
+        /// How long a cached entry of this kind is trusted before it must be fetched again.
+        pub fn ttl(&self) -> Duration {
+            match self {
+                CacheEntity::Waypoint => Duration::from_secs(60 * 60 * 24),
+                // Connections only change when a gate is built, so they can be trusted for longer.
+                CacheEntity::JumpGate => Duration::from_secs(60 * 60 * 24 * 7),
+                // Waypoints are rarely added to a system, while their own entries are refreshed by scans.
+                CacheEntity::System => Duration::from_secs(60 * 60 * 24 * 7),
+            }
+        }
+    }
+
+    /// A single cached value along with when it was stored.
+    #[derive(Clone, Debug, Deserialize, Serialize)]
+    pub struct CacheEntry<T> {
+        /// Seconds since the Unix epoch at which the entry was written.
+        pub cached_at: u64,
+        pub data: T,
+    }
+    impl<T> CacheEntry<T> {
+        fn new(data: T) -> Self {
+            Self {
+                cached_at: now_secs(),
+                data,
+            }
+        }
+
+        /// Whether the entry is still within the TTL of the given [`CacheEntity`].
+        fn is_fresh(&self, entity: CacheEntity) -> bool {
+            now_secs().saturating_sub(self.cached_at) < entity.ttl().as_secs()
+        }
+    }
+
+    /// The shape of the data contained in the cache file.
+    #[derive(Clone, Debug, Default, Deserialize, Serialize)]
+    pub struct CacheData {
+        /// Server reset date the cached data belongs to, if known.
+        pub reset_date: Option<String>,
+        #[serde(default)]
+        pub waypoints: HashMap<WaypointSymbol, CacheEntry<LocationData>>,
+        #[serde(default)]
+        pub jump_gates: HashMap<WaypointSymbol, CacheEntry<JumpGate>>,
+        /// Symbols of every waypoint in each system that has been listed in full.
+        #[serde(default)]
+        pub systems: HashMap<String, CacheEntry<Vec<WaypointSymbol>>>,
+    }
+
+    fn get_default_cache_file_path() -> PathBuf {
+        super::get_app_file_path(CACHE_FILE_NAME)
+    }
+
+    /// Middleware function for reading the cache file.
+    ///
+    /// * `cache_file_path` - [`PathBuf`] path to the cache file.
+    ///
+    /// Returns the [`CacheData`], which is empty if the file cannot be read or contains no data.
+    fn read_cache_file(cache_file_path: PathBuf) -> CacheData {
+        fs::read_to_string(cache_file_path)
+            .ok()
+            .and_then(|cache_data_str| {
+                without_schema_drift(|| serde_json::from_str(&cache_data_str)).ok()
+            })
+            .unwrap_or_default()
+    }
+
+    /// Middleware function for writing the cache file.
+    ///
+    /// The data is written to a temporary file first and then moved into place, so the cache file is never seen
+    /// half-written.
+    ///
+    /// * `cache_data` - [`CacheData`] to be written.
+    /// * `cache_file_path` - [`PathBuf`] path to the cache file.
+    ///
+    /// Returns [`CacheResult`] containing unit on success, or [`CacheError::FileWrite`] if the operation fails.
+    fn write_cache_file(cache_data: &CacheData, cache_file_path: PathBuf) -> CacheResult<()> {
+        let mut tmp_file_path = cache_file_path.clone().into_os_string();
+        tmp_file_path.push(".tmp");
+        let tmp_file_path = PathBuf::from(tmp_file_path);
+
+        let tmp_file = fs::File::create(&tmp_file_path).map_err(|_| CacheError::FileWrite)?;
+        serde_json::to_writer(tmp_file, cache_data).map_err(|_| CacheError::FileWrite)?;
+        fs::rename(tmp_file_path, cache_file_path).map_err(|_| CacheError::FileWrite)
+    }
+
+    fn read_waypoint(waypoint: &str, cache_file_path: PathBuf) -> Option<LocationData> {
+        read_cache_file(cache_file_path)
+            .waypoints
+            .remove(waypoint)
+            .filter(|entry| entry.is_fresh(CacheEntity::Waypoint))
+            .map(|entry| entry.data)
+    }
+
+    fn read_waypoints(
+        waypoints: &[&str],
+        cache_file_path: PathBuf,
+    ) -> HashMap<WaypointSymbol, LocationData> {
+        let mut cache_data = read_cache_file(cache_file_path);
+        waypoints
+            .iter()
+            .filter_map(|waypoint| {
+                cache_data
+                    .waypoints
+                    .remove(*waypoint)
+                    .filter(|entry| entry.is_fresh(CacheEntity::Waypoint))
+                    .map(|entry| (waypoint.to_string(), entry.data))
+            })
+            .collect()
+    }
+
+    fn write_waypoint(location_data: &LocationData, cache_file_path: PathBuf) -> CacheResult<()> {
+        write_waypoints(std::slice::from_ref(location_data), cache_file_path)
+    }
+
+    fn write_waypoints(waypoints: &[LocationData], cache_file_path: PathBuf) -> CacheResult<()> {
+        let mut cache_data = read_cache_file(cache_file_path.clone());
+        for location_data in waypoints {
+            cache_data.waypoints.insert(
+                location_data.symbol.clone(),
+                CacheEntry::new(location_data.clone()),
+            );
+        }
+        write_cache_file(&cache_data, cache_file_path)
+    }
+
+    fn read_system_waypoints(system: &str, cache_file_path: PathBuf) -> Option<Vec<LocationData>> {
+        let mut cache_data = read_cache_file(cache_file_path);
+        let waypoint_symbols = cache_data
+            .systems
+            .remove(system)
+            .filter(|entry| entry.is_fresh(CacheEntity::System))?
+            .data;
+
+        // The listing is only usable while every one of its waypoints is still fresh.
+        waypoint_symbols
+            .iter()
+            .map(|waypoint| {
+                cache_data
+                    .waypoints
+                    .remove(waypoint)
+                    .filter(|entry| entry.is_fresh(CacheEntity::Waypoint))
+                    .map(|entry| entry.data)
+            })
+            .collect()
+    }
+
+    fn write_system_waypoints(
+        system: &str,
+        waypoints: &[LocationData],
+        cache_file_path: PathBuf,
+    ) -> CacheResult<()> {
+        let mut cache_data = read_cache_file(cache_file_path.clone());
+        for location_data in waypoints {
+            cache_data.waypoints.insert(
+                location_data.symbol.clone(),
+                CacheEntry::new(location_data.clone()),
+            );
+        }
+        let waypoint_symbols = waypoints
+            .iter()
+            .map(|location_data| location_data.symbol.clone())
+            .collect();
+        cache_data
+            .systems
+            .insert(system.to_string(), CacheEntry::new(waypoint_symbols));
+        write_cache_file(&cache_data, cache_file_path)
+    }
+
+    fn read_jump_gate(waypoint: &str, cache_file_path: PathBuf) -> Option<JumpGate> {
+        read_cache_file(cache_file_path)
+            .jump_gates
+            .remove(waypoint)
+            .filter(|entry| entry.is_fresh(CacheEntity::JumpGate))
+            .map(|entry| entry.data)
+    }
+
+    fn write_jump_gate(jump_gate: &JumpGate, cache_file_path: PathBuf) -> CacheResult<()> {
+        let mut cache_data = read_cache_file(cache_file_path.clone());
+        cache_data
+            .jump_gates
+            .insert(jump_gate.symbol.clone(), CacheEntry::new(jump_gate.clone()));
+        write_cache_file(&cache_data, cache_file_path)
+    }
+
+    fn invalidate_on_reset(reset_date: &str, cache_file_path: PathBuf) -> CacheResult<bool> {
+        let cache_data = read_cache_file(cache_file_path.clone());
+        if cache_data.reset_date.as_deref() == Some(reset_date) {
+            return Ok(false);
+        }
+
+        let had_entries = !cache_data.waypoints.is_empty()
+            || !cache_data.jump_gates.is_empty()
+            || !cache_data.systems.is_empty();
+        let fresh_cache_data = CacheData {
+            reset_date: Some(reset_date.to_string()),
+            ..Default::default()
+        };
+        write_cache_file(&fresh_cache_data, cache_file_path)?;
+        Ok(had_entries)
+    }
+
+    /// Gets a cached waypoint from the default cache file.
+    ///
+    /// * `waypoint` - symbol of the waypoint to look up.
+    ///
+    /// Returns the cached [`LocationData`], or [`Option::None`] if it is missing or has expired.
+    pub fn read_cached_waypoint(waypoint: &str) -> Option<LocationData> {
+        let _cache_file_lock = lock_app_file(&CACHE_FILE_LOCK);
+        read_waypoint(waypoint, get_default_cache_file_path())
+    }
+
+    /// Gets several cached waypoints from the default cache file at once.
+    ///
+    /// The cache file is only read once, however many waypoints there are.
+    ///
+    /// * `waypoints` - symbols of the waypoints to look up.
+    ///
+    /// Returns the cached [`LocationData`] by waypoint symbol, leaving out waypoints that are missing or have
+    /// expired.
+    pub fn read_cached_waypoints(waypoints: &[&str]) -> HashMap<WaypointSymbol, LocationData> {
+        let _cache_file_lock = lock_app_file(&CACHE_FILE_LOCK);
+        read_waypoints(waypoints, get_default_cache_file_path())
+    }
+
+    /// Stores a waypoint in the default cache file, replacing any previous entry for it.
+    ///
+    /// * `location_data` - [`LocationData`] to be cached.
+    ///
+    /// Returns [`CacheResult`] containing unit on success, or [`CacheError::FileWrite`] if the operation fails.
+    pub fn write_cached_waypoint(location_data: &LocationData) -> CacheResult<()> {
+        let _cache_file_lock = lock_app_file(&CACHE_FILE_LOCK);
+        write_waypoint(location_data, get_default_cache_file_path())
+    }
+
+    /// Stores several waypoints in the default cache file at once, replacing any previous entries for them.
+    ///
+    /// The cache file is only read and written once, however many waypoints there are.
+    ///
+    /// * `waypoints` - [`LocationData`] of the waypoints to be cached.
+    ///
+    /// Returns [`CacheResult`] containing unit on success, or [`CacheError::FileWrite`] if the operation fails.
+    pub fn write_cached_waypoints(waypoints: &[LocationData]) -> CacheResult<()> {
+        let _cache_file_lock = lock_app_file(&CACHE_FILE_LOCK);
+        write_waypoints(waypoints, get_default_cache_file_path())
+    }
+
+    /// Gets every waypoint of a system from the default cache file.
+    ///
+    /// * `system` - symbol of the system.
+    ///
+    /// Returns the cached [`LocationData`] of each waypoint, or [`Option::None`] if the system hasn't been listed
+    /// in full, or the listing or any of its waypoints has expired.
+    pub fn read_cached_system_waypoints(system: &str) -> Option<Vec<LocationData>> {
+        let _cache_file_lock = lock_app_file(&CACHE_FILE_LOCK);
+        read_system_waypoints(system, get_default_cache_file_path())
+    }
+
+    /// Stores the full list of waypoints in a system in the default cache file, along with each waypoint.
+    ///
+    /// * `system` - symbol of the system.
+    /// * `waypoints` - [`LocationData`] of every waypoint in the system.
+    ///
+    /// Returns [`CacheResult`] containing unit on success, or [`CacheError::FileWrite`] if the operation fails.
+    pub fn write_cached_system_waypoints(
+        system: &str,
+        waypoints: &[LocationData],
+    ) -> CacheResult<()> {
+        let _cache_file_lock = lock_app_file(&CACHE_FILE_LOCK);
+        write_system_waypoints(system, waypoints, get_default_cache_file_path())
+    }
+
+    /// Reads a jump gate from the default cache file.
+    ///
+    /// * `waypoint` - symbol of the jump gate's waypoint.
+    ///
+    /// Returns the cached [`JumpGate`], or [`Option::None`] if it is missing or has expired.
+    pub fn read_cached_jump_gate(waypoint: &str) -> Option<JumpGate> {
+        let _cache_file_lock = lock_app_file(&CACHE_FILE_LOCK);
+        read_jump_gate(waypoint, get_default_cache_file_path())
+    }
+
+    /// Stores a jump gate in the default cache file, replacing any previous entry for it.
+    ///
+    /// * `jump_gate` - [`JumpGate`] to be cached.
+    ///
+    /// Returns [`CacheResult`] containing unit on success, or [`CacheError::FileWrite`] if the operation fails.
+    pub fn write_cached_jump_gate(jump_gate: &JumpGate) -> CacheResult<()> {
+        let _cache_file_lock = lock_app_file(&CACHE_FILE_LOCK);
+        write_jump_gate(jump_gate, get_default_cache_file_path())
+    }
+
+    /// Clears the default cache file if it was filled before the given server reset.
+    ///
+    /// * `reset_date` - the server's current reset date.
+    ///
+    /// Returns [`CacheResult`] containing whether any cached data was discarded, or [`CacheError::FileWrite`]
+    /// if the operation fails.
+    pub fn invalidate_cache_on_reset(reset_date: &str) -> CacheResult<bool> {
+        let _cache_file_lock = lock_app_file(&CACHE_FILE_LOCK);
+        invalidate_on_reset(reset_date, get_default_cache_file_path())
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::{
+            invalidate_on_reset, read_cache_file, read_system_waypoints, read_waypoint,
+            read_waypoints, write_cache_file, write_system_waypoints, write_waypoint,
+            write_waypoints, CacheData, CacheEntity, CacheEntry,
+        };
+        use crate::api::data::{Coords, LocationData, UnknownFields, WaypointType};
+
+        use tempfile;
+
+        fn test_location_data(symbol: &str) -> LocationData {
+            LocationData {
+                system_symbol: String::from("X1-TEST"),
+                symbol: String::from(symbol),
+                r#type: WaypointType::Planet,
+                coords: Coords { x: 1, y: -1 },
+                orbitals: None,
+                traits: None,
+                chart: None,
+                faction: None,
+                extra: UnknownFields::default(),
+            }
+        }
+
+        #[test]
+        fn verify_waypoint_round_trip() {
+            // Setup
+            let tmp_cache_file = tempfile::NamedTempFile::new().unwrap();
+            let expected_location_data = test_location_data("X1-TEST-A1");
+
+            // Test
+            write_waypoint(&expected_location_data, tmp_cache_file.path().to_path_buf()).unwrap();
+            let actual_location_data =
+                read_waypoint("X1-TEST-A1", tmp_cache_file.path().to_path_buf());
+
+            // Verify
+            assert!(actual_location_data.is_some());
+            assert_eq!(actual_location_data.unwrap().symbol, "X1-TEST-A1");
+            assert!(read_waypoint("X1-TEST-B2", tmp_cache_file.path().to_path_buf()).is_none());
+        }
+
+        #[test]
+        fn verify_batched_waypoint_write() {
+            // Setup
+            let tmp_cache_file = tempfile::NamedTempFile::new().unwrap();
+            write_waypoint(
+                &test_location_data("X1-TEST-A1"),
+                tmp_cache_file.path().to_path_buf(),
+            )
+            .unwrap();
+            let waypoints = vec![
+                test_location_data("X1-TEST-B2"),
+                test_location_data("X1-TEST-C3"),
+            ];
+
+            // Test
+            write_waypoints(&waypoints, tmp_cache_file.path().to_path_buf()).unwrap();
+
+            // Verify
+            let cache_data = read_cache_file(tmp_cache_file.path().to_path_buf());
+            let mut cached_symbols: Vec<&String> = cache_data.waypoints.keys().collect();
+            cached_symbols.sort();
+            assert_eq!(
+                cached_symbols,
+                vec!["X1-TEST-A1", "X1-TEST-B2", "X1-TEST-C3"]
+            );
+        }
+
+        #[test]
+        fn verify_cache_file_is_replaced_whole() {
+            // Setup
+            let tmp_cache_dir = tempfile::tempdir().unwrap();
+            let cache_file_path = tmp_cache_dir.path().join("cache.json");
+            write_waypoint(&test_location_data("X1-TEST-A1"), cache_file_path.clone()).unwrap();
+
+            // Test
+            write_waypoint(&test_location_data("X1-TEST-B2"), cache_file_path.clone()).unwrap();
+
+            // Verify
+            let file_names: Vec<String> = std::fs::read_dir(tmp_cache_dir.path())
+                .unwrap()
+                .map(|entry| entry.unwrap().file_name().to_string_lossy().into_owned())
+                .collect();
+            assert_eq!(file_names, vec!["cache.json"]);
+            assert_eq!(read_cache_file(cache_file_path).waypoints.len(), 2);
+        }
+
+        #[test]
+        fn verify_batched_waypoint_lookup() {
+            // Setup
+            let tmp_cache_file = tempfile::NamedTempFile::new().unwrap();
+            let cache_file_path = tmp_cache_file.path().to_path_buf();
+            let mut cache_data = CacheData::default();
+            for (symbol, cached_at) in [("X1-TEST-A1", super::now_secs()), ("X1-TEST-B2", 0)] {
+                cache_data.waypoints.insert(
+                    String::from(symbol),
+                    CacheEntry {
+                        cached_at,
+                        data: test_location_data(symbol),
+                    },
+                );
+            }
+            write_cache_file(&cache_data, cache_file_path.clone()).unwrap();
+
+            // Test
+            let cached_waypoints =
+                read_waypoints(&["X1-TEST-A1", "X1-TEST-B2", "X1-TEST-C3"], cache_file_path);
+
+            // Verify
+            let cached_symbols: Vec<&String> = cached_waypoints.keys().collect();
+            assert_eq!(cached_symbols, vec!["X1-TEST-A1"]);
+        }
+
+        #[test]
+        fn verify_cache_without_waypoints_is_read() {
+            // Setup
+            let tmp_cache_file = tempfile::NamedTempFile::new().unwrap();
+            std::fs::write(tmp_cache_file.path(), r#"{"reset_date":"2023-05-20"}"#).unwrap();
+
+            // Test
+            let cache_data = read_cache_file(tmp_cache_file.path().to_path_buf());
+
+            // Verify
+            assert_eq!(cache_data.reset_date.as_deref(), Some("2023-05-20"));
+            assert!(cache_data.waypoints.is_empty());
+        }
+
+        #[test]
+        fn verify_system_waypoints_lookup() {
+            // Setup
+            let tmp_cache_file = tempfile::NamedTempFile::new().unwrap();
+            let cache_file_path = tmp_cache_file.path().to_path_buf();
+            let waypoints = vec![
+                test_location_data("X1-TEST-A1"),
+                test_location_data("X1-TEST-B2"),
+            ];
+            write_waypoint(&test_location_data("X1-OTHER-C3"), cache_file_path.clone()).unwrap();
+            let unlisted_system = read_system_waypoints("X1-OTHER", cache_file_path.clone());
+
+            // Test
+            write_system_waypoints("X1-TEST", &waypoints, cache_file_path.clone()).unwrap();
+            let listed_system = read_system_waypoints("X1-TEST", cache_file_path.clone());
+            let mut cache_data = read_cache_file(cache_file_path.clone());
+            if let Some(entry) = cache_data.waypoints.get_mut("X1-TEST-B2") {
+                entry.cached_at = 0;
+            }
+            write_cache_file(&cache_data, cache_file_path.clone()).unwrap();
+            let partly_expired_system = read_system_waypoints("X1-TEST", cache_file_path);
+
+            // Verify
+            assert!(unlisted_system.is_none());
+            let listed_symbols: Vec<String> = listed_system
+                .unwrap()
+                .into_iter()
+                .map(|location_data| location_data.symbol)
+                .collect();
+            assert_eq!(listed_symbols, vec!["X1-TEST-A1", "X1-TEST-B2"]);
+            assert!(partly_expired_system.is_none());
+        }
+
+        #[test]
+        fn verify_expired_waypoint_is_ignored() {
+            // Setup
+            let tmp_cache_file = tempfile::NamedTempFile::new().unwrap();
+            let mut cache_data = CacheData::default();
+            cache_data.waypoints.insert(
+                String::from("X1-TEST-A1"),
+                CacheEntry {
+                    cached_at: 0,
+                    data: test_location_data("X1-TEST-A1"),
+                },
+            );
+            write_cache_file(&cache_data, tmp_cache_file.path().to_path_buf()).unwrap();
+
+            // Test
+            let actual_location_data =
+                read_waypoint("X1-TEST-A1", tmp_cache_file.path().to_path_buf());
+
+            // Verify
+            assert!(CacheEntity::Waypoint.ttl().as_secs() > 0);
+            assert!(actual_location_data.is_none());
+        }
+
+        #[test]
+        fn verify_invalidate_on_reset() {
+            // Setup
+            let tmp_cache_file = tempfile::NamedTempFile::new().unwrap();
+            invalidate_on_reset("2023-05-20", tmp_cache_file.path().to_path_buf()).unwrap();
+            write_waypoint(
+                &test_location_data("X1-TEST-A1"),
+                tmp_cache_file.path().to_path_buf(),
+            )
+            .unwrap();
+
+            // Test
+            let same_reset =
+                invalidate_on_reset("2023-05-20", tmp_cache_file.path().to_path_buf()).unwrap();
+            let new_reset =
+                invalidate_on_reset("2023-06-03", tmp_cache_file.path().to_path_buf()).unwrap();
+
+            // Verify
+            assert!(!same_reset);
+            assert!(new_reset);
+            let final_cache_data = read_cache_file(tmp_cache_file.path().to_path_buf());
+            assert_eq!(final_cache_data.reset_date.as_deref(), Some("2023-06-03"));
+            assert!(final_cache_data.waypoints.is_empty());
+        }
     }
 }